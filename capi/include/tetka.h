@@ -0,0 +1,155 @@
+/* Generated by cbindgen from tetka-capi, do not edit by hand. */
+
+#ifndef TETKA_H
+#define TETKA_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// TetkaMoveResult is the result of trying to make a move on a position.
+typedef enum TetkaMoveResult {
+  // Ok means that the move was legal and has been made.
+  TETKA_MOVE_RESULT_OK,
+  // NullArgument means that one of the arguments was a null pointer.
+  TETKA_MOVE_RESULT_NULL_ARGUMENT,
+  // BadSyntax means that the move string couldn't be parsed.
+  TETKA_MOVE_RESULT_BAD_SYNTAX,
+  // GameOver means that the game is already over.
+  TETKA_MOVE_RESULT_GAME_OVER,
+  // NoPieceOnSource means that the side to move has no piece on the source
+  // square of the move.
+  TETKA_MOVE_RESULT_NO_PIECE_ON_SOURCE,
+  // OccupiedTarget means that the target square of the move is occupied.
+  TETKA_MOVE_RESULT_OCCUPIED_TARGET,
+  // TooFar means that the target square is too far from the source square.
+  TETKA_MOVE_RESULT_TOO_FAR,
+  // Unreachable means that no piece of the side to move can reach the
+  // target square of the move.
+  TETKA_MOVE_RESULT_UNREACHABLE,
+  // PassWithMovesAvailable means that the move is a pass even though the
+  // side to move has other moves available.
+  TETKA_MOVE_RESULT_PASS_WITH_MOVES_AVAILABLE,
+  // Illegal means that the move is illegal for any other reason.
+  TETKA_MOVE_RESULT_ILLEGAL,
+} TetkaMoveResult;
+
+// TetkaColor represents the two sides of an ataxx game.
+typedef enum TetkaColor {
+  // Black is the side with the `x` pieces, which moves first.
+  TETKA_COLOR_BLACK,
+  // White is the side with the `o` pieces.
+  TETKA_COLOR_WHITE,
+} TetkaColor;
+
+// TetkaOutcome represents the outcome of an ataxx position.
+typedef enum TetkaOutcome {
+  // Ongoing is a position where the game isn't over.
+  TETKA_OUTCOME_ONGOING,
+  // BlackWins is a position where the game is over and black has won.
+  TETKA_OUTCOME_BLACK_WINS,
+  // WhiteWins is a position where the game is over and white has won.
+  TETKA_OUTCOME_WHITE_WINS,
+  // Draw is a position where the game is over and drawn.
+  TETKA_OUTCOME_DRAW,
+} TetkaOutcome;
+
+// TetkaPosition is an opaque ataxx position.
+typedef struct TetkaPosition TetkaPosition;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Creates a new position from the given FEN string, which returns null if
+// the FEN is null or invalid. The position must be freed with
+// `tetka_position_free`.
+//
+// # Safety
+// `fen` must be null or point to a NUL-terminated string.
+struct TetkaPosition *tetka_position_new(const char *fen);
+
+// Creates a new position with the standard ataxx starting position. The
+// position must be freed with `tetka_position_free`.
+struct TetkaPosition *tetka_position_startpos(void);
+
+// Creates a copy of the given position, which returns null if the position
+// is null. The copy must be freed with `tetka_position_free`.
+//
+// # Safety
+// `position` must be null or a valid position.
+struct TetkaPosition *tetka_position_clone(const struct TetkaPosition *position);
+
+// Frees the given position, which does nothing if the position is null.
+//
+// # Safety
+// `position` must be null or a valid position, which must not be used again.
+void tetka_position_free(struct TetkaPosition *position);
+
+// Writes the FEN of the given position into the given buffer, and returns its
+// length without the NUL terminator, like `snprintf`. The FEN is truncated if
+// the buffer is too small, and nothing is written if the buffer is null.
+//
+// # Safety
+// `position` must be a valid position, and `buffer` must be null or point to
+// at least `length` writable bytes.
+size_t tetka_position_fen(const struct TetkaPosition *position, char *buffer, size_t length);
+
+// Writes the legal moves of the given position into the given buffer as a
+// space separated list, and returns its length without the NUL terminator,
+// like `tetka_position_fen`. A position where the side to move has no moves
+// but the game isn't over has a single pass move, `0000`.
+//
+// # Safety
+// `position` must be a valid position, and `buffer` must be null or point to
+// at least `length` writable bytes.
+size_t tetka_position_legal_moves(const struct TetkaPosition *position,
+                                  char *buffer,
+                                  size_t length);
+
+// Returns the number of legal moves in the given position.
+//
+// # Safety
+// `position` must be a valid position.
+size_t tetka_position_move_count(const struct TetkaPosition *position);
+
+// Makes the given move on the given position if it is legal, and returns the
+// reason it is illegal otherwise, in which case the position is unchanged.
+//
+// # Safety
+// `position` must be null or a valid position, and `mov` must be null or point
+// to a NUL-terminated string.
+enum TetkaMoveResult tetka_position_make_move(struct TetkaPosition *position, const char *mov);
+
+// Returns the side to move in the given position.
+//
+// # Safety
+// `position` must be a valid position.
+enum TetkaColor tetka_position_side_to_move(const struct TetkaPosition *position);
+
+// Returns the outcome of the game in the given position.
+//
+// # Safety
+// `position` must be a valid position.
+enum TetkaOutcome tetka_position_outcome(const struct TetkaPosition *position);
+
+// Returns the hash of the given position.
+//
+// # Safety
+// `position` must be a valid position.
+uint64_t tetka_position_hash(const struct TetkaPosition *position);
+
+// Returns the number of leaf nodes in the move generation tree of the given
+// depth from the given position.
+//
+// # Safety
+// `position` must be a valid position.
+uint64_t tetka_position_perft(const struct TetkaPosition *position, uint8_t depth);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* TETKA_H */
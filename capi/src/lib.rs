@@ -0,0 +1,301 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! C bindings for the ataxx implementation of tetka-games.
+//!
+//! Positions are handled through opaque [`TetkaPosition`] pointers, which are
+//! created by [`tetka_position_new`] or [`tetka_position_startpos`] and must be
+//! freed with [`tetka_position_free`]. Strings like FENs and moves are passed
+//! as NUL-terminated UTF-8 strings, and are returned by writing them into a
+//! caller-provided buffer in the style of `snprintf`:
+//! ```c
+//! size_t length = tetka_position_fen(position, NULL, 0);
+//! char *fen = malloc(length + 1);
+//! tetka_position_fen(position, fen, length + 1);
+//! ```
+//! The C header of the bindings is committed as `include/tetka.h`. It is
+//! generated by cbindgen into the build directory whenever the crate is built,
+//! and the tests check that the committed header matches the generated one.
+
+use std::ffi::{c_char, CStr};
+use std::ptr;
+use std::str::FromStr;
+
+use tetka_games::ataxx::{Color, Position};
+use tetka_games::interface::{MoveError, PositionType};
+
+/// TetkaPosition is an opaque ataxx position.
+pub struct TetkaPosition(Position);
+
+/// TetkaColor represents the two sides of an ataxx game.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TetkaColor {
+    /// Black is the side with the `x` pieces, which moves first.
+    Black,
+    /// White is the side with the `o` pieces.
+    White,
+}
+
+/// TetkaOutcome represents the outcome of an ataxx position.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TetkaOutcome {
+    /// Ongoing is a position where the game isn't over.
+    Ongoing,
+    /// BlackWins is a position where the game is over and black has won.
+    BlackWins,
+    /// WhiteWins is a position where the game is over and white has won.
+    WhiteWins,
+    /// Draw is a position where the game is over and drawn.
+    Draw,
+}
+
+/// TetkaMoveResult is the result of trying to make a move on a position.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TetkaMoveResult {
+    /// Ok means that the move was legal and has been made.
+    Ok,
+    /// NullArgument means that one of the arguments was a null pointer.
+    NullArgument,
+    /// BadSyntax means that the move string couldn't be parsed.
+    BadSyntax,
+    /// GameOver means that the game is already over.
+    GameOver,
+    /// NoPieceOnSource means that the side to move has no piece on the source
+    /// square of the move.
+    NoPieceOnSource,
+    /// OccupiedTarget means that the target square of the move is occupied.
+    OccupiedTarget,
+    /// TooFar means that the target square is too far from the source square.
+    TooFar,
+    /// Unreachable means that no piece of the side to move can reach the
+    /// target square of the move.
+    Unreachable,
+    /// PassWithMovesAvailable means that the move is a pass even though the
+    /// side to move has other moves available.
+    PassWithMovesAvailable,
+    /// Illegal means that the move is illegal for any other reason.
+    Illegal,
+}
+
+impl<S> From<MoveError<S>> for TetkaMoveResult {
+    fn from(err: MoveError<S>) -> Self {
+        match err {
+            MoveError::BadSyntax(_) => TetkaMoveResult::BadSyntax,
+            MoveError::GameOver => TetkaMoveResult::GameOver,
+            MoveError::NoPieceOnSource(_) => TetkaMoveResult::NoPieceOnSource,
+            MoveError::OccupiedTarget(_) => TetkaMoveResult::OccupiedTarget,
+            MoveError::TooFar(_, _) => TetkaMoveResult::TooFar,
+            MoveError::Unreachable(_) => TetkaMoveResult::Unreachable,
+            MoveError::PassWithMovesAvailable => TetkaMoveResult::PassWithMovesAvailable,
+            MoveError::Illegal(_) => TetkaMoveResult::Illegal,
+        }
+    }
+}
+
+/// Creates a new position from the given FEN string, which returns null if
+/// the FEN is null or invalid. The position must be freed with
+/// `tetka_position_free`.
+///
+/// # Safety
+/// `fen` must be null or point to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn tetka_position_new(fen: *const c_char) -> *mut TetkaPosition {
+    let Some(fen) = to_str(fen) else {
+        return ptr::null_mut();
+    };
+
+    match Position::from_str(fen) {
+        Ok(position) => Box::into_raw(Box::new(TetkaPosition(position))),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Creates a new position with the standard ataxx starting position. The
+/// position must be freed with `tetka_position_free`.
+#[no_mangle]
+pub extern "C" fn tetka_position_startpos() -> *mut TetkaPosition {
+    let position = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    Box::into_raw(Box::new(TetkaPosition(position)))
+}
+
+/// Creates a copy of the given position, which returns null if the position
+/// is null. The copy must be freed with `tetka_position_free`.
+///
+/// # Safety
+/// `position` must be null or a valid position.
+#[no_mangle]
+pub unsafe extern "C" fn tetka_position_clone(
+    position: *const TetkaPosition,
+) -> *mut TetkaPosition {
+    match position.as_ref() {
+        Some(position) => Box::into_raw(Box::new(TetkaPosition(position.0))),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Frees the given position, which does nothing if the position is null.
+///
+/// # Safety
+/// `position` must be null or a valid position, which must not be used again.
+#[no_mangle]
+pub unsafe extern "C" fn tetka_position_free(position: *mut TetkaPosition) {
+    if !position.is_null() {
+        drop(Box::from_raw(position));
+    }
+}
+
+/// Writes the FEN of the given position into the given buffer, and returns its
+/// length without the NUL terminator, like `snprintf`. The FEN is truncated if
+/// the buffer is too small, and nothing is written if the buffer is null.
+///
+/// # Safety
+/// `position` must be a valid position, and `buffer` must be null or point to
+/// at least `length` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn tetka_position_fen(
+    position: *const TetkaPosition,
+    buffer: *mut c_char,
+    length: usize,
+) -> usize {
+    write_string(&(*position).0.fen(), buffer, length)
+}
+
+/// Writes the legal moves of the given position into the given buffer as a
+/// space separated list, and returns its length without the NUL terminator,
+/// like `tetka_position_fen`. A position where the side to move has no moves
+/// but the game isn't over has a single pass move, `0000`.
+///
+/// # Safety
+/// `position` must be a valid position, and `buffer` must be null or point to
+/// at least `length` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn tetka_position_legal_moves(
+    position: *const TetkaPosition,
+    buffer: *mut c_char,
+    length: usize,
+) -> usize {
+    let moves = (*position).0.generate_moves::<false, true, true>();
+    let moves: Vec<_> = moves.iter().map(ToString::to_string).collect();
+    write_string(&moves.join(" "), buffer, length)
+}
+
+/// Returns the number of legal moves in the given position.
+///
+/// # Safety
+/// `position` must be a valid position.
+#[no_mangle]
+pub unsafe extern "C" fn tetka_position_move_count(position: *const TetkaPosition) -> usize {
+    (*position).0.count_moves::<true, true>()
+}
+
+/// Makes the given move on the given position if it is legal, and returns the
+/// reason it is illegal otherwise, in which case the position is unchanged.
+///
+/// # Safety
+/// `position` must be null or a valid position, and `mov` must be null or point
+/// to a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn tetka_position_make_move(
+    position: *mut TetkaPosition,
+    mov: *const c_char,
+) -> TetkaMoveResult {
+    let (Some(position), Some(mov)) = (position.as_mut(), to_str(mov)) else {
+        return TetkaMoveResult::NullArgument;
+    };
+
+    match position.0.parse_move(mov) {
+        Ok(mov) => {
+            position.0 = position.0.after_move::<true>(mov);
+            TetkaMoveResult::Ok
+        }
+        Err(err) => err.into(),
+    }
+}
+
+/// Returns the side to move in the given position.
+///
+/// # Safety
+/// `position` must be a valid position.
+#[no_mangle]
+pub unsafe extern "C" fn tetka_position_side_to_move(position: *const TetkaPosition) -> TetkaColor {
+    to_color((*position).0.side_to_move())
+}
+
+/// Returns the outcome of the game in the given position.
+///
+/// # Safety
+/// `position` must be a valid position.
+#[no_mangle]
+pub unsafe extern "C" fn tetka_position_outcome(position: *const TetkaPosition) -> TetkaOutcome {
+    let position = &(*position).0;
+    if !position.is_game_over() {
+        return TetkaOutcome::Ongoing;
+    }
+
+    match position.winner().map(to_color) {
+        Some(TetkaColor::Black) => TetkaOutcome::BlackWins,
+        Some(TetkaColor::White) => TetkaOutcome::WhiteWins,
+        None => TetkaOutcome::Draw,
+    }
+}
+
+/// Returns the hash of the given position.
+///
+/// # Safety
+/// `position` must be a valid position.
+#[no_mangle]
+pub unsafe extern "C" fn tetka_position_hash(position: *const TetkaPosition) -> u64 {
+    (*position).0.hash().into()
+}
+
+/// Returns the number of leaf nodes in the move generation tree of the given
+/// depth from the given position.
+///
+/// # Safety
+/// `position` must be a valid position.
+#[no_mangle]
+pub unsafe extern "C" fn tetka_position_perft(position: *const TetkaPosition, depth: u8) -> u64 {
+    tetka_games::perft::<false, true, _>((*position).0, depth)
+}
+
+/// to_str converts the given C string into a string slice, which returns
+/// `None` if the pointer is null or the string isn't valid UTF-8.
+unsafe fn to_str<'a>(string: *const c_char) -> Option<&'a str> {
+    if string.is_null() {
+        return None;
+    }
+
+    CStr::from_ptr(string).to_str().ok()
+}
+
+/// write_string writes the given string into the given buffer, truncating it
+/// to fit along with its NUL terminator, and returns the length of the string.
+unsafe fn write_string(string: &str, buffer: *mut c_char, length: usize) -> usize {
+    if !buffer.is_null() && length > 0 {
+        let written = string.len().min(length - 1);
+        ptr::copy_nonoverlapping(string.as_ptr().cast(), buffer, written);
+        *buffer.add(written) = 0;
+    }
+
+    string.len()
+}
+
+fn to_color(color: Color) -> TetkaColor {
+    match color {
+        Color::Black => TetkaColor::Black,
+        Color::White => TetkaColor::White,
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks that the committed C header is up to date, and builds the C test
+//! program in `tests/c` against it and the static library, checking that all
+//! of its checks pass.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// deps_dir returns the directory containing the libraries built for the
+/// tests, which is the directory of the test executable.
+#[cfg(unix)]
+fn deps_dir() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    exe.parent().unwrap().to_path_buf()
+}
+
+#[test]
+fn header() {
+    let generated = Path::new(env!("OUT_DIR")).join("tetka.h");
+    let committed = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("include")
+        .join("tetka.h");
+
+    // Line endings may be converted when the repository is checked out.
+    let read = |path: &Path| fs::read_to_string(path).unwrap().replace("\r\n", "\n");
+    assert!(
+        read(&generated) == read(&committed),
+        "include/tetka.h is out of date, update it from {}",
+        generated.display()
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn c_api() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let library = deps_dir().join("libtetka_capi.a");
+    assert!(library.exists(), "missing {}", library.display());
+
+    let output = Path::new(env!("CARGO_TARGET_TMPDIR")).join("tetka-c-test");
+    let mut cc = Command::new(env::var("CC").unwrap_or_else(|_| "cc".to_string()));
+    cc.arg("-std=c99")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg(manifest_dir.join("tests").join("c").join("test.c"))
+        .arg(&library)
+        .arg("-o")
+        .arg(&output);
+
+    // The system libraries which the Rust standard library depends on.
+    if cfg!(target_os = "linux") {
+        cc.args(["-lpthread", "-ldl", "-lm"]);
+    }
+
+    let status = cc.status().expect("failed to run the C compiler");
+    assert!(status.success(), "failed to compile the C test program");
+
+    let run = Command::new(&output).output().unwrap();
+    let stderr = String::from_utf8_lossy(&run.stderr);
+    assert!(run.status.success(), "C test program failed:\n{}", stderr);
+    assert_eq!(String::from_utf8_lossy(&run.stdout), "all checks passed\n");
+}
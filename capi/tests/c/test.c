@@ -0,0 +1,121 @@
+/*
+ * Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+/* test.c exercises the C API of tetka through the generated header. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "tetka.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                                      \
+    do {                                                                      \
+        if (!(condition)) {                                                   \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, \
+                    #condition);                                              \
+            failures++;                                                       \
+        }                                                                     \
+    } while (0)
+
+static void test_fen(void) {
+    const char *fen = "x5o/7/7/7/7/7/o5x x 0 1";
+    TetkaPosition *position = tetka_position_new(fen);
+    CHECK(position != NULL);
+
+    char buffer[64];
+    CHECK(tetka_position_fen(position, buffer, sizeof buffer) == strlen(fen));
+    CHECK(strcmp(buffer, fen) == 0);
+
+    /* A small buffer truncates the FEN but still reports its full length. */
+    char small[4];
+    CHECK(tetka_position_fen(position, small, sizeof small) == strlen(fen));
+    CHECK(strcmp(small, "x5o") == 0);
+    CHECK(tetka_position_fen(position, NULL, 0) == strlen(fen));
+
+    CHECK(tetka_position_new("x5o/7/7 x 0 1") == NULL);
+    CHECK(tetka_position_new(NULL) == NULL);
+
+    tetka_position_free(position);
+}
+
+static void test_moves(void) {
+    TetkaPosition *position = tetka_position_startpos();
+    CHECK(tetka_position_move_count(position) == 16);
+    CHECK(tetka_position_side_to_move(position) == TETKA_COLOR_BLACK);
+
+    char moves[256];
+    size_t length = tetka_position_legal_moves(position, moves, sizeof moves);
+    CHECK(length == strlen(moves));
+    CHECK(strstr(moves, "g2") != NULL);
+    CHECK(strstr(moves, "a7c5") != NULL);
+
+    uint64_t hash = tetka_position_hash(position);
+    TetkaPosition *copy = tetka_position_clone(position);
+
+    CHECK(tetka_position_make_move(position, "g2") == TETKA_MOVE_RESULT_OK);
+    CHECK(tetka_position_side_to_move(position) == TETKA_COLOR_WHITE);
+    CHECK(tetka_position_hash(position) != hash);
+    CHECK(tetka_position_hash(copy) == hash);
+
+    CHECK(tetka_position_make_move(position, "a7") ==
+          TETKA_MOVE_RESULT_OCCUPIED_TARGET);
+    CHECK(tetka_position_make_move(position, "d4") ==
+          TETKA_MOVE_RESULT_UNREACHABLE);
+    CHECK(tetka_position_make_move(position, "z9") ==
+          TETKA_MOVE_RESULT_BAD_SYNTAX);
+    CHECK(tetka_position_make_move(position, NULL) ==
+          TETKA_MOVE_RESULT_NULL_ARGUMENT);
+    CHECK(tetka_position_side_to_move(position) == TETKA_COLOR_WHITE);
+
+    tetka_position_free(copy);
+    tetka_position_free(position);
+}
+
+static void test_outcome(void) {
+    TetkaPosition *position = tetka_position_startpos();
+    CHECK(tetka_position_outcome(position) == TETKA_OUTCOME_ONGOING);
+    tetka_position_free(position);
+
+    position = tetka_position_new("xx5/7/7/7/7/7/7 o 0 1");
+    CHECK(tetka_position_outcome(position) == TETKA_OUTCOME_BLACK_WINS);
+    CHECK(tetka_position_make_move(position, "0000") ==
+          TETKA_MOVE_RESULT_GAME_OVER);
+    tetka_position_free(position);
+}
+
+static void test_perft(void) {
+    TetkaPosition *position = tetka_position_startpos();
+    CHECK(tetka_position_perft(position, 1) == 16);
+    CHECK(tetka_position_perft(position, 2) == 256);
+    CHECK(tetka_position_perft(position, 3) == 6460);
+    tetka_position_free(position);
+}
+
+int main(void) {
+    test_fen();
+    test_moves();
+    test_outcome();
+    test_perft();
+
+    if (failures != 0) {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+
+    printf("all checks passed\n");
+    return 0;
+}
@@ -0,0 +1,113 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use arrayvec::ArrayVec;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use tetka_games::interface::{MoveStore, PositionType};
+use tetka_games::{ataxx, breakthrough, perft};
+
+/// FixedList is the fixed capacity move storage which was used by
+/// `generate_moves` before its capacity was decided by the game, which is
+/// used as the baseline for the perft benchmarks.
+struct FixedList<M>(ArrayVec<M, 256>);
+
+impl<M> Default for FixedList<M> {
+    fn default() -> Self {
+        FixedList(ArrayVec::new())
+    }
+}
+
+impl<M> MoveStore<M> for FixedList<M> {
+    fn push(&mut self, m: M) {
+        self.0.push(m);
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// fixed_perft is [`perft`] with bulk-counting, but which stores the moves
+/// into a [`FixedList`] instead of the game's move storage.
+fn fixed_perft<T: PositionType>(position: T, depth: u8) -> u64 {
+    if depth == 1 {
+        return position.count_moves::<true, true>() as u64;
+    }
+
+    let mut movelist = FixedList::default();
+    position.generate_moves_into::<false, true, true, _>(&mut movelist);
+
+    let mut nodes = 0;
+    for m in movelist.0 {
+        let new_position = position.after_move::<false>(m);
+        nodes += fixed_perft(new_position, depth - 1);
+    }
+
+    nodes
+}
+
+/// bench_perft benchmarks perft on the given positions, both with the game's
+/// move storage and with the [`FixedList`] baseline.
+fn bench_perft<T: PositionType + Copy>(
+    c: &mut Criterion,
+    name: &str,
+    fens: &[&str],
+    depth: u8,
+) where
+    <T as FromStr>::Err: std::fmt::Debug,
+{
+    let mut group = c.benchmark_group(name);
+
+    for &fen in fens {
+        let position = T::from_str(fen).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("movelist", fen),
+            &position,
+            |b, &position| b.iter(|| perft::<false, true, _>(position, depth)),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("fixed", fen),
+            &position,
+            |b, &position| b.iter(|| fixed_perft(position, depth)),
+        );
+    }
+
+    group.finish();
+}
+
+fn ataxx_perft(c: &mut Criterion) {
+    bench_perft::<ataxx::Position>(
+        c,
+        "ataxx perft",
+        &["x5o/7/7/7/7/7/o5x x 0 1", "7/7/7/2x1o2/7/7/7 x 0 1"],
+        4,
+    );
+}
+
+// Breakthrough's move list is smaller than the baseline's, unlike ataxx's.
+fn breakthrough_perft(c: &mut Criterion) {
+    bench_perft::<breakthrough::Breakthrough>(
+        c,
+        "breakthrough perft",
+        &["pppppppp/pppppppp/8/8/8/8/PPPPPPPP/PPPPPPPP w"],
+        4,
+    );
+}
+
+criterion_group!(benches, ataxx_perft, breakthrough_perft);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::interface::{bitboard_type, RepresentableType};
+
+use super::Square;
+
+bitboard_type! {
+    /// A set of Squares implemented as a bitset where the `1 << sq.into()` bit
+    /// represents whether `sq` is in the BitBoard or not.
+    struct BitBoard : u128 {
+        // The BitBoard's Square type.
+        Square = Square;
+
+        // BitBoards representing the null and the universe sets.
+        Empty = Self(0);
+        Universe = Self(0xfffffffffffffffffffffffff);
+
+        // BitBoards containing the squares of the first file and the first rank.
+        FirstFile = Self(0x0040100401004010040100401);
+        FirstRank = Self(0x00000000000000000000003ff);
+    }
+}
+
+use crate::interface::BitBoardType;
+
+impl BitBoard {
+    /// neighbours returns a BitBoard containing all the Squares which are
+    /// adjacent, orthogonally or diagonally, to the Squares in the BitBoard.
+    #[must_use]
+    pub fn neighbours(self) -> BitBoard {
+        let bar = self | self.east() | self.west();
+        (bar | bar.north() | bar.south()) ^ self
+    }
+
+    /// queen_attacks returns the Squares which can be reached by a queen-like
+    /// move from the given Square, i.e. by sliding any number of Squares
+    /// orthogonally or diagonally, without crossing or landing on any of the
+    /// given occupied Squares.
+    pub fn queen_attacks(square: Square, occupied: BitBoard) -> BitBoard {
+        let empty = !occupied;
+        let source = BitBoard::from(square);
+        let mut attacks = BitBoard::EMPTY;
+
+        let shifts: [fn(BitBoard) -> BitBoard; 8] = [
+            BitBoard::north,
+            BitBoard::south,
+            BitBoard::east,
+            BitBoard::west,
+            |bb: BitBoard| bb.north().east(),
+            |bb: BitBoard| bb.north().west(),
+            |bb: BitBoard| bb.south().east(),
+            |bb: BitBoard| bb.south().west(),
+        ];
+
+        for shift in shifts {
+            // Slide along the ray until it is blocked or leaves the Board.
+            let mut ray = shift(source) & empty;
+            while !ray.is_empty() {
+                attacks |= ray;
+                ray = shift(ray) & empty;
+            }
+        }
+
+        attacks
+    }
+}
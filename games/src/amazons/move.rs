@@ -0,0 +1,209 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::str::FromStr;
+
+use thiserror::Error;
+
+use crate::interface::{MoveType, RepresentableType, TypeParseError};
+
+use super::Square;
+
+/// Move represents an Amazons move which can be played on the Board. Every
+/// Move consists of a queen-like move of an Amazon from its source Square to
+/// its target Square, followed by a queen-like shot of an Arrow from the
+/// target Square to the arrow Square, which is blocked for the rest of the
+/// game. The three Squares don't fit in 16 bits, so a Move is a 32-bit value.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Move(u32);
+
+impl MoveType for Move {
+    type Repr = u32;
+
+    const NULL: Self = Move(1 << 31);
+    const MAX_IN_GAME: usize = Square::N;
+    const MAX_IN_POSITION: usize = 4 * 36 * 36;
+
+    // The moves of a position are too many to be stored on the stack.
+    type List = Vec<Self>;
+}
+
+impl From<u32> for Move {
+    fn from(value: u32) -> Self {
+        Move(value)
+    }
+}
+
+impl From<Move> for u32 {
+    fn from(value: Move) -> Self {
+        value.0
+    }
+}
+
+impl Move {
+    // Bit-widths of fields.
+    const SOURCE_WIDTH: u32 = 7;
+    const TARGET_WIDTH: u32 = 7;
+    const ARROW_WIDTH: u32 = 7;
+
+    // Bit-masks of fields.
+    const SOURCE_MASK: u32 = (1 << Self::SOURCE_WIDTH) - 1;
+    const TARGET_MASK: u32 = (1 << Self::TARGET_WIDTH) - 1;
+    const ARROW_MASK: u32 = (1 << Self::ARROW_WIDTH) - 1;
+
+    // Bit-offsets of fields.
+    const SOURCE_OFFSET: u32 = 0;
+    const TARGET_OFFSET: u32 = Self::SOURCE_OFFSET + Self::SOURCE_WIDTH;
+    const ARROW_OFFSET: u32 = Self::TARGET_OFFSET + Self::TARGET_WIDTH;
+
+    /// NULL Move represents an invalid move.
+    pub const NULL: Move = Move(1 << 31);
+
+    /// new returns a new Move which moves an Amazon from the given source
+    /// Square to the given target Square and shoots an Arrow from there to the
+    /// given arrow Square. These Squares can be recovered with the
+    /// [`Move::source`], [`Move::target`] and [`Move::arrow`] methods.
+    /// ```
+    /// use tetka_games::amazons::*;
+    ///
+    /// let mov = Move::new(Square::D1, Square::D7, Square::G7);
+    ///
+    /// assert_eq!(mov.source(), Square::D1);
+    /// assert_eq!(mov.target(), Square::D7);
+    /// assert_eq!(mov.arrow(), Square::G7);
+    /// ```
+    #[inline(always)]
+    #[rustfmt::skip]
+    pub fn new(source: Square, target: Square, arrow: Square) -> Move {
+        Move(
+            (source as u32) << Self::SOURCE_OFFSET |
+            (target as u32) << Self::TARGET_OFFSET |
+            (arrow as u32) << Self::ARROW_OFFSET
+        )
+    }
+
+    /// Source returns the source Square of the moving Amazon.
+    pub fn source(self) -> Square {
+        unsafe {
+            Square::unsafe_from(
+                ((self.0 >> Self::SOURCE_OFFSET) & Self::SOURCE_MASK) as u8,
+            )
+        }
+    }
+
+    /// Target returns the target Square of the moving Amazon.
+    pub fn target(self) -> Square {
+        unsafe {
+            Square::unsafe_from(
+                ((self.0 >> Self::TARGET_OFFSET) & Self::TARGET_MASK) as u8,
+            )
+        }
+    }
+
+    /// Arrow returns the Square which the Arrow is shot to.
+    pub fn arrow(self) -> Square {
+        unsafe {
+            Square::unsafe_from(
+                ((self.0 >> Self::ARROW_OFFSET) & Self::ARROW_MASK) as u8,
+            )
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum MoveParseError {
+    #[error("move string should contain an arrow square after a '/'")]
+    MissingArrow,
+    #[error("move string should contain a source and a target square")]
+    MissingSquare,
+    #[error("bad square string \"{0}\"")]
+    BadSquare(#[from] TypeParseError),
+}
+
+impl FromStr for Move {
+    type Err = MoveParseError;
+
+    /// from_str converts the given string representation of a Move into a
+    /// [Move]. The format supported is `<source><target>/<arrow>`. This
+    /// function can be treated as the inverse of the [`fmt::Display`] trait
+    /// for [Move].
+    /// ```
+    /// use tetka_games::amazons::*;
+    /// use core::str::FromStr;
+    ///
+    /// let mov = Move::new(Square::D1, Square::D7, Square::G7);
+    ///
+    /// assert_eq!(Move::from_str("d1d7/g7").unwrap(), mov);
+    /// assert_eq!(Move::from_str(&mov.to_string()).unwrap(), mov);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (queen, arrow) =
+            s.split_once('/').ok_or(MoveParseError::MissingArrow)?;
+
+        // The target Square starts with the second File letter in the string.
+        let split = queen
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| c.is_ascii_alphabetic())
+            .map(|(i, _)| i)
+            .ok_or(MoveParseError::MissingSquare)?;
+
+        let parse = |s: &str| {
+            Square::from_str(s).map_err(|_| {
+                TypeParseError::StrError(stringify!(Square).to_string())
+            })
+        };
+
+        Ok(Move::new(
+            parse(&queen[..split])?,
+            parse(&queen[split..])?,
+            parse(arrow)?,
+        ))
+    }
+}
+
+impl fmt::Display for Move {
+    /// Display formats the given Move in a human-readable manner. The format
+    /// used for displaying moves is `<source><target>/<arrow>`, while
+    /// [`Move::NULL`] is formatted as `null`.
+    /// ```
+    /// use tetka_games::amazons::*;
+    ///
+    /// let mov = Move::new(Square::J4, Square::J10, Square::A10);
+    ///
+    /// assert_eq!(Move::NULL.to_string(), "null");
+    /// assert_eq!(mov.to_string(), "j4j10/a10");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Move::NULL {
+            write!(f, "null")
+        } else {
+            write!(f, "{}{}/{}", self.source(), self.target(), self.arrow())
+        }
+    }
+}
+
+// Moves are serialized as their move strings.
+crate::interface::serde_string!(Move);
+
+impl fmt::Debug for Move {
+    /// Debug formats the given Move into a human-readable debug string. It uses
+    /// `Move::Display` trait under the hood for formatting the Move.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
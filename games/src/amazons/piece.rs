@@ -0,0 +1,78 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::ops;
+use core::str::FromStr;
+
+use crate::interface::representable_type;
+use crate::interface::ColoredPieceType;
+use crate::interface::RepresentableType;
+
+representable_type!(
+    /// Color represents all the possible colors that an Amazon can have,
+    /// specifically, White and Black. White moves first.
+    enum Color: u8 { White "w", Black "b", }
+);
+
+impl ops::Not for Color {
+    type Output = Color;
+
+    /// not implements the not unary operator (!) which switches the current
+    /// Color to its opposite, i.e. [`Color::White`] to [`Color::Black`] and
+    /// vice versa.
+    fn not(self) -> Self::Output {
+        unsafe { Color::unsafe_from(self as usize ^ 1) }
+    }
+}
+
+representable_type!(
+    /// Piece represents the types of pieces in Amazons, namely Amazon and the
+    /// Arrows which are shot by them to block Squares.
+    enum Piece: u8 { Amazon "a", Arrow "x", }
+);
+
+representable_type!(
+    /// ColoredPiece represents all the possible Amazons pieces.
+    enum ColoredPiece: u8 { White "w", Black "b", Arrow "x", }
+);
+
+impl ColoredPieceType for ColoredPiece {
+    type Piece = Piece;
+    type Color = Color;
+
+    /// new creates a new ColoredPiece from the given Piece and Color. Since an
+    /// Arrow doesn't have a Color, the given Color is ignored for Arrows.
+    fn new(piece: Piece, color: Color) -> Self {
+        match (piece, color) {
+            (Piece::Amazon, Color::White) => ColoredPiece::White,
+            (Piece::Amazon, Color::Black) => ColoredPiece::Black,
+            (Piece::Arrow, _) => ColoredPiece::Arrow,
+        }
+    }
+
+    fn piece(self) -> Piece {
+        match self {
+            ColoredPiece::White | ColoredPiece::Black => Piece::Amazon,
+            ColoredPiece::Arrow => Piece::Arrow,
+        }
+    }
+
+    fn color(self) -> Option<Color> {
+        match self {
+            ColoredPiece::White => Some(Color::White),
+            ColoredPiece::Black => Some(Color::Black),
+            ColoredPiece::Arrow => None,
+        }
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::str::FromStr;
+
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use crate::interface;
+use crate::interface::{
+    BitBoardType, Hash, MoveStore, PiecePlacementParseError, PositionType,
+    RepresentableType, SquareType, TypeParseError,
+};
+
+use crate::amazons::{
+    BitBoard, Color, ColoredPiece, File, Move, Piece, Rank, Square,
+};
+
+/// Position represents the snapshot of an Amazons Board, the state of the game
+/// at a single point in time.
+///
+/// Every turn, the side to move moves one of its Amazons like a chess queen,
+/// and then shoots an Arrow from it like a chess queen, which blocks the Square
+/// it lands on for the rest of the game. A side which has no legal moves
+/// loses the game.
+#[derive(Copy, Clone)]
+pub struct Position {
+    /// bitboards stores BitBoards for all the ColoredPieces.
+    pub bitboards: [BitBoard; ColoredPiece::N],
+    /// checksum stores the semi-unique [struct@Hash] of the current Position.
+    pub checksum: Hash,
+    /// side_to_move stores the piece whose turn to move it currently is.
+    pub side_to_move: Color,
+}
+
+impl PositionType for Position {
+    type BitBoard = BitBoard;
+    type ColoredPiece = ColoredPiece;
+    type Move = Move;
+
+    fn insert(&mut self, sq: Square, piece: ColoredPiece) {
+        self.bitboards[piece as usize].insert(sq);
+    }
+
+    fn remove(&mut self, sq: Square) -> Option<ColoredPiece> {
+        match self.at(sq) {
+            Some(piece) => {
+                self.bitboards[piece as usize].remove(sq);
+                Some(piece)
+            }
+            None => None,
+        }
+    }
+
+    fn at(&self, sq: Square) -> Option<ColoredPiece> {
+        ColoredPiece::iter()
+            .find(|piece| self.colored_piece_bb(*piece).contains(sq))
+    }
+
+    fn piece_bb(&self, piece: Piece) -> BitBoard {
+        match piece {
+            Piece::Amazon => {
+                self.colored_piece_bb(ColoredPiece::White)
+                    | self.colored_piece_bb(ColoredPiece::Black)
+            }
+            Piece::Arrow => self.colored_piece_bb(ColoredPiece::Arrow),
+        }
+    }
+
+    fn color_bb(&self, color: Color) -> BitBoard {
+        self.bitboards[color as usize]
+    }
+
+    fn colored_piece_bb(&self, piece: ColoredPiece) -> BitBoard {
+        self.bitboards[piece as usize]
+    }
+
+    fn hash(&self) -> Hash {
+        self.checksum
+    }
+
+    fn side_to_move(&self) -> Color {
+        self.side_to_move
+    }
+
+    fn fen(&self) -> String {
+        format!(
+            "{} {}",
+            interface::format_piece_placement(self),
+            self.side_to_move
+        )
+    }
+
+    fn winner(&self) -> Option<Color> {
+        // An Amazon which can move can always shoot an Arrow back to the
+        // Square it came from, so the side to move has a legal move as long
+        // as any of its Amazons has an empty neighbouring Square.
+        let stm = self.color_bb(self.side_to_move);
+        if (stm.neighbours() & !self.occupied()).is_empty() {
+            Some(!self.side_to_move)
+        } else {
+            None
+        }
+    }
+
+    fn after_move<const UPDATE_HASH: bool>(&self, m: Move) -> Self {
+        let stm = self.side_to_move;
+
+        let mut bitboards = self.bitboards;
+        bitboards[stm as usize].remove(m.source());
+        bitboards[stm as usize].insert(m.target());
+        bitboards[ColoredPiece::Arrow as usize].insert(m.arrow());
+
+        Position {
+            bitboards,
+            checksum: if UPDATE_HASH {
+                Self::get_hash(bitboards, !stm)
+            } else {
+                Default::default()
+            },
+            side_to_move: !stm,
+        }
+    }
+
+    fn generate_moves_into<
+        const ALLOW_ILLEGAL: bool,
+        const QUIET: bool,
+        const NOISY: bool,
+        T: MoveStore<Move>,
+    >(
+        &self,
+        movelist: &mut T,
+    ) {
+        // All Amazons moves are irreversible, since they block a Square.
+        if !NOISY {
+            return;
+        }
+
+        let occupied = self.occupied();
+        for source in self.color_bb(self.side_to_move) {
+            // The Amazon leaves its source Square, so it doesn't block any
+            // Arrows shot through or to that Square.
+            let occupied = occupied ^ BitBoard::from(source);
+            for target in BitBoard::queen_attacks(source, occupied) {
+                let occupied = occupied | BitBoard::from(target);
+                for arrow in BitBoard::queen_attacks(target, occupied) {
+                    movelist.push(Move::new(source, target, arrow));
+                }
+            }
+        }
+    }
+
+    fn count_moves<const QUIET: bool, const NOISY: bool>(&self) -> usize {
+        if !NOISY {
+            return 0;
+        }
+
+        let occupied = self.occupied();
+        let mut moves = 0;
+        for source in self.color_bb(self.side_to_move) {
+            let occupied = occupied ^ BitBoard::from(source);
+            for target in BitBoard::queen_attacks(source, occupied) {
+                let occupied = occupied | BitBoard::from(target);
+                moves += BitBoard::queen_attacks(target, occupied).len();
+            }
+        }
+
+        moves
+    }
+}
+
+impl Position {
+    /// occupied returns a BitBoard containing all the occupied Squares.
+    pub fn occupied(&self) -> BitBoard {
+        self.bitboards
+            .iter()
+            .fold(BitBoard::EMPTY, |occupied, &bb| occupied | bb)
+    }
+
+    fn get_hash(bitboards: [BitBoard; ColoredPiece::N], stm: Color) -> Hash {
+        // The Arrows are hashed together with each side's Amazons, since the
+        // hash function only supports a pair of bitsets.
+        let arrows = u128::from(bitboards[ColoredPiece::Arrow as usize]);
+        let hash = Hash::from_bitsets(
+            u128::from(bitboards[Color::White as usize])
+                ^ arrows.rotate_left(64),
+            u128::from(bitboards[Color::Black as usize]) ^ arrows,
+        );
+
+        // The Hash is bitwise complemented if the given side to move is White.
+        // Therefore, if two Positions only differ in side to move,
+        // `a.Hash == !b.Hash`.
+        if stm == Color::White {
+            !hash
+        } else {
+            hash
+        }
+    }
+}
+
+impl Default for Position {
+    /// Returns the starting Position of the game, with White's Amazons on a4,
+    /// d1, g1 and j4, Black's Amazons on a7, d10, g10 and j7 and White to
+    /// move.
+    fn default() -> Self {
+        Position::from_str("3b2b3/10/10/b8b/10/10/w8w/10/10/3w2w3 w").unwrap()
+    }
+}
+
+/// PositionParseErr represents an error encountered while parsing
+/// the given FEN position field into a valid Position.
+#[derive(Error, Debug)]
+pub enum PositionParseError {
+    #[error("expected 2 fields, found {0}")]
+    WrongFieldNumber(usize),
+
+    #[error("parsing piece placement: {0}")]
+    BadPiecePlacement(#[from] PiecePlacementParseError),
+
+    #[error("parsing side to move: {0}")]
+    BadSideToMove(#[from] TypeParseError),
+}
+
+// FromStr implements parsing of the position field in a FEN. The FEN for an
+// Amazons game contains the piece placement, with Arrows represented by `x`,
+// and the side to move, for example `3b2b3/10/10/b8b/10/10/w8w/10/10/3w2w3 w`
+// for the starting position.
+impl FromStr for Position {
+    type Err = PositionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split(' ').collect::<Vec<&str>>();
+
+        if parts.len() != 2 {
+            return Err(PositionParseError::WrongFieldNumber(parts.len()));
+        }
+
+        let pos = parts[0];
+        let stm = parts[1];
+
+        let mut position = Position {
+            bitboards: [BitBoard::EMPTY; ColoredPiece::N],
+            checksum: Default::default(),
+            side_to_move: Color::White,
+        };
+
+        interface::parse_piece_placement(&mut position, pos)?;
+
+        position.side_to_move = Color::from_str(stm)?;
+
+        // Calculate the Hash value for the Position.
+        position.checksum =
+            Self::get_hash(position.bitboards, position.side_to_move);
+
+        Ok(position)
+    }
+}
+
+// Display implements displaying a Position using ASCII art.
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut string_rep = String::new();
+
+        for rank in Rank::iter().rev() {
+            for file in File::iter() {
+                let square = Square::new(file, rank);
+                let square_str = match self.at(square) {
+                    Some(piece) => format!("{} ", piece),
+                    None => ". ".to_string(),
+                };
+                string_rep += &square_str;
+            }
+
+            // Append the rank marker.
+            string_rep += &format!(" {}\n", rank);
+        }
+
+        // Append the file markers.
+        for file in File::iter() {
+            string_rep += &format!("{} ", file);
+        }
+
+        writeln!(f, "{}", string_rep.trim_end()).unwrap();
+        writeln!(f, "Side To Move: {}", self.side_to_move)
+    }
+}
+
+// Positions are serialized as their FEN strings.
+crate::interface::serde_string!(Position, crate::interface::PositionType::fen);
@@ -0,0 +1,67 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::interface::{representable_type, RepresentableType, SquareType};
+
+representable_type!(
+    /// Square represents all the squares present on an Amazons Board.
+    /// The index of each Square is equal to `rank-index * 10 + file-index`.
+    enum Square: u8 {
+        A1 "a1", B1 "b1", C1 "c1", D1 "d1", E1 "e1", F1 "f1",
+        G1 "g1", H1 "h1", I1 "i1", J1 "j1",
+        A2 "a2", B2 "b2", C2 "c2", D2 "d2", E2 "e2", F2 "f2",
+        G2 "g2", H2 "h2", I2 "i2", J2 "j2",
+        A3 "a3", B3 "b3", C3 "c3", D3 "d3", E3 "e3", F3 "f3",
+        G3 "g3", H3 "h3", I3 "i3", J3 "j3",
+        A4 "a4", B4 "b4", C4 "c4", D4 "d4", E4 "e4", F4 "f4",
+        G4 "g4", H4 "h4", I4 "i4", J4 "j4",
+        A5 "a5", B5 "b5", C5 "c5", D5 "d5", E5 "e5", F5 "f5",
+        G5 "g5", H5 "h5", I5 "i5", J5 "j5",
+        A6 "a6", B6 "b6", C6 "c6", D6 "d6", E6 "e6", F6 "f6",
+        G6 "g6", H6 "h6", I6 "i6", J6 "j6",
+        A7 "a7", B7 "b7", C7 "c7", D7 "d7", E7 "e7", F7 "f7",
+        G7 "g7", H7 "h7", I7 "i7", J7 "j7",
+        A8 "a8", B8 "b8", C8 "c8", D8 "d8", E8 "e8", F8 "f8",
+        G8 "g8", H8 "h8", I8 "i8", J8 "j8",
+        A9 "a9", B9 "b9", C9 "c9", D9 "d9", E9 "e9", F9 "f9",
+        G9 "g9", H9 "h9", I9 "i9", J9 "j9",
+        A10 "a10", B10 "b10", C10 "c10", D10 "d10", E10 "e10", F10 "f10",
+        G10 "g10", H10 "h10", I10 "i10", J10 "j10",
+    }
+);
+
+impl SquareType for Square {
+    type File = File;
+    type Rank = Rank;
+}
+
+representable_type!(
+    /// File represents a file on an Amazons Board. There are 10 of them in
+    /// total.
+    enum File: u8 {
+        A "a", B "b", C "c", D "d", E "e", F "f",
+        G "g", H "h", I "i", J "j",
+    }
+);
+
+representable_type!(
+    /// Rank represents a rank on an Amazons Board. There are 10 of them in
+    /// total.
+    enum Rank: u8 {
+        First "1", Second "2", Third "3", Fourth "4", Fifth "5", Sixth "6",
+        Seventh "7", Eighth "8", Ninth "9", Tenth "10",
+    }
+);
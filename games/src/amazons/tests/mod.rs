@@ -0,0 +1,64 @@
+use crate::amazons::{Color, Move, Position, Square};
+use crate::interface::{MoveList, PositionType};
+use crate::perft;
+use std::str::FromStr;
+
+macro_rules! perft_test {
+    ($name:ident $pos:literal $depth:literal $nodes:literal) => {
+        #[test]
+        fn $name() {
+            let position = Position::from_str($pos).unwrap();
+            assert_eq!(perft::<true, true, _>(position, $depth), $nodes)
+        }
+    };
+}
+
+perft_test!(startpos_01 "3b2b3/10/10/b8b/10/10/w8w/10/10/3w2w3 w" 1 2176);
+perft_test!(startpos_02 "3b2b3/10/10/b8b/10/10/w8w/10/10/3w2w3 w" 2 4307152);
+perft_test!(position_01
+    "3b2b3/10/2x7/b3x4b/5x4/2x7/w3x3xw/10/10/3w2w3 b" 2 1476154);
+perft_test!(position_02
+    "10/10/10/10/xx3bxxxx/xx4xxxx/xx4xxxx/xxw2wxxxx/10/10 b" 1 403);
+perft_test!(position_03
+    "xxxxxxxxxx/xxxxxxxxxx/xxxxxxxxxx/xxxxxxxxxx/xx3bxxxx/xx4xxxx/xx4xxxx/xxw2wxxxx/xxxxxxxxxx/xxxxxxxxxx b"
+    4 11920410);
+
+#[test]
+fn move_generation() {
+    let position = Position::default();
+    let moves = position.generate_moves::<false, true, true>();
+
+    // The starting position has more moves than fit in a default MoveList.
+    assert!(moves.len() > MoveList::<Move>::new().capacity());
+    assert_eq!(moves.len(), position.count_moves::<true, true>());
+    assert!(moves.contains(&Move::new(Square::D1, Square::D7, Square::G7)));
+    assert!(moves.contains(&Move::new(Square::D1, Square::D2, Square::D1)));
+
+    // All Amazons moves are noisy.
+    assert!(position.generate_moves::<false, true, false>().is_empty());
+    assert_eq!(position.count_moves::<true, false>(), 0);
+}
+
+#[test]
+fn winner() {
+    let trapped =
+        Position::from_str("bx8/xx8/10/10/10/10/10/10/10/9w b").unwrap();
+    assert_eq!(trapped.winner(), Some(Color::White));
+    assert_eq!(trapped.count_moves::<true, true>(), 0);
+
+    let ongoing =
+        Position::from_str("bx8/x9/10/10/10/10/10/10/10/9w b").unwrap();
+    assert_eq!(ongoing.winner(), None);
+    assert_eq!(Position::default().winner(), None);
+}
+
+#[test]
+fn move_strings() {
+    for mov in ["d1d7/g7", "j4j10/a10", "a7a8/a7"] {
+        assert_eq!(Move::from_str(mov).unwrap().to_string(), mov);
+    }
+
+    for mov in ["d1d7", "d1/g7", "d1d11/g7", "null"] {
+        assert!(Move::from_str(mov).is_err(), "{}", mov);
+    }
+}
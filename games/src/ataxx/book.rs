@@ -0,0 +1,397 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Opening books for ataxx.
+//!
+//! A [`Book`] maps the [`struct@Hash`] of a Position to the moves which can be
+//! played from it, along with a weight and some learn data for each of them.
+//! Books are stored on disk as a sorted array of 16-byte [`BookEntry`]s, in a
+//! layout similar to the Polyglot format used by chess engines:
+//!
+//! | bytes  | field                          |
+//! |--------|--------------------------------|
+//! | 0..8   | position hash                  |
+//! | 8..10  | move                           |
+//! | 10..12 | weight                         |
+//! | 12..16 | learn data                     |
+//!
+//! All the fields are stored in big-endian byte order. Books are usually
+//! created from game records with a [`BookBuilder`].
+
+use std::collections::HashMap;
+use std::io;
+use std::io::{Read, Write};
+
+use thiserror::Error;
+
+use crate::ataxx::{Color, Move, Position, Square};
+use crate::interface::{Hash, MoveError, PositionType};
+use crate::random::Rng;
+
+/// BookEntry represents a single move in a [`Book`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BookEntry {
+    /// hash is the Hash of the Position the move is played in.
+    pub hash: Hash,
+    /// mov is the Move played in the Position.
+    pub mov: Move,
+    /// weight is the relative likelihood of the Move being picked.
+    pub weight: u16,
+    /// learn stores extra data about the Move. Books generated by a
+    /// [`BookBuilder`] store the number of games the Move was played in.
+    pub learn: u32,
+}
+
+impl BookEntry {
+    /// SIZE is the number of bytes taken by a BookEntry on disk.
+    pub const SIZE: usize = 16;
+
+    /// to_bytes returns the on-disk representation of the BookEntry.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        bytes[0..8].copy_from_slice(&u64::from(self.hash).to_be_bytes());
+        bytes[8..10].copy_from_slice(&u16::from(self.mov).to_be_bytes());
+        bytes[10..12].copy_from_slice(&self.weight.to_be_bytes());
+        bytes[12..16].copy_from_slice(&self.learn.to_be_bytes());
+        bytes
+    }
+
+    /// from_bytes parses a BookEntry from its on-disk representation, checking
+    /// that its Move is a pass or is between valid Squares.
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, BookError> {
+        let mov = u16::from_be_bytes(bytes[8..10].try_into().unwrap());
+        Ok(BookEntry {
+            hash: Hash::new(u64::from_be_bytes(
+                bytes[0..8].try_into().unwrap(),
+            )),
+            mov: Self::parse_move(mov).ok_or(BookError::BadMove(mov))?,
+            weight: u16::from_be_bytes(bytes[10..12].try_into().unwrap()),
+            learn: u32::from_be_bytes(bytes[12..16].try_into().unwrap()),
+        })
+    }
+
+    /// parse_move parses the binary representation of a Move, which has its
+    /// source Square in the lowest 6 bits and its target Square in the next 6.
+    /// Any other Move is invalid, unless it is a pass.
+    fn parse_move(mov: u16) -> Option<Move> {
+        let source = Square::try_from((mov & 0x3f) as u8).ok();
+        let target = Square::try_from((mov >> 6 & 0x3f) as u8).ok();
+        let valid = mov >> 12 == 0 && source.is_some() && target.is_some();
+        (valid || Move::from(mov) == Move::PASS).then_some(Move::from(mov))
+    }
+}
+
+/// BookError represents an error encountered while reading a [`Book`].
+#[derive(Error, Debug)]
+pub enum BookError {
+    #[error("reading book: {0}")]
+    Io(#[from] io::Error),
+    #[error("book size of {0} bytes is not a multiple of the entry size")]
+    BadLength(usize),
+    #[error("book entry {0} is not sorted by hash")]
+    Unsorted(usize),
+    #[error("book entry has invalid move {0:#06x}")]
+    BadMove(u16),
+}
+
+/// Book is an opening book, which stores the moves for Positions by their
+/// [`struct@Hash`]. The entries of a Book are always sorted by their Hash, so
+/// probing the Book is a binary search.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Book {
+    entries: Vec<BookEntry>,
+}
+
+impl Book {
+    /// new creates a new Book from the given entries, which are sorted by their
+    /// Hash and then by their weight, with the heaviest first.
+    #[must_use]
+    pub fn new(mut entries: Vec<BookEntry>) -> Self {
+        entries.sort_by_key(|entry| {
+            (u64::from(entry.hash), u16::MAX - entry.weight)
+        });
+        Book { entries }
+    }
+
+    /// entries returns all the entries in the Book.
+    #[must_use]
+    pub fn entries(&self) -> &[BookEntry] {
+        &self.entries
+    }
+
+    /// len returns the number of entries in the Book.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// is_empty checks if the Book has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// from_bytes parses a Book from its on-disk representation, checking that
+    /// the entries are sorted by their Hash and that their Moves are valid.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BookError> {
+        if !bytes.len().is_multiple_of(BookEntry::SIZE) {
+            return Err(BookError::BadLength(bytes.len()));
+        }
+
+        let entries = bytes
+            .chunks_exact(BookEntry::SIZE)
+            .map(|chunk| BookEntry::from_bytes(chunk.try_into().unwrap()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (i, pair) in entries.windows(2).enumerate() {
+            if u64::from(pair[0].hash) > u64::from(pair[1].hash) {
+                return Err(BookError::Unsorted(i + 1));
+            }
+        }
+
+        Ok(Book { entries })
+    }
+
+    /// to_bytes returns the on-disk representation of the Book.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.entries.iter().flat_map(BookEntry::to_bytes).collect()
+    }
+
+    /// read reads a Book from the given reader, see [`Book::from_bytes`].
+    pub fn read(mut reader: impl Read) -> Result<Self, BookError> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// write writes the Book into the given writer.
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    /// probe returns the entries of the Book for the given Position. Entries
+    /// for Moves which aren't legal in the Position, which can happen due to
+    /// Hash collisions, are skipped.
+    pub fn probe<'a>(
+        &'a self,
+        position: &'a Position,
+    ) -> impl Iterator<Item = &'a BookEntry> + 'a {
+        let hash = u64::from(position.hash());
+        let start = self
+            .entries
+            .partition_point(|entry| u64::from(entry.hash) < hash);
+
+        self.entries[start..]
+            .iter()
+            .take_while(move |entry| u64::from(entry.hash) == hash)
+            .filter(|entry| position.is_legal(entry.mov))
+    }
+
+    /// best returns the Move with the highest weight for the given Position,
+    /// if the Book has any Move with a non-zero weight for it.
+    pub fn best(&self, position: &Position) -> Option<Move> {
+        self.probe(position)
+            .filter(|entry| entry.weight > 0)
+            .max_by_key(|entry| entry.weight)
+            .map(|entry| entry.mov)
+    }
+
+    /// pick returns a random Move for the given Position, where the chance of
+    /// a Move being picked is proportional to its weight. Moves with a weight
+    /// of zero are never picked.
+    /// ```
+    /// use tetka_games::ataxx::book::{Book, BookEntry};
+    /// use tetka_games::ataxx::{Move, Position, Square};
+    /// use tetka_games::interface::PositionType;
+    /// use tetka_games::random::Rng;
+    /// use std::str::FromStr;
+    ///
+    /// let position = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    /// let book = Book::new(vec![BookEntry {
+    ///     hash: position.hash(),
+    ///     mov: Move::new_single(Square::B6),
+    ///     weight: 1,
+    ///     learn: 0,
+    /// }]);
+    ///
+    /// let mut rng = Rng::new(0);
+    /// assert_eq!(book.pick(&position, &mut rng), Some(Move::new_single(Square::B6)));
+    /// ```
+    pub fn pick(&self, position: &Position, rng: &mut Rng) -> Option<Move> {
+        let total: usize = self
+            .probe(position)
+            .map(|entry| entry.weight as usize)
+            .sum();
+        if total == 0 {
+            return None;
+        }
+
+        let mut choice = rng.below(total);
+        for entry in self.probe(position) {
+            if choice < entry.weight as usize {
+                return Some(entry.mov);
+            }
+
+            choice -= entry.weight as usize;
+        }
+
+        unreachable!()
+    }
+
+    /// export_epd writes the FENs of all the distinct Positions reached by
+    /// playing exactly `plies` Moves with a non-zero weight from the given root
+    /// Position, one per line, which is the format used by match runners for
+    /// their opening suites. It returns the number of Positions written.
+    pub fn export_epd(
+        &self,
+        root: Position,
+        plies: usize,
+        mut writer: impl Write,
+    ) -> io::Result<usize> {
+        let mut fens = vec![];
+        self.collect_lines(root, plies, &mut fens);
+
+        fens.sort();
+        fens.dedup();
+
+        for fen in &fens {
+            writeln!(writer, "{}", fen)?;
+        }
+
+        Ok(fens.len())
+    }
+
+    fn collect_lines(
+        &self,
+        position: Position,
+        plies: usize,
+        fens: &mut Vec<String>,
+    ) {
+        if plies == 0 {
+            fens.push(position.fen());
+            return;
+        }
+
+        for entry in self.probe(&position).filter(|entry| entry.weight > 0) {
+            let child = position.after_move::<true>(entry.mov);
+            self.collect_lines(child, plies - 1, fens);
+        }
+    }
+}
+
+/// BookBuilder builds a [`Book`] from a set of game records. Every Move played
+/// in the first `max_plies` plies of a game is added to the Book, with its
+/// weight decided by how well the Move scored. Like in Polyglot, a win is worth
+/// two points and a draw one point for the side which played the Move.
+/// ```
+/// use tetka_games::ataxx::book::BookBuilder;
+/// use tetka_games::ataxx::{Color, Move, Position};
+/// use std::str::FromStr;
+///
+/// let root = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+/// let game = ["b6", "f6", "c5"].map(|m| Move::from_str(m).unwrap());
+///
+/// let mut builder = BookBuilder::new(8);
+/// builder.add_game(root, &game, Some(Color::Black)).unwrap();
+///
+/// let book = builder.build();
+/// assert_eq!(book.best(&root), Some(game[0]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BookBuilder {
+    max_plies: usize,
+    min_games: u32,
+    stats: HashMap<(u64, u16), MoveStats>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct MoveStats {
+    games: u32,
+    points: u32,
+}
+
+impl BookBuilder {
+    /// new creates a new BookBuilder which only adds the first `max_plies`
+    /// plies of every game to the Book.
+    #[must_use]
+    pub fn new(max_plies: usize) -> Self {
+        BookBuilder {
+            max_plies,
+            min_games: 1,
+            stats: HashMap::new(),
+        }
+    }
+
+    /// min_games sets the number of games a Move needs to be played in to be
+    /// added to the Book.
+    #[must_use]
+    pub fn min_games(mut self, games: u32) -> Self {
+        self.min_games = games;
+        self
+    }
+
+    /// add_game adds the given game, which starts from the given root Position
+    /// and has the given `winner`, to the Book. A `winner` of `None` means the
+    /// game was a draw. Every Move of the game is checked for legality, and an
+    /// error is returned without adding the game if an illegal Move is found.
+    pub fn add_game(
+        &mut self,
+        root: Position,
+        moves: &[Move],
+        winner: Option<Color>,
+    ) -> Result<(), MoveError<Square>> {
+        let mut updates = vec![];
+
+        let mut position = root;
+        for &mov in moves.iter().take(self.max_plies) {
+            position.check_move(mov)?;
+
+            let points = match winner {
+                Some(color) if color == position.side_to_move => 2,
+                Some(_) => 0,
+                None => 1,
+            };
+
+            updates
+                .push(((u64::from(position.hash()), u16::from(mov)), points));
+            position = position.after_move::<true>(mov);
+        }
+
+        for (key, points) in updates {
+            let stats = self.stats.entry(key).or_default();
+            stats.games += 1;
+            stats.points += points;
+        }
+
+        Ok(())
+    }
+
+    /// build creates a Book from the games added to the BookBuilder.
+    #[must_use]
+    pub fn build(&self) -> Book {
+        Book::new(
+            self.stats
+                .iter()
+                .filter(|(_, stats)| stats.games >= self.min_games)
+                .map(|(&(hash, mov), stats)| BookEntry {
+                    hash: Hash::new(hash),
+                    mov: Move::from(mov),
+                    weight: stats.points.min(u16::MAX as u32) as u16,
+                    learn: stats.games,
+                })
+                .collect(),
+        )
+    }
+}
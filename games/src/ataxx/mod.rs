@@ -1,3 +1,11 @@
+// Namespaced modules.
+#[cfg(feature = "std")]
+pub mod book;
+#[cfg(feature = "std")]
+pub mod tablebase;
+#[cfg(feature = "std")]
+pub mod training;
+
 // Make the contents of the non-namespaced
 // modules public, so they can be accessed
 // without their parent namespace.
@@ -11,22 +11,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt;
-use std::str::FromStr;
+use core::fmt;
+use core::str::FromStr;
 
 use thiserror::Error;
 
 use crate::ataxx::Square;
-use crate::interface::{MoveType, RepresentableType, TypeParseError};
+use crate::interface::{MoveList, MoveType, RepresentableType, TypeParseError};
 
 /// Move represents an Ataxx move which can be played on the Board.
 #[derive(Copy, Clone, PartialEq, Eq, Default)]
 pub struct Move(u16);
 
 impl MoveType for Move {
+    type Repr = u16;
+
     const NULL: Self = Move(1 << 15);
     const MAX_IN_GAME: usize = 256;
     const MAX_IN_POSITION: usize = 256;
+
+    type List = MoveList<Self, { Self::MAX_IN_POSITION }>;
 }
 
 impl From<u16> for Move {
@@ -60,7 +64,7 @@ impl Move {
     /// ```
     /// use tetka_games::ataxx::*;
     /// use tetka_games::interface::PositionType;
-    /// use std::str::FromStr;
+    /// use core::str::FromStr;
     ///
     /// let old_pos = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
     /// let new_pos = old_pos.after_move::<true>(Move::PASS);
@@ -176,7 +180,7 @@ impl FromStr for Move {
     /// inverse of the [`fmt::Display`] trait for [Move].
     /// ```
     /// use tetka_games::ataxx::*;
-    /// use std::str::FromStr;
+    /// use core::str::FromStr;
     ///
     /// let pass = Move::PASS;
     /// let sing = Move::new_single(Square::A1);
@@ -241,6 +245,9 @@ impl fmt::Display for Move {
     }
 }
 
+// Moves are serialized as their move strings.
+crate::interface::serde_string!(Move);
+
 impl fmt::Debug for Move {
     /// Debug formats the given Move into a human-readable debug string. It uses
     /// `Move::Display` trait under the hood for formatting the Move.
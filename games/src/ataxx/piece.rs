@@ -11,9 +11,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt;
-use std::ops;
-use std::str::FromStr;
+use core::fmt;
+use core::ops;
+use core::str::FromStr;
 
 use crate::interface::representable_type;
 use crate::interface::ColoredPieceType;
@@ -36,31 +36,52 @@ impl ops::Not for Color {
 }
 
 representable_type!(
-    /// Piece represents the types of pieces in ataxx, namely Piece and Block.
-    enum Piece: u8 { Piece "x", Block "■", }
+    /// Piece represents the types of pieces in ataxx, namely the Stones which
+    /// belong to either side, and the colorless Blocks which fill gaps.
+    enum Piece: u8 { Stone "s", Block "-", }
 );
 
 representable_type!(
     /// Piece represents all the possible ataxx pieces.
-    enum ColoredPiece: u8 { Black "x", White "o", Block "■", }
+    enum ColoredPiece: u8 { Black "x", White "o", Block "-", }
 );
 
 impl ColoredPieceType for ColoredPiece {
     type Piece = Piece;
     type Color = Color;
 
+    /// new creates a new ColoredPiece from the given Piece and Color. Since a
+    /// Block doesn't have a Color, the given Color is ignored for Blocks.
+    /// ```
+    /// use tetka_games::ataxx::*;
+    /// use tetka_games::interface::ColoredPieceType;
+    ///
+    /// let piece = ColoredPiece::new(Piece::Stone, Color::White);
+    /// let block = ColoredPiece::new(Piece::Block, Color::White);
+    ///
+    /// assert_eq!(piece, ColoredPiece::White);
+    /// assert_eq!(block, ColoredPiece::Block);
+    /// ```
+    fn new(piece: Piece, color: Color) -> Self {
+        match (piece, color) {
+            (Piece::Stone, Color::Black) => ColoredPiece::Black,
+            (Piece::Stone, Color::White) => ColoredPiece::White,
+            (Piece::Block, _) => ColoredPiece::Block,
+        }
+    }
+
     fn piece(self) -> Piece {
         match self {
-            ColoredPiece::Black | ColoredPiece::White => Piece::Piece,
+            ColoredPiece::Black | ColoredPiece::White => Piece::Stone,
             ColoredPiece::Block => Piece::Block,
         }
     }
 
-    fn color(self) -> Color {
+    fn color(self) -> Option<Color> {
         match self {
-            ColoredPiece::Black => Color::Black,
-            ColoredPiece::White => Color::White,
-            _ => panic!("Piece::color() called on Piece::Block"),
+            ColoredPiece::Black => Some(Color::Black),
+            ColoredPiece::White => Some(Color::White),
+            ColoredPiece::Block => None,
         }
     }
 }
@@ -11,10 +11,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::cmp;
-use std::fmt;
-use std::num::ParseIntError;
-use std::str::FromStr;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+
+use core::cmp;
+use core::fmt;
+use core::num::ParseIntError;
+use core::str::FromStr;
 
 use strum::IntoEnumIterator;
 
@@ -22,7 +26,9 @@ use crate::interface;
 use crate::interface::PiecePlacementParseError;
 use crate::interface::PositionType;
 use crate::interface::TypeParseError;
-use crate::interface::{BitBoardType, Hash, RepresentableType, SquareType};
+use crate::interface::{
+    BitBoardType, ColoredPieceType, Hash, RepresentableType, SquareType,
+};
 
 use thiserror::Error;
 
@@ -31,7 +37,7 @@ use crate::ataxx::{
     BitBoard, ColoredPiece, File, Move,
     Rank, Square, Color, Piece
 };
-use crate::interface::MoveStore;
+use crate::interface::{MoveError, MoveStore};
 
 /// Position represents the snapshot of an Ataxx Board, the state of the an
 /// ataxx game at a single point in time. It also provides all of the methods
@@ -75,11 +81,17 @@ impl PositionType for Position {
     }
 
     fn piece_bb(&self, piece: Piece) -> BitBoard {
-        self.bitboards[piece as usize]
+        match piece {
+            Piece::Stone => {
+                self.colored_piece_bb(ColoredPiece::Black)
+                    | self.colored_piece_bb(ColoredPiece::White)
+            }
+            Piece::Block => self.colored_piece_bb(ColoredPiece::Block),
+        }
     }
 
     fn color_bb(&self, color: Color) -> BitBoard {
-        self.bitboards[color as usize]
+        self.colored_piece_bb(ColoredPiece::new(Piece::Stone, color))
     }
 
     fn colored_piece_bb(&self, piece: ColoredPiece) -> BitBoard {
@@ -90,6 +102,14 @@ impl PositionType for Position {
         self.checksum
     }
 
+    fn side_to_move(&self) -> Color {
+        self.side_to_move
+    }
+
+    fn fen(&self) -> String {
+        Position::fen(self)
+    }
+
     fn is_game_over(&self) -> bool {
         let black = self.colored_piece_bb(ColoredPiece::Black);
         let white = self.colored_piece_bb(ColoredPiece::White);
@@ -154,7 +174,7 @@ impl PositionType for Position {
                 bitboards: self.bitboards,
                 checksum: update_hash!(!self.checksum),
                 side_to_move: !self.side_to_move,
-                ply_count: self.ply_count + 1,
+                ply_count: self.ply_count.saturating_add(1),
                 half_move_clock: self.half_move_clock + 1,
             };
         }
@@ -190,11 +210,80 @@ impl PositionType for Position {
             ],
             checksum: update_hash!(Self::get_hash(black, white, !stm)),
             side_to_move: !stm,
-            ply_count: self.ply_count + 1,
+            ply_count: self.ply_count.saturating_add(1),
             half_move_clock,
         }
     }
 
+    fn is_legal(&self, m: Move) -> bool {
+        if m == Move::NULL || self.is_game_over() {
+            return false;
+        }
+
+        let stm = self.color_bb(self.side_to_move);
+        let allowed = !(stm
+            | self.color_bb(!self.side_to_move)
+            | self.colored_piece_bb(ColoredPiece::Block));
+
+        if m == Move::PASS {
+            return !self.has_moves();
+        }
+
+        let target = m.target();
+        if m.is_single() {
+            // Singular moves are legal if any piece can clone itself to the
+            // empty target Square.
+            allowed.contains(target)
+                && !(BitBoard::single(target) & stm).is_empty()
+        } else {
+            stm.contains(m.source())
+                && allowed.contains(target)
+                && BitBoard::double(m.source()).contains(target)
+        }
+    }
+
+    fn check_move(&self, m: Move) -> Result<(), MoveError<Square>> {
+        if m == Move::NULL {
+            return Err(MoveError::Illegal(m.to_string()));
+        }
+
+        if self.is_game_over() {
+            return Err(MoveError::GameOver);
+        }
+
+        if m == Move::PASS {
+            return if self.has_moves() {
+                Err(MoveError::PassWithMovesAvailable)
+            } else {
+                Ok(())
+            };
+        }
+
+        let stm = self.color_bb(self.side_to_move);
+        let (source, target) = (m.source(), m.target());
+
+        if !m.is_single() && !stm.contains(source) {
+            return Err(MoveError::NoPieceOnSource(source));
+        }
+
+        if self.at(target).is_some() {
+            return Err(MoveError::OccupiedTarget(target));
+        }
+
+        if m.is_single() {
+            if (BitBoard::single(target) & stm).is_empty() {
+                return Err(MoveError::Unreachable(target));
+            }
+        } else if BitBoard::single(source).contains(target) {
+            // Singular moves are only represented by their target Square.
+            return Err(MoveError::Illegal(m.to_string()));
+        } else if !BitBoard::double(source).contains(target) {
+            return Err(MoveError::TooFar(source, target));
+        }
+
+        Ok(())
+    }
+
     fn generate_moves_into<
         const ALLOW_ILLEGAL: bool,
         const QUIET: bool,
@@ -270,6 +359,71 @@ impl PositionType for Position {
 }
 
 impl Position {
+    /// new creates a new Position from the BitBoards of its pieces, with the
+    /// given side to move. The half-move clock and ply count are set to zero.
+    /// ```
+    /// use tetka_games::ataxx::*;
+    /// use tetka_games::interface::{BitBoardType, PositionType};
+    /// use core::str::FromStr;
+    ///
+    /// let black = BitBoard::from(Square::A7) | Square::G1;
+    /// let white = BitBoard::from(Square::G7) | Square::A1;
+    /// let position = Position::new(black, white, BitBoard::EMPTY, Color::Black);
+    ///
+    /// let startpos = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    /// assert_eq!(position.fen(), startpos.fen());
+    /// assert_eq!(position.hash(), startpos.hash());
+    /// ```
+    pub fn new(
+        black: BitBoard,
+        white: BitBoard,
+        block: BitBoard,
+        side_to_move: Color,
+    ) -> Position {
+        Position {
+            bitboards: [black, white, block],
+            checksum: Self::get_hash(black, white, side_to_move),
+            side_to_move,
+            ply_count: 0,
+            half_move_clock: 0,
+        }
+    }
+
+    /// fen returns the FEN string of the current Position, with all the four
+    /// fields present. This is the inverse of parsing a Position from a FEN.
+    /// ```
+    /// use tetka_games::ataxx::*;
+    /// use core::str::FromStr;
+    ///
+    /// let fen = "x5o/7/2-1-2/7/2-1-2/7/o5x o 12 7";
+    /// let position = Position::from_str(fen).unwrap();
+    ///
+    /// assert_eq!(position.fen(), fen);
+    /// ```
+    pub fn fen(&self) -> String {
+        format!(
+            "{} {} {} {}",
+            interface::format_piece_placement(self),
+            self.side_to_move,
+            self.half_move_clock,
+            self.ply_count / 2 + 1,
+        )
+    }
+
+    /// has_moves checks if the side to move has any moves other than a pass,
+    /// without checking if the game is over.
+    fn has_moves(&self) -> bool {
+        let stm = self.color_bb(self.side_to_move);
+        let allowed = !(stm
+            | self.color_bb(!self.side_to_move)
+            | self.colored_piece_bb(ColoredPiece::Block));
+
+        !(BitBoard::singles(stm) & allowed).is_empty()
+            || stm
+                .into_iter()
+                .any(|piece| !(BitBoard::double(piece) & allowed).is_empty())
+    }
+
     fn get_hash(black: BitBoard, white: BitBoard, stm: Color) -> Hash {
         let a = black.into();
         let b = white.into();
@@ -308,36 +462,70 @@ impl Position {
 }
 
 /// PositionParseErr represents an error encountered while parsing
-/// the given FEN position field into a valid Position.
-#[derive(Error, Debug)]
+/// the given FEN position field into a valid Position. Errors in a field
+/// report the 1-based column in the FEN string where the parsing failed.
+#[derive(Error, Debug, PartialEq, Eq)]
 pub enum PositionParseError {
-    #[error("expected 3 fields, found {0}")]
-    TooManyFields(usize),
-
-    #[error("parsing piece placement: {0}")]
-    BadPiecePlacement(#[from] PiecePlacementParseError),
+    #[error("expected 2 to 4 fields, found {0}")]
+    WrongFieldNumber(usize),
+
+    #[error("column {0}: parsing piece placement: {1}")]
+    BadPiecePlacement(usize, PiecePlacementParseError),
+
+    #[error("column {0}: parsing side to move: {1}")]
+    BadSideToMove(usize, TypeParseError),
+    #[error("column {0}: parsing half-move clock: {1}")]
+    BadHalfMoveClock(usize, ParseIntError),
+    #[error("column {0}: parsing full-move number: {1}")]
+    BadFullMoveNumber(usize, ParseIntError),
+    #[error("column {0}: full-move number should be at least 1")]
+    ZeroFullMoveNumber(usize),
+    #[error("column {0}: full-move number is too large")]
+    FullMoveNumberTooLarge(usize),
+}
 
-    #[error("parsing side to move: {0}")]
-    BadSideToMove(#[from] TypeParseError),
-    #[error("parsing half-move clock: {0}")]
-    BadHalfMoveClock(#[from] ParseIntError),
+impl PositionParseError {
+    /// Returns the 1-based column in the FEN string where the parsing failed,
+    /// if the error can be attributed to a single field.
+    pub fn column(&self) -> Option<usize> {
+        match self {
+            Self::WrongFieldNumber(_) => None,
+            Self::BadPiecePlacement(column, _)
+            | Self::BadSideToMove(column, _)
+            | Self::BadHalfMoveClock(column, _)
+            | Self::BadFullMoveNumber(column, _)
+            | Self::ZeroFullMoveNumber(column)
+            | Self::FullMoveNumberTooLarge(column) => Some(*column),
+        }
+    }
 }
 
-// FromStr implements parsing of the position field in a FEN.
+// FromStr implements parsing of the position field in a FEN. The FEN fields
+// are the piece placement, the side to move, the half-move clock, and the
+// full-move number, out of which the last two are optional and default to 0
+// and 1 respectively. Gaps are represented by '-' in the piece placement.
 impl FromStr for Position {
     type Err = PositionParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split(' ').collect::<Vec<&str>>();
+        // Split the FEN into its fields, along with the column of each one.
+        // The columns are counted in characters from the end of the previous
+        // field, so that the whole FEN is only walked through once.
+        let mut parts = vec![];
+        let (mut column, mut counted) = (1, 0);
+        for field in s.split_whitespace() {
+            let offset = field.as_ptr() as usize - s.as_ptr() as usize;
+            column += s[counted..offset].chars().count();
+            counted = offset;
+            parts.push((column, field));
+        }
 
-        if parts.len() != 4 {
-            return Err(PositionParseError::TooManyFields(parts.len()));
+        if parts.len() < 2 || parts.len() > 4 {
+            return Err(PositionParseError::WrongFieldNumber(parts.len()));
         }
 
-        let pos = parts[0];
-        let stm = parts[1];
-        let hmc = parts[2];
-        let fmc = parts[3];
+        let (pos_col, pos) = &parts[0];
+        let (stm_col, stm) = &parts[1];
 
         let mut position = Position {
             bitboards: [BitBoard::EMPTY; ColoredPiece::N],
@@ -347,13 +535,38 @@ impl FromStr for Position {
             half_move_clock: 0,
         };
 
-        interface::parse_piece_placement(&mut position, pos)?;
+        interface::parse_piece_placement(&mut position, pos).map_err(
+            |err| {
+                PositionParseError::BadPiecePlacement(
+                    pos_col + err.column() - 1,
+                    err,
+                )
+            },
+        )?;
+
+        position.side_to_move = Color::from_str(stm)
+            .map_err(|err| PositionParseError::BadSideToMove(*stm_col, err))?;
+
+        if let Some((hmc_col, hmc)) = parts.get(2) {
+            position.half_move_clock = hmc.parse::<u8>().map_err(|err| {
+                PositionParseError::BadHalfMoveClock(*hmc_col, err)
+            })?;
+        }
 
-        position.side_to_move = Color::from_str(stm)?;
-        position.half_move_clock = hmc.parse::<u8>()?;
-        position.ply_count = fmc.parse::<u16>()? * 2 - 1;
-        if position.side_to_move == Color::Black {
-            position.ply_count -= 1;
+        position.ply_count = (position.side_to_move == Color::White).into();
+        if let Some((fmc_col, fmc)) = parts.get(3) {
+            let full_move_number = fmc.parse::<u16>().map_err(|err| {
+                PositionParseError::BadFullMoveNumber(*fmc_col, err)
+            })?;
+
+            // The ply count has to fit in a u16 along with the full-move
+            // number's conversion into plies.
+            position.ply_count = full_move_number
+                .checked_sub(1)
+                .ok_or(PositionParseError::ZeroFullMoveNumber(*fmc_col))?
+                .checked_mul(2)
+                .and_then(|plies| plies.checked_add(position.ply_count))
+                .ok_or(PositionParseError::FullMoveNumberTooLarge(*fmc_col))?;
         }
 
         // Calculate the Hash value for the Position.
@@ -394,3 +607,6 @@ impl fmt::Display for Position {
         writeln!(f, "Side To Move: {}", self.side_to_move)
     }
 }
+
+// Positions are serialized as their FEN strings.
+crate::interface::serde_string!(Position, crate::interface::PositionType::fen);
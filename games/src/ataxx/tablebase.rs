@@ -0,0 +1,460 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Endgame tablebases for ataxx.
+//!
+//! Ataxx endgames with only a few empty Squares left can be solved exactly.
+//! Since the number of empty Squares never increases during a game, all the
+//! Positions reachable from a root Position form a finite graph, which is
+//! solved by [`Tablebase::generate`] with retrograde analysis. The resulting
+//! [`Tablebase`] stores the win/draw/loss [`Outcome`] of every Position in the
+//! graph, along with the distance in plies to the end of the game under
+//! optimal play, where the winning side tries to win as fast as possible.
+//!
+//! Jump moves can move the empty Squares around and flip the Stones near them,
+//! so on an open Board even two empty Squares can lead to billions of
+//! reachable Positions. Tablebases are therefore practical for small Boards,
+//! which are made by filling the rest of the Board with Blocks, and for
+//! endgames where the empty Squares are walled off by Blocks. The number of
+//! Positions explored is capped, and generation fails instead of running out
+//! of memory on bigger graphs.
+//!
+//! Tablebases ignore the half-move clock, so Positions in which both sides can
+//! avoid losing forever are treated as draws. On disk, a Tablebase is stored
+//! as a 16-byte header followed by the entries sorted by their keys:
+//!
+//! | bytes  | field                                      |
+//! |--------|--------------------------------------------|
+//! | 0..4   | magic bytes `TKTB`                         |
+//! | 4..6   | format version                             |
+//! | 6      | maximum number of empty Squares            |
+//! | 7      | reserved, always zero                      |
+//! | 8..16  | number of entries                          |
+//!
+//! Every entry is 10 bytes long, made up of an 8-byte key derived from the
+//! Position's [`struct@Hash`] and its Blocks, and a 2-byte [`Outcome`] with
+//! the win/draw/loss in the top 2 bits and the distance in the rest. All the
+//! fields are stored in big-endian byte order.
+
+use std::collections::{HashMap, VecDeque};
+use std::io;
+use std::io::{Read, Write};
+
+use thiserror::Error;
+
+use crate::ataxx::{BitBoard, ColoredPiece, Position};
+use crate::interface::{BitBoardType, Hash, PositionType, Wdl};
+
+/// Outcome represents the solved result of a Position for the side to move,
+/// along with the number of plies until the game ends. The distance of drawn
+/// Positions is always zero.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    pub wdl: Wdl,
+    pub distance: u16,
+}
+
+impl Outcome {
+    /// MAX_DISTANCE is the largest distance which can be stored in a table.
+    pub const MAX_DISTANCE: u16 = (1 << 14) - 1;
+
+    const DRAW: Outcome = Outcome {
+        wdl: Wdl::Draw,
+        distance: 0,
+    };
+
+    fn to_bits(self) -> u16 {
+        (self.wdl as u16) << 14 | self.distance
+    }
+
+    fn from_bits(bits: u16) -> Option<Self> {
+        let wdl = match bits >> 14 {
+            0 => Wdl::Loss,
+            1 => Wdl::Draw,
+            2 => Wdl::Win,
+            _ => return None,
+        };
+
+        Some(Outcome {
+            wdl,
+            distance: bits & Self::MAX_DISTANCE,
+        })
+    }
+}
+
+/// TablebaseError represents an error encountered while generating, reading,
+/// or writing a [`Tablebase`].
+#[derive(Error, Debug)]
+pub enum TablebaseError {
+    #[error("root position has {0} empty squares, more than the maximum {1}")]
+    TooManyEmpty(usize, usize),
+    #[error("more than {0} positions are reachable from the roots")]
+    TooManyPositions(usize),
+    #[error(
+        "game is longer than the maximum distance of {}",
+        Outcome::MAX_DISTANCE
+    )]
+    TooLong,
+
+    #[error("reading tablebase: {0}")]
+    Io(#[from] io::Error),
+    #[error("bad magic bytes, not a tablebase file")]
+    BadMagic,
+    #[error("unsupported tablebase format version {0}")]
+    BadVersion(u16),
+    #[error("expected {0} bytes of entries, found {1}")]
+    BadLength(usize, usize),
+    #[error("tablebase entry {0} is not sorted by key or has a bad outcome")]
+    BadEntry(usize),
+}
+
+/// Tablebase stores the solved [`Outcome`]s of ataxx Positions.
+/// ```
+/// use tetka_games::ataxx::tablebase::{Outcome, Tablebase};
+/// use tetka_games::interface::Wdl;
+/// use tetka_games::ataxx::Position;
+/// use std::str::FromStr;
+///
+/// // A 2x2 Board, where Black can fill the last Square and capture all of
+/// // White's Stones.
+/// let board = "xo-----/o1-----/-------/-------/-------/-------/-------";
+/// let position = Position::from_str(&format!("{} x", board)).unwrap();
+/// let tablebase = Tablebase::generate([position], 1, 100).unwrap();
+///
+/// assert_eq!(tablebase.probe(&position), Some(Outcome { wdl: Wdl::Win, distance: 1 }));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Tablebase {
+    max_empty: u8,
+    entries: Vec<(u64, Outcome)>,
+}
+
+impl Tablebase {
+    /// MAGIC are the bytes every Tablebase file starts with.
+    pub const MAGIC: [u8; 4] = *b"TKTB";
+    /// VERSION is the current version of the on-disk format.
+    pub const VERSION: u16 = 1;
+
+    const HEADER_SIZE: usize = 16;
+    const ENTRY_SIZE: usize = 10;
+
+    /// generate solves all the Positions reachable from the given roots, each
+    /// of which can have at most `max_empty` empty Squares. Generation fails if
+    /// more than `max_positions` Positions are reachable, to keep the memory
+    /// usage in check.
+    pub fn generate(
+        roots: impl IntoIterator<Item = Position>,
+        max_empty: u8,
+        max_positions: usize,
+    ) -> Result<Self, TablebaseError> {
+        let mut graph = Graph::default();
+        for root in roots {
+            let empty = Self::empty(&root).len();
+            if empty > max_empty as usize {
+                return Err(TablebaseError::TooManyEmpty(
+                    empty,
+                    max_empty as usize,
+                ));
+            }
+
+            graph.explore(root, max_positions)?;
+        }
+
+        let outcomes = graph.solve()?;
+
+        let mut entries = graph
+            .positions
+            .iter()
+            .zip(outcomes)
+            .map(|(position, outcome)| (Self::key(position), outcome))
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|&(key, _)| key);
+        entries.dedup_by_key(|&mut (key, _)| key);
+
+        Ok(Tablebase { max_empty, entries })
+    }
+
+    /// solve returns the [`Outcome`] of the given Position, by generating a
+    /// Tablebase for it. See [`Tablebase::generate`] for more details.
+    pub fn solve(
+        position: Position,
+        max_empty: u8,
+        max_positions: usize,
+    ) -> Result<Outcome, TablebaseError> {
+        let tablebase = Self::generate([position], max_empty, max_positions)?;
+        Ok(tablebase.probe(&position).unwrap())
+    }
+
+    /// probe returns the [`Outcome`] of the given Position, if it is present
+    /// in the Tablebase. Probing is a binary search, which is cheap enough to
+    /// be done before evaluating a Position in a search.
+    #[must_use]
+    pub fn probe(&self, position: &Position) -> Option<Outcome> {
+        if Self::empty(position).len() > self.max_empty as usize {
+            return None;
+        }
+
+        let key = Self::key(position);
+        self.entries
+            .binary_search_by_key(&key, |&(key, _)| key)
+            .ok()
+            .map(|index| self.entries[index].1)
+    }
+
+    /// len returns the number of Positions stored in the Tablebase.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// is_empty checks if the Tablebase doesn't store any Positions.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// max_empty returns the maximum number of empty Squares of the Positions
+    /// stored in the Tablebase.
+    #[must_use]
+    pub fn max_empty(&self) -> u8 {
+        self.max_empty
+    }
+
+    /// to_bytes returns the on-disk representation of the Tablebase.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            Self::HEADER_SIZE + self.entries.len() * Self::ENTRY_SIZE,
+        );
+
+        bytes.extend_from_slice(&Self::MAGIC);
+        bytes.extend_from_slice(&Self::VERSION.to_be_bytes());
+        bytes.push(self.max_empty);
+        bytes.push(0);
+        bytes.extend_from_slice(&(self.entries.len() as u64).to_be_bytes());
+
+        for (key, outcome) in &self.entries {
+            bytes.extend_from_slice(&key.to_be_bytes());
+            bytes.extend_from_slice(&outcome.to_bits().to_be_bytes());
+        }
+
+        bytes
+    }
+
+    /// from_bytes parses a Tablebase from its on-disk representation.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TablebaseError> {
+        if bytes.len() < Self::HEADER_SIZE || bytes[0..4] != Self::MAGIC {
+            return Err(TablebaseError::BadMagic);
+        }
+
+        let version = u16::from_be_bytes(bytes[4..6].try_into().unwrap());
+        if version != Self::VERSION {
+            return Err(TablebaseError::BadVersion(version));
+        }
+
+        let max_empty = bytes[6];
+        let count = u64::from_be_bytes(bytes[8..16].try_into().unwrap());
+
+        let data = &bytes[Self::HEADER_SIZE..];
+        let expected = (count as usize).saturating_mul(Self::ENTRY_SIZE);
+        if data.len() != expected {
+            return Err(TablebaseError::BadLength(expected, data.len()));
+        }
+
+        let mut entries = Vec::with_capacity(count as usize);
+        for (i, chunk) in data.chunks_exact(Self::ENTRY_SIZE).enumerate() {
+            let key = u64::from_be_bytes(chunk[0..8].try_into().unwrap());
+            let bits = u16::from_be_bytes(chunk[8..10].try_into().unwrap());
+
+            let outcome =
+                Outcome::from_bits(bits).ok_or(TablebaseError::BadEntry(i))?;
+            if entries.last().is_some_and(|&(last, _)| last >= key) {
+                return Err(TablebaseError::BadEntry(i));
+            }
+
+            entries.push((key, outcome));
+        }
+
+        Ok(Tablebase { max_empty, entries })
+    }
+
+    /// read reads a Tablebase from the given reader.
+    pub fn read(mut reader: impl Read) -> Result<Self, TablebaseError> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// write writes the Tablebase into the given writer.
+    pub fn write(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+
+    fn empty(position: &Position) -> BitBoard {
+        !(position.colored_piece_bb(ColoredPiece::Black)
+            | position.colored_piece_bb(ColoredPiece::White)
+            | position.colored_piece_bb(ColoredPiece::Block))
+    }
+
+    /// key returns the key of the given Position in a Tablebase. The Hash of a
+    /// Position doesn't depend on the Blocks, so they are mixed into it.
+    fn key(position: &Position) -> u64 {
+        let blocks = position.colored_piece_bb(ColoredPiece::Block);
+        let blocks = Hash::from_bitsets(u64::from(blocks) as u128, 0);
+        u64::from(position.hash()) ^ u64::from(blocks).rotate_left(17)
+    }
+}
+
+/// Graph stores the Positions reachable from a set of roots, along with the
+/// Moves between them, for retrograde analysis.
+#[derive(Default)]
+struct Graph {
+    positions: Vec<Position>,
+    indices: HashMap<u64, usize>,
+    children: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    /// explore adds all the Positions reachable from the given root into the
+    /// Graph, with a breadth-first search.
+    fn explore(
+        &mut self,
+        root: Position,
+        max_positions: usize,
+    ) -> Result<(), TablebaseError> {
+        let mut queue = VecDeque::new();
+        if let Some(index) = self.insert(root, max_positions)? {
+            queue.push_back(index);
+        }
+
+        while let Some(index) = queue.pop_front() {
+            let position = self.positions[index];
+            for mov in position.generate_moves::<false, true, true>().iter() {
+                let child = position.after_move::<true>(*mov);
+                let child_index = match self.insert(child, max_positions)? {
+                    Some(child_index) => {
+                        queue.push_back(child_index);
+                        child_index
+                    }
+                    None => self.indices[&Tablebase::key(&child)],
+                };
+
+                self.children[index].push(child_index);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// insert adds the given Position into the Graph, and returns its index if
+    /// it wasn't already present.
+    fn insert(
+        &mut self,
+        mut position: Position,
+        max_positions: usize,
+    ) -> Result<Option<usize>, TablebaseError> {
+        // Tablebases ignore the half-move clock and the move counters.
+        position.half_move_clock = 0;
+        position.ply_count = 0;
+
+        let key = Tablebase::key(&position);
+        if self.indices.contains_key(&key) {
+            return Ok(None);
+        }
+
+        if self.positions.len() >= max_positions {
+            return Err(TablebaseError::TooManyPositions(max_positions));
+        }
+
+        let index = self.positions.len();
+        self.indices.insert(key, index);
+        self.positions.push(position);
+        self.children.push(vec![]);
+        Ok(Some(index))
+    }
+
+    /// solve finds the Outcome of every Position in the Graph, working
+    /// backwards from the Positions where the game is over.
+    fn solve(&self) -> Result<Vec<Outcome>, TablebaseError> {
+        let n = self.positions.len();
+
+        let mut parents = vec![vec![]; n];
+        for (parent, children) in self.children.iter().enumerate() {
+            for &child in children {
+                parents[child].push(parent);
+            }
+        }
+
+        // remaining stores the number of children of a Position which haven't
+        // been proven to be wins for the opponent yet.
+        let mut remaining =
+            self.children.iter().map(Vec::len).collect::<Vec<_>>();
+        let mut outcomes: Vec<Option<Outcome>> = vec![None; n];
+        let mut queue = VecDeque::new();
+
+        for (index, position) in self.positions.iter().enumerate() {
+            let Some(wdl) = position.result() else {
+                continue;
+            };
+
+            outcomes[index] = Some(Outcome { wdl, distance: 0 });
+            if wdl != Wdl::Draw {
+                queue.push_back(index);
+            }
+        }
+
+        // The queue is processed in the order of increasing distance, so the
+        // first time a Position is resolved as a win its distance is minimal,
+        // while a loss is only resolved after its longest child.
+        while let Some(index) = queue.pop_front() {
+            let outcome = outcomes[index].unwrap();
+            if outcome.distance == Outcome::MAX_DISTANCE {
+                return Err(TablebaseError::TooLong);
+            }
+
+            let distance = outcome.distance + 1;
+            for &parent in &parents[index] {
+                if outcomes[parent].is_some() {
+                    continue;
+                }
+
+                match outcome.wdl {
+                    Wdl::Loss => {
+                        outcomes[parent] = Some(Outcome {
+                            wdl: Wdl::Win,
+                            distance,
+                        });
+                        queue.push_back(parent);
+                    }
+                    Wdl::Win => {
+                        remaining[parent] -= 1;
+                        if remaining[parent] == 0 {
+                            outcomes[parent] = Some(Outcome {
+                                wdl: Wdl::Loss,
+                                distance,
+                            });
+                            queue.push_back(parent);
+                        }
+                    }
+                    Wdl::Draw => unreachable!(),
+                }
+            }
+        }
+
+        // Positions which weren't resolved can't be forced to a decisive end.
+        Ok(outcomes
+            .into_iter()
+            .map(|outcome| outcome.unwrap_or(Outcome::DRAW))
+            .collect())
+    }
+}
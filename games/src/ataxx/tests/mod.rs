@@ -1,6 +1,21 @@
-use crate::ataxx::Position;
+use crate::ataxx::book::{Book, BookBuilder, BookEntry, BookError};
+use crate::ataxx::tablebase::{Outcome, Tablebase, TablebaseError};
+use crate::ataxx::training::{
+    feature_indices, DataError, RecordReader, RecordWriter, TrainingRecord,
+    FEATURES,
+};
+use crate::ataxx::{
+    BitBoard, Color, ColoredPiece, Move, Piece, Position, PositionParseError,
+    Square,
+};
+use crate::interface::PiecePlacementParseError::*;
+use crate::interface::{
+    BitBoardType, ColoredPieceType, MoveError, PositionType, Wdl,
+};
 use crate::perft;
+use crate::random::{RandomPositions, Rng};
 use std::str::FromStr;
+use strum::IntoEnumIterator;
 
 macro_rules! perft_test {
     ($name:ident $pos:literal $depth:literal $nodes:literal) => {
@@ -36,3 +51,704 @@ perft_test!(position_09_o "x5o/7/7/7/7/7/o5x o 100 1" 5 0);
 // TODO: Deal with disjointing blockers
 perft_test!(position_10_x "7/7/7/7/-------/-------/x5o x 0 1" 6 175); // 174 ^^
 perft_test!(position_10_o "7/7/7/7/-------/-------/x5o o 0 1" 6 175); // 174 ^^
+
+macro_rules! legality_test {
+    ($name:ident $pos:literal) => {
+        #[test]
+        fn $name() {
+            let position = Position::from_str($pos).unwrap();
+            let moves = position.generate_moves::<false, true, true>();
+
+            // Check every possible Move against the generated ones.
+            let squares = Square::iter();
+            let candidates = squares
+                .clone()
+                .flat_map(|s| Square::iter().map(move |t| Move::new(s, t)))
+                .chain([Move::PASS, Move::NULL]);
+
+            for m in candidates {
+                let legal = moves.contains(&m);
+                assert_eq!(position.is_legal(m), legal, "{}", m);
+                assert_eq!(position.check_move(m).is_ok(), legal, "{}", m);
+            }
+        }
+    };
+}
+
+macro_rules! parse_move_test {
+    ($name:ident $pos:literal $mov:literal $result:expr) => {
+        #[test]
+        fn $name() {
+            let position = Position::from_str($pos).unwrap();
+            let result: Result<Move, MoveError<Square>> = $result;
+            assert_eq!(position.parse_move($mov), result);
+        }
+    };
+}
+
+legality_test!(legality_01 "x5o/7/7/7/7/7/o5x x 0 1");
+legality_test!(legality_02 "7/7/7/2x1o2/7/7/7 o 0 1");
+legality_test!(legality_03 "7/7/7/7/ooooooo/ooooooo/xxxxxxx x 0 1");
+legality_test!(legality_04 "oooo3/ooooooo/ooooooo/ooooooo/ooooooo/ooooooo/ooooxoo x 0 1");
+legality_test!(legality_05 "x5o/7/7/7/7/7/o5x x 100 1");
+
+parse_move_test!(parse_single "x5o/7/7/7/7/7/o5x x 0 1" "b6"
+    Ok(Move::new_single(Square::B6)));
+parse_move_test!(parse_jump "x5o/7/7/7/7/7/o5x x 0 1" "a7c5"
+    Ok(Move::new(Square::A7, Square::C5)));
+parse_move_test!(parse_pass "oooo3/ooooooo/ooooooo/ooooooo/ooooooo/ooooooo/ooooxoo x 0 1"
+    "0000" Ok(Move::PASS));
+parse_move_test!(parse_bad_syntax "x5o/7/7/7/7/7/o5x x 0 1" "a1a"
+    Err(MoveError::BadSyntax("length of move string should be 2 or 4, not 3".to_string())));
+parse_move_test!(parse_no_piece "x5o/7/7/7/7/7/o5x x 0 1" "c3c5"
+    Err(MoveError::NoPieceOnSource(Square::C3)));
+parse_move_test!(parse_occupied_target "x5o/7/7/7/7/7/o5x x 0 1" "a7g7"
+    Err(MoveError::OccupiedTarget(Square::G7)));
+parse_move_test!(parse_too_far "x5o/7/7/7/7/7/o5x x 0 1" "a7a4"
+    Err(MoveError::TooFar(Square::A7, Square::A4)));
+parse_move_test!(parse_unreachable "x5o/7/7/7/7/7/o5x x 0 1" "d4"
+    Err(MoveError::Unreachable(Square::D4)));
+parse_move_test!(parse_single_as_jump "x5o/7/7/7/7/7/o5x x 0 1" "a7b6"
+    Err(MoveError::Illegal("a7b6".to_string())));
+parse_move_test!(parse_pass_with_moves "x5o/7/7/7/7/7/o5x x 0 1" "0000"
+    Err(MoveError::PassWithMovesAvailable));
+parse_move_test!(parse_game_over "x5o/7/7/7/7/7/o5x x 100 1" "b6"
+    Err(MoveError::GameOver));
+
+macro_rules! fen_test {
+    ($name:ident $fen:literal $canonical:literal) => {
+        #[test]
+        fn $name() {
+            let position = Position::from_str($fen).unwrap();
+            assert_eq!(position.fen(), $canonical);
+
+            // The printed FEN should parse back into the same Position.
+            let reparsed = Position::from_str(&position.fen()).unwrap();
+            assert_eq!(reparsed.fen(), $canonical);
+            assert_eq!(reparsed.hash(), position.hash());
+        }
+    };
+}
+
+macro_rules! bad_fen_test {
+    ($name:ident $fen:literal $column:expr, $err:pat) => {
+        #[test]
+        fn $name() {
+            let err = Position::from_str($fen).err().unwrap();
+            assert!(matches!(err, $err), "{:?}", err);
+            assert_eq!(err.column(), $column);
+        }
+    };
+}
+
+fen_test!(fen_startpos "x5o/7/7/7/7/7/o5x x 0 1" "x5o/7/7/7/7/7/o5x x 0 1");
+fen_test!(fen_gaps "x5o/7/2-1-2/7/2-1-2/7/o5x o 0 1"
+    "x5o/7/2-1-2/7/2-1-2/7/o5x o 0 1");
+fen_test!(fen_full_board "xxxxxxx/ooooooo/-------/xoxoxox/7/7/7 x 99 42"
+    "xxxxxxx/ooooooo/-------/xoxoxox/7/7/7 x 99 42");
+fen_test!(fen_no_move_number "x5o/7/7/7/7/7/o5x o 5" "x5o/7/7/7/7/7/o5x o 5 1");
+fen_test!(fen_no_clocks "x5o/7/7/7/7/7/o5x x" "x5o/7/7/7/7/7/o5x x 0 1");
+fen_test!(fen_empty_board "7/7/7/7/7/7/7 o 0 1" "7/7/7/7/7/7/7 o 0 1");
+fen_test!(fen_max_full_move "x5o/7/7/7/7/7/o5x o 0 32768"
+    "x5o/7/7/7/7/7/o5x o 0 32768");
+fen_test!(fen_extra_whitespace "  x5o/7/7/7/7/7/o5x \t x  3 2\n"
+    "x5o/7/7/7/7/7/o5x x 3 2");
+
+bad_fen_test!(bad_fen_empty "" None, PositionParseError::WrongFieldNumber(0));
+bad_fen_test!(bad_fen_no_side_to_move "x5o/7/7/7/7/7/o5x" None,
+    PositionParseError::WrongFieldNumber(1));
+bad_fen_test!(bad_fen_too_many_fields "x5o/7/7/7/7/7/o5x x 0 1 1" None,
+    PositionParseError::WrongFieldNumber(5));
+bad_fen_test!(bad_fen_bad_piece "x5o/7/7/3z3/7/7/o5x x 0 1" Some(10),
+    PositionParseError::BadPiecePlacement(_, InvalidPieceIdent('z', 10)));
+bad_fen_test!(bad_fen_jump_too_long "x6o/7/7/7/7/7/o5x x 0 1" Some(3),
+    PositionParseError::BadPiecePlacement(_, JumpTooLong(3)));
+bad_fen_test!(bad_fen_jump_overflow "99999999999999999999/7/7/7/7/7/7 x 0 1"
+    Some(1), PositionParseError::BadPiecePlacement(_, JumpTooLong(1)));
+bad_fen_test!(bad_fen_jump_wrap "18446744073709551617x4o/7/7/7/7/7/o5x x 0 1"
+    Some(1), PositionParseError::BadPiecePlacement(_, JumpTooLong(1)));
+bad_fen_test!(bad_fen_short_rank "x5o/7/6/7/7/7/o5x x 0 1" Some(8),
+    PositionParseError::BadPiecePlacement(_, FileDataIncomplete(_, 8)));
+bad_fen_test!(bad_fen_too_many_ranks "x5o/7/7/7/7/7/o5x/7 x 0 1" Some(18),
+    PositionParseError::BadPiecePlacement(_, TooManyRanks(7, 18)));
+bad_fen_test!(bad_fen_too_few_ranks "x5o/7/7/7/7/o5x x 0 1" Some(16),
+    PositionParseError::BadPiecePlacement(_, TooFewRanks(7, 16)));
+bad_fen_test!(bad_fen_offset_placement "  x5o/7/7/7/7/7/o5y x 0 1" Some(19),
+    PositionParseError::BadPiecePlacement(19, _));
+bad_fen_test!(bad_fen_side_to_move "x5o/7/7/7/7/7/o5x b 0 1" Some(19),
+    PositionParseError::BadSideToMove(19, _));
+bad_fen_test!(bad_fen_half_move_clock "x5o/7/7/7/7/7/o5x x -1 1" Some(21),
+    PositionParseError::BadHalfMoveClock(21, _));
+bad_fen_test!(bad_fen_half_move_overflow "x5o/7/7/7/7/7/o5x x 256 1" Some(21),
+    PositionParseError::BadHalfMoveClock(21, _));
+bad_fen_test!(bad_fen_full_move_number "x5o/7/7/7/7/7/o5x x 0 a" Some(23),
+    PositionParseError::BadFullMoveNumber(23, _));
+bad_fen_test!(bad_fen_zero_full_move "x5o/7/7/7/7/7/o5x x 0 0" Some(23),
+    PositionParseError::ZeroFullMoveNumber(23));
+bad_fen_test!(bad_fen_full_move_too_large "x5o/7/7/7/7/7/o5x x 0 40000" Some(23),
+    PositionParseError::FullMoveNumberTooLarge(23));
+bad_fen_test!(bad_fen_full_move_just_too_large "x5o/7/7/7/7/7/o5x x 0 32769"
+    Some(23), PositionParseError::FullMoveNumberTooLarge(23));
+bad_fen_test!(bad_fen_unicode_whitespace "x5o/7/7/7/7/7/o5x x\u{3000}0 ä" Some(23),
+    PositionParseError::BadFullMoveNumber(23, _));
+
+#[test]
+fn bitboards_match_at() {
+    // A small xorshift generator, so that the random Positions are the same
+    // on every run of the test.
+    let mut seed: u64 = 0x9E3779B97F4A7C15;
+    let mut random = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    for _ in 0..1000 {
+        let mut position = Position::from_str("7/7/7/7/7/7/7 x 0 1").unwrap();
+        for sq in Square::iter() {
+            // Leave about a quarter of the Squares empty.
+            if let Some(piece) = ColoredPiece::iter().nth(random() as usize % 4)
+            {
+                position.insert(sq, piece);
+            }
+        }
+
+        for sq in Square::iter() {
+            let at = position.at(sq);
+            for piece in ColoredPiece::iter() {
+                let expected = at == Some(piece);
+                assert_eq!(
+                    position.colored_piece_bb(piece).contains(sq),
+                    expected
+                );
+            }
+
+            for piece in Piece::iter() {
+                let expected = at.map(|p| p.piece()) == Some(piece);
+                assert_eq!(position.piece_bb(piece).contains(sq), expected);
+            }
+
+            for color in Color::iter() {
+                let expected = at.and_then(|p| p.color()) == Some(color);
+                assert_eq!(position.color_bb(color).contains(sq), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn colored_piece_round_trip() {
+    for piece in ColoredPiece::iter() {
+        let color = piece.color().unwrap_or(Color::Black);
+        assert_eq!(ColoredPiece::new(piece.piece(), color), piece);
+    }
+}
+
+#[test]
+fn random_position_invariants() {
+    let root = Position::from_str("x5o/7/2-1-2/7/2-1-2/7/o5x x 0 1").unwrap();
+    for position in RandomPositions::new(root, 0xA7A88).plies(0..=80).take(500)
+    {
+        let fen = position.fen();
+
+        // The incrementally updated Hash should match a fresh one.
+        let reparsed = Position::from_str(&fen).unwrap();
+        assert_eq!(position.hash(), reparsed.hash(), "{}", fen);
+
+        // Counting and generating moves should agree.
+        assert_eq!(
+            position.count_moves::<true, true>(),
+            position.generate_moves::<false, true, true>().len(),
+            "{}",
+            fen
+        );
+
+        // Every Square should be occupied by at most one piece.
+        let mut occupied = BitBoard::EMPTY;
+        for piece in ColoredPiece::iter() {
+            let bb = position.colored_piece_bb(piece);
+            assert!((bb & occupied).is_empty(), "{}", fen);
+            assert_eq!(bb & BitBoard::UNIVERSE, bb, "{}", fen);
+            occupied |= bb;
+        }
+    }
+}
+
+fn book_game(moves: &str) -> Vec<Move> {
+    moves
+        .split(' ')
+        .map(|m| Move::from_str(m).unwrap())
+        .collect()
+}
+
+fn book_builder() -> BookBuilder {
+    let root = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    let mut builder = BookBuilder::new(4);
+    for (moves, winner) in [
+        ("b6 f6 c5 e5 d4", Some(Color::Black)),
+        ("b6 f6 c5 g5", Some(Color::White)),
+        ("b6 b2", None),
+        ("a6 f6", Some(Color::Black)),
+    ] {
+        builder.add_game(root, &book_game(moves), winner).unwrap();
+    }
+
+    builder
+}
+
+#[test]
+fn book_builder_weights() {
+    let root = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    let book = book_builder().build();
+
+    let mut entries = book.probe(&root).copied().collect::<Vec<_>>();
+    entries.sort_by_key(|entry| entry.mov.to_string());
+    let stats = entries
+        .iter()
+        .map(|entry| (entry.mov.to_string(), entry.weight, entry.learn))
+        .collect::<Vec<_>>();
+
+    // b6 has a win, a loss and a draw, while a6 has a single win.
+    assert_eq!(stats, [("a6".to_string(), 2, 1), ("b6".to_string(), 3, 3)]);
+    assert_eq!(book.best(&root), Some(Move::new_single(Square::B6)));
+
+    // Only the first 4 plies of a game are added.
+    let deep = book_game("b6 f6 c5 e5")
+        .into_iter()
+        .fold(root, |p, m| p.after_move::<true>(m));
+    assert_eq!(book.probe(&deep).count(), 0);
+}
+
+#[test]
+fn book_builder_min_games() {
+    let root = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    let book = book_builder().min_games(2).build();
+
+    let moves = book.probe(&root).map(|e| e.mov).collect::<Vec<_>>();
+    assert_eq!(moves, [Move::new_single(Square::B6)]);
+}
+
+#[test]
+fn book_builder_illegal_game() {
+    let root = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    let mut builder = BookBuilder::new(4);
+    let result = builder.add_game(root, &book_game("b6 d4"), None);
+
+    assert_eq!(result, Err(MoveError::Unreachable(Square::D4)));
+    assert!(builder.build().is_empty());
+}
+
+#[test]
+fn book_round_trip() {
+    let book = book_builder().build();
+
+    let mut bytes = vec![];
+    book.write(&mut bytes).unwrap();
+    assert_eq!(bytes.len(), book.len() * BookEntry::SIZE);
+    assert_eq!(Book::read(bytes.as_slice()).unwrap(), book);
+
+    assert!(matches!(
+        Book::from_bytes(&bytes[1..]),
+        Err(BookError::BadLength(_))
+    ));
+
+    let mut unsorted = bytes[BookEntry::SIZE..].to_vec();
+    unsorted.extend_from_slice(&bytes[..BookEntry::SIZE]);
+    assert!(matches!(
+        Book::from_bytes(&unsorted),
+        Err(BookError::Unsorted(_))
+    ));
+}
+
+#[test]
+fn book_corrupt_move() {
+    let startpos = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    let entry = BookEntry {
+        hash: startpos.hash(),
+        mov: Move::new_single(Square::B6),
+        weight: 1,
+        learn: 0,
+    };
+
+    // Passes and moves between valid squares are accepted.
+    for mov in [Move::new_single(Square::B6), Move::PASS] {
+        let bytes = BookEntry { mov, ..entry }.to_bytes();
+        assert_eq!(BookEntry::from_bytes(&bytes).unwrap().mov, mov);
+    }
+
+    // Moves with squares outside the board or with stray bits are rejected.
+    for mov in [0x0fff, 0x0031, 0x0c40, 0x1000, u16::from(Move::NULL)] {
+        let mut bytes = entry.to_bytes();
+        bytes[8..10].copy_from_slice(&mov.to_be_bytes());
+        assert!(matches!(
+            Book::from_bytes(&bytes),
+            Err(BookError::BadMove(bad)) if bad == mov
+        ));
+    }
+}
+
+#[test]
+fn book_weighted_pick() {
+    let root = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    let entry = |mov, weight| BookEntry {
+        hash: root.hash(),
+        mov,
+        weight,
+        learn: 0,
+    };
+
+    let book = Book::new(vec![
+        entry(Move::new_single(Square::B6), 3),
+        entry(Move::new_single(Square::A6), 1),
+        entry(Move::new_single(Square::F6), 0),
+        // Illegal moves, like ones from Hash collisions, are never picked.
+        entry(Move::new_single(Square::D4), 100),
+    ]);
+
+    let mut rng = Rng::new(99);
+    let mut b6 = 0;
+    for _ in 0..4000 {
+        match book.pick(&root, &mut rng).unwrap().to_string().as_str() {
+            "b6" => b6 += 1,
+            "a6" => {}
+            mov => panic!("unexpected book move {}", mov),
+        }
+    }
+
+    assert!((2800..3200).contains(&b6), "{}", b6);
+}
+
+#[test]
+fn book_export_epd() {
+    let root = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    let book = book_builder().build();
+
+    let mut epd = vec![];
+    let count = book.export_epd(root, 2, &mut epd).unwrap();
+    let epd = String::from_utf8(epd).unwrap();
+
+    // The lines b6 f6 and b6 b2 have a non-zero weight, while a6 f6 doesn't
+    // since White lost the only game in which it played f6.
+    assert_eq!(count, 2);
+    assert_eq!(epd.lines().count(), 2);
+    for fen in epd.lines() {
+        assert_eq!(Position::from_str(fen).unwrap().ply_count, 2);
+    }
+}
+
+/// Checks that the Outcome of every Position reachable from the given root is
+/// consistent with the Outcomes of its children.
+fn check_tablebase(root: Position, tablebase: &Tablebase) -> usize {
+    let mut seen = std::collections::HashSet::new();
+    let mut stack = vec![root];
+    while let Some(mut position) = stack.pop() {
+        // Tablebases ignore the half-move clock.
+        position.half_move_clock = 0;
+        if !seen.insert(u64::from(position.hash())) {
+            continue;
+        }
+
+        let outcome = tablebase.probe(&position).unwrap();
+        if position.is_game_over() {
+            assert_eq!(outcome.distance, 0);
+            continue;
+        }
+
+        let children = position
+            .generate_moves::<false, true, true>()
+            .iter()
+            .map(|&m| position.after_move::<true>(m))
+            .collect::<Vec<_>>();
+        let outcomes = children
+            .iter()
+            .map(|child| tablebase.probe(child).unwrap())
+            .collect::<Vec<_>>();
+
+        let fastest_win = outcomes
+            .iter()
+            .filter(|o| o.wdl == Wdl::Loss)
+            .map(|o| o.distance + 1)
+            .min();
+        let slowest_loss = outcomes.iter().map(|o| o.distance + 1).max();
+        let fen = position.fen();
+        match outcome.wdl {
+            Wdl::Win => {
+                assert_eq!(Some(outcome.distance), fastest_win, "{}", fen)
+            }
+            Wdl::Loss => {
+                assert!(outcomes.iter().all(|o| o.wdl == Wdl::Win), "{}", fen);
+                assert_eq!(Some(outcome.distance), slowest_loss, "{}", fen);
+            }
+            Wdl::Draw => {
+                assert_eq!(outcome.distance, 0);
+                assert!(outcomes.iter().any(|o| o.wdl == Wdl::Draw), "{}", fen);
+                assert!(fastest_win.is_none(), "{}", fen);
+            }
+        }
+
+        stack.extend(children);
+    }
+
+    seen.len()
+}
+
+#[test]
+fn tablebase_small_boards() {
+    for fen in [
+        "x1o----/3----/o1x----/-------/-------/-------/------- x 0 1",
+        "x1o----/3----/o1x----/-------/-------/-------/------- o 0 1",
+        "xo1----/1o1----/2x----/-------/-------/-------/------- x 0 1",
+        "x2o---/-1-----/o2x---/-------/-------/-------/------- x 0 1",
+        "x2o---/o2x---/-------/-------/-------/-------/------- o 0 1",
+    ] {
+        let root = Position::from_str(fen).unwrap();
+        let tablebase = Tablebase::generate([root], 16, 1_000_000).unwrap();
+
+        assert_eq!(
+            check_tablebase(root, &tablebase),
+            tablebase.len(),
+            "{}",
+            fen
+        );
+    }
+}
+
+#[test]
+fn tablebase_known_outcomes() {
+    let win = |distance| Outcome {
+        wdl: Wdl::Win,
+        distance,
+    };
+
+    // Filling the last Square of a 2x2 Board captures every White Stone.
+    let board = "xo-----/o1-----/-------/-------/-------/-------/-------";
+    let position = Position::from_str(&format!("{} x", board)).unwrap();
+    assert_eq!(Tablebase::solve(position, 1, 100).unwrap(), win(1));
+
+    // White also wins by filling the last Square, as it is left with three
+    // Stones to Black's one.
+    let position = Position::from_str(&format!("{} o", board)).unwrap();
+    assert_eq!(Tablebase::solve(position, 1, 100).unwrap(), win(1));
+
+    // A side without any pieces has already lost.
+    let lost = Position::from_str("x6/7/7/7/7/7/7 o 0 1").unwrap();
+    let outcome = Tablebase::solve(lost, 48, 10).unwrap();
+    assert_eq!(outcome.wdl, Wdl::Loss);
+    assert_eq!(outcome.distance, 0);
+}
+
+#[test]
+fn tablebase_limits() {
+    let root = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    assert!(matches!(
+        Tablebase::generate([root], 4, 1000),
+        Err(TablebaseError::TooManyEmpty(45, 4))
+    ));
+    assert!(matches!(
+        Tablebase::generate([root], 45, 1000),
+        Err(TablebaseError::TooManyPositions(1000))
+    ));
+
+    // Positions with too many empty Squares or which weren't generated are
+    // not found in a Tablebase.
+    let small = "x1o----/3----/o1x----/-------/-------/-------/------- x 0 1";
+    let small = Position::from_str(small).unwrap();
+    let tablebase = Tablebase::generate([small], 5, 1_000_000).unwrap();
+    assert_eq!(tablebase.probe(&root), None);
+    let other = "xo1----/1o1----/2x----/-------/-------/-------/------- x 0 1";
+    assert_eq!(tablebase.probe(&Position::from_str(other).unwrap()), None);
+}
+
+#[test]
+fn tablebase_round_trip() {
+    let root = "x1o----/3----/o1x----/-------/-------/-------/------- x 0 1";
+    let root = Position::from_str(root).unwrap();
+    let tablebase = Tablebase::generate([root], 5, 1_000_000).unwrap();
+
+    let mut bytes = vec![];
+    tablebase.write(&mut bytes).unwrap();
+    assert_eq!(bytes.len(), 16 + tablebase.len() * 10);
+    assert_eq!(Tablebase::read(bytes.as_slice()).unwrap(), tablebase);
+
+    let mut bad = bytes.clone();
+    bad[0] = b'X';
+    assert!(matches!(
+        Tablebase::from_bytes(&bad),
+        Err(TablebaseError::BadMagic)
+    ));
+
+    let mut bad = bytes.clone();
+    bad[5] = 2;
+    assert!(matches!(
+        Tablebase::from_bytes(&bad),
+        Err(TablebaseError::BadVersion(2))
+    ));
+
+    assert!(matches!(
+        Tablebase::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(TablebaseError::BadLength(_, _))
+    ));
+
+    // Swap the first two entries, which breaks their ordering.
+    let mut bad = bytes.clone();
+    let (first, rest) = bad[16..].split_at_mut(10);
+    first.swap_with_slice(&mut rest[..10]);
+    assert!(matches!(
+        Tablebase::from_bytes(&bad),
+        Err(TablebaseError::BadEntry(1))
+    ));
+}
+
+#[test]
+fn training_records_round_trip() {
+    let root = Position::from_str("x5o/7/2-1-2/7/2-1-2/7/o5x x 0 1").unwrap();
+    let positions = RandomPositions::new(root, 39).plies(0..=60).take(200);
+    let records = positions
+        .zip(0..)
+        .map(|(position, i)| TrainingRecord {
+            position,
+            score: i * 7 - 700,
+            result: [Wdl::Loss, Wdl::Draw, Wdl::Win][i as usize % 3],
+        })
+        .collect::<Vec<_>>();
+
+    let mut writer = RecordWriter::new(vec![]);
+    for record in &records {
+        writer.write(record).unwrap();
+    }
+    assert_eq!(writer.written(), records.len());
+
+    let bytes = writer.into_inner().unwrap();
+    assert_eq!(bytes.len(), records.len() * TrainingRecord::SIZE);
+
+    let read = RecordReader::new(bytes.as_slice())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(read.len(), records.len());
+    for (a, b) in records.iter().zip(read) {
+        let fen = a.position.fen();
+        let fields = fen.split(' ').take(2).collect::<Vec<_>>();
+        assert_eq!(
+            b.position.fen().split(' ').take(2).collect::<Vec<_>>(),
+            fields
+        );
+        assert_eq!(b.position.hash(), a.position.hash());
+        assert_eq!(b.score, a.score);
+        assert_eq!(b.result, a.result);
+    }
+}
+
+#[test]
+fn training_records_corrupted() {
+    let position = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    let record = TrainingRecord {
+        position,
+        score: 0,
+        result: Wdl::Win,
+    };
+    let bytes = record.to_bytes();
+
+    // A Square, g1, occupied by both Black and White.
+    let mut bad = bytes;
+    bad[7] |= 1 << 6;
+    assert!(matches!(
+        TrainingRecord::from_bytes(&bad),
+        Err(DataError::BadBitBoards)
+    ));
+
+    // A bit outside the Board.
+    let mut bad = bytes;
+    bad[20] |= 0x80;
+    assert!(matches!(
+        TrainingRecord::from_bytes(&bad),
+        Err(DataError::BadBitBoards)
+    ));
+
+    let mut bad = bytes;
+    bad[21] |= 0b110;
+    assert!(matches!(
+        TrainingRecord::from_bytes(&bad),
+        Err(DataError::BadResult(3))
+    ));
+
+    let mut reader = RecordReader::new(&bytes[..TrainingRecord::SIZE - 1]);
+    assert!(matches!(reader.next(), Some(Err(DataError::Truncated(23)))));
+}
+
+#[test]
+fn training_features_symmetry() {
+    let root = Position::from_str("x5o/7/2-1-2/7/2-1-2/7/o5x x 0 1").unwrap();
+    for position in RandomPositions::new(root, 40).plies(0..=60).take(200) {
+        let mirror = |bb: BitBoard| {
+            bb.into_iter().fold(BitBoard::EMPTY, |mirrored, sq| {
+                let sq = sq as usize;
+                let flipped = (6 - sq / 7) * 7 + sq % 7;
+                mirrored | Square::iter().nth(flipped).unwrap()
+            })
+        };
+
+        // Swap the colors and mirror the Board vertically.
+        let swapped = Position::new(
+            mirror(position.color_bb(Color::White)),
+            mirror(position.color_bb(Color::Black)),
+            mirror(position.colored_piece_bb(ColoredPiece::Block)),
+            !position.side_to_move,
+        );
+
+        for color in Color::iter() {
+            let mut features = feature_indices(&position, color);
+            let mut mirrored = feature_indices(&swapped, !color);
+            features.sort();
+            mirrored.sort();
+            assert_eq!(features, mirrored);
+            assert!(features.iter().all(|&f| f < FEATURES));
+
+            let pieces = position.piece_bb(Piece::Stone)
+                | position.piece_bb(Piece::Block);
+            assert_eq!(features.len(), pieces.len());
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use serde_json::{from_str, json, to_value};
+
+    let position = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    let mov = Move::from_str("a7c5").unwrap();
+    let value = json!({
+        "position": "x5o/7/7/7/7/7/o5x x 0 1",
+        "move": "a7c5",
+        "square": "c5",
+        "piece": "x",
+        "bitboard": 1,
+        "hash": u64::from(position.hash()),
+        "wdl": "Win",
+    });
+
+    assert_eq!(to_value(position).unwrap(), value["position"]);
+    assert_eq!(to_value(mov).unwrap(), value["move"]);
+    assert_eq!(to_value(Square::C5).unwrap(), value["square"]);
+    assert_eq!(to_value(ColoredPiece::Black).unwrap(), value["piece"]);
+    assert_eq!(
+        to_value(BitBoard::from(Square::A1)).unwrap(),
+        value["bitboard"]
+    );
+    assert_eq!(to_value(position.hash()).unwrap(), value["hash"]);
+    assert_eq!(to_value(Wdl::Win).unwrap(), value["wdl"]);
+
+    let parsed: Position = from_str(&value["position"].to_string()).unwrap();
+    assert_eq!(parsed.hash(), position.hash());
+    assert_eq!(from_str::<Move>("\"a7c5\"").unwrap(), mov);
+    assert_eq!(from_str::<Square>("\"c5\"").unwrap(), Square::C5);
+    assert_eq!(
+        from_str::<BitBoard>(&BitBoard::UNIVERSE.0.to_string()).unwrap(),
+        BitBoard::UNIVERSE
+    );
+    assert!(from_str::<BitBoard>(&(1u64 << 49).to_string()).is_err());
+    assert!(from_str::<BitBoard>(&u64::MAX.to_string()).is_err());
+    assert!(from_str::<Move>("\"a7c\"").is_err());
+    assert!(from_str::<Position>("\"x5o/7/7 x 0 1\"").is_err());
+}
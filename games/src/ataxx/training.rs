@@ -0,0 +1,285 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Training data for ataxx evaluation networks.
+//!
+//! Positions dumped for training are stored as [`TrainingRecord`]s, which are
+//! written and read in bulk with a [`RecordWriter`] and a [`RecordReader`].
+//! Every record takes [`TrainingRecord::SIZE`] bytes on disk:
+//!
+//! | bytes  | field                                              |
+//! |--------|----------------------------------------------------|
+//! | 0..7   | Black's Stones, as the low 56 bits of a BitBoard   |
+//! | 7..14  | White's Stones                                     |
+//! | 14..21 | Blocks                                             |
+//! | 21     | side to move in bit 0, result in bits 1 and 2      |
+//! | 22..24 | score for the side to move, as an `i16`            |
+//!
+//! All the fields are stored in little-endian byte order, and the result uses
+//! the same encoding as [`Wdl`], from the point of view of the side to move.
+//!
+//! Networks and training tools should get their inputs from
+//! [`feature_indices`], so that both of them use the exact same encoding.
+
+use std::io;
+use std::io::{Read, Write};
+
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use crate::ataxx::{BitBoard, Color, ColoredPiece, Position, Square};
+use crate::data::{RecordSink, SelfPlayRecord};
+use crate::interface::{BitBoardType, PositionType, RepresentableType, Wdl};
+
+/// TrainingRecord is a Position labelled with its search score and the final
+/// result of the game it was played in.
+#[derive(Copy, Clone)]
+pub struct TrainingRecord {
+    /// position is the labelled Position.
+    pub position: Position,
+    /// score is the search score of the Position for the side to move.
+    pub score: i16,
+    /// result is the final result of the game for the side to move.
+    pub result: Wdl,
+}
+
+/// DataError represents an error encountered while reading training data.
+#[derive(Error, Debug)]
+pub enum DataError {
+    #[error("reading training data: {0}")]
+    Io(#[from] io::Error),
+    #[error("record ends after {0} of {size} bytes", size = TrainingRecord::SIZE)]
+    Truncated(usize),
+    #[error("bitboards overlap or have bits outside the board")]
+    BadBitBoards,
+    #[error("invalid result {0}")]
+    BadResult(u8),
+}
+
+impl TrainingRecord {
+    /// SIZE is the number of bytes taken by a TrainingRecord on disk.
+    pub const SIZE: usize = 24;
+
+    const BITBOARD_SIZE: usize = 7;
+
+    /// to_bytes returns the on-disk representation of the TrainingRecord. The
+    /// half-move clock and the ply count of the Position are not stored.
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+
+        for (i, piece) in ColoredPiece::iter().enumerate() {
+            let bb = u64::from(self.position.colored_piece_bb(piece));
+            let start = i * Self::BITBOARD_SIZE;
+            bytes[start..start + Self::BITBOARD_SIZE]
+                .copy_from_slice(&bb.to_le_bytes()[..Self::BITBOARD_SIZE]);
+        }
+
+        bytes[21] = self.position.side_to_move as u8 | (self.result as u8) << 1;
+        bytes[22..24].copy_from_slice(&self.score.to_le_bytes());
+        bytes
+    }
+
+    /// from_bytes parses a TrainingRecord from its on-disk representation.
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, DataError> {
+        let mut bitboards = [BitBoard::EMPTY; ColoredPiece::N];
+        for (i, bb) in bitboards.iter_mut().enumerate() {
+            let start = i * Self::BITBOARD_SIZE;
+            let mut raw = [0; 8];
+            raw[..Self::BITBOARD_SIZE]
+                .copy_from_slice(&bytes[start..start + Self::BITBOARD_SIZE]);
+
+            let raw = u64::from_le_bytes(raw);
+            if raw & !u64::from(BitBoard::UNIVERSE) != 0 {
+                return Err(DataError::BadBitBoards);
+            }
+
+            *bb = BitBoard::from(raw);
+        }
+
+        let [black, white, block] = bitboards;
+        if !(black & white).is_empty() || !((black | white) & block).is_empty()
+        {
+            return Err(DataError::BadBitBoards);
+        }
+
+        let side_to_move = if bytes[21] & 1 == 0 {
+            Color::Black
+        } else {
+            Color::White
+        };
+
+        let result = match (bytes[21] >> 1) & 0b11 {
+            0 => Wdl::Loss,
+            1 => Wdl::Draw,
+            2 => Wdl::Win,
+            bits => return Err(DataError::BadResult(bits)),
+        };
+
+        Ok(TrainingRecord {
+            position: Position::new(black, white, block, side_to_move),
+            score: i16::from_le_bytes([bytes[22], bytes[23]]),
+            result,
+        })
+    }
+}
+
+/// RecordWriter writes TrainingRecords into a writer.
+pub struct RecordWriter<W: Write> {
+    writer: W,
+    written: usize,
+}
+
+impl<W: Write> RecordWriter<W> {
+    /// new creates a new RecordWriter which writes into the given writer. The
+    /// writer should usually be buffered, since every record is a small write.
+    pub fn new(writer: W) -> Self {
+        RecordWriter { writer, written: 0 }
+    }
+
+    /// write writes the given TrainingRecord.
+    pub fn write(&mut self, record: &TrainingRecord) -> io::Result<()> {
+        self.writer.write_all(&record.to_bytes())?;
+        self.written += 1;
+        Ok(())
+    }
+
+    /// written returns the number of TrainingRecords written so far.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// into_inner flushes and returns the underlying writer.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write + Send> RecordSink<Position> for RecordWriter<W> {
+    /// write_record writes the given self-play record as a TrainingRecord,
+    /// with its score clamped to the range of an i16.
+    fn write_record(
+        &mut self,
+        record: &SelfPlayRecord<Position>,
+    ) -> io::Result<()> {
+        let score = record.score.clamp(i16::MIN.into(), i16::MAX.into());
+        self.write(&TrainingRecord {
+            position: record.position,
+            score: score as i16,
+            result: record.result,
+        })
+    }
+}
+
+/// RecordReader reads TrainingRecords from a reader, as an [`Iterator`].
+/// ```
+/// use tetka_games::ataxx::training::*;
+/// use tetka_games::ataxx::Position;
+/// use tetka_games::interface::Wdl;
+/// use std::str::FromStr;
+///
+/// let position = Position::from_str("x5o/7/2-1-2/7/2-1-2/7/o5x o 0 1").unwrap();
+/// let record = TrainingRecord { position, score: -35, result: Wdl::Draw };
+///
+/// let mut writer = RecordWriter::new(vec![]);
+/// writer.write(&record).unwrap();
+/// let bytes = writer.into_inner().unwrap();
+///
+/// let records = RecordReader::new(bytes.as_slice())
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(records.len(), 1);
+/// assert_eq!(records[0].position.fen(), "x5o/7/2-1-2/7/2-1-2/7/o5x o 0 1");
+/// assert_eq!(records[0].score, -35);
+/// assert_eq!(records[0].result, Wdl::Draw);
+/// ```
+pub struct RecordReader<R: Read> {
+    reader: R,
+}
+
+impl<R: Read> RecordReader<R> {
+    /// new creates a new RecordReader which reads from the given reader.
+    pub fn new(reader: R) -> Self {
+        RecordReader { reader }
+    }
+}
+
+impl<R: Read> Iterator for RecordReader<R> {
+    type Item = Result<TrainingRecord, DataError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut bytes = [0; TrainingRecord::SIZE];
+        let mut read = 0;
+        while read < TrainingRecord::SIZE {
+            match self.reader.read(&mut bytes[read..]) {
+                Ok(0) if read == 0 => return None,
+                Ok(0) => return Some(Err(DataError::Truncated(read))),
+                Ok(n) => read += n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Some(Err(err.into())),
+            }
+        }
+
+        Some(TrainingRecord::from_bytes(&bytes))
+    }
+}
+
+/// FEATURES is the number of input features of a Position, which is one for
+/// every pair of a piece type and a Square. The piece types are the Stones of
+/// the perspective, the Stones of its opponent, and the Blocks, in this order.
+pub const FEATURES: usize = 3 * Square::N;
+
+/// feature_indices returns the indices of the active input features of the
+/// given Position from the point of view of the given perspective. The Board
+/// is mirrored vertically for White, so the Positions which only differ in the
+/// colors of their Stones and a vertical mirror have the same features.
+///
+/// NNUE-style networks usually use two sets of features, one for the side to
+/// move and one for the other side.
+/// ```
+/// use tetka_games::ataxx::training::{feature_indices, FEATURES};
+/// use tetka_games::ataxx::{Color, Position};
+/// use std::str::FromStr;
+///
+/// let position = Position::from_str("x6/7/7/7/7/7/6o x 0 1").unwrap();
+///
+/// // For White, the Board is mirrored so that a7 becomes a1 and g1 becomes g7.
+/// assert_eq!(feature_indices(&position, Color::Black), [42, 49 + 6]);
+/// assert_eq!(feature_indices(&position, Color::White), [48, 49]);
+/// assert!(feature_indices(&position, Color::Black).iter().all(|&f| f < FEATURES));
+/// ```
+#[must_use]
+pub fn feature_indices(position: &Position, perspective: Color) -> Vec<usize> {
+    let ours = position.color_bb(perspective);
+    let theirs = position.color_bb(!perspective);
+    let blocks = position.colored_piece_bb(ColoredPiece::Block);
+
+    let mirror = |square: Square| {
+        if perspective == Color::Black {
+            square as usize
+        } else {
+            // Flip the Rank while keeping the File.
+            let (rank, file) = (square as usize / 7, square as usize % 7);
+            (6 - rank) * 7 + file
+        }
+    };
+
+    let mut features = Vec::with_capacity(Square::N);
+    for (i, bb) in [ours, theirs, blocks].into_iter().enumerate() {
+        features.extend(bb.into_iter().map(|sq| i * Square::N + mirror(sq)));
+    }
+
+    features
+}
@@ -0,0 +1,38 @@
+//! An implementation of breakthrough, where two players race to move one of
+//! their pawn-like pieces to the opponent's home Rank. Pieces move one Square
+//! forward, either straight or diagonally, and capture diagonally forward.
+//!
+//! The [`Position`] type is generic over the BitBoard of the board it is played
+//! on, and over whether pieces may move diagonally to empty Squares. Hexapawn
+//! is the 3x3 special case where pieces move like pawns in chess. The board
+//! sizes provided out of the box live in the `board<m>x<n>` modules.
+
+// Make the contents of the non-namespaced
+// modules public, so they can be accessed
+// without their parent namespace.
+pub use self::piece::*;
+pub use self::position::*;
+pub use self::r#move::*;
+
+// Namespaced modules containing the supported boards.
+pub mod board3x3;
+pub mod board6x6;
+pub mod board8x8;
+
+// Non-namespaced modules.
+mod r#move;
+mod piece;
+mod position;
+
+#[cfg(test)]
+mod tests;
+
+/// Breakthrough is breakthrough played on the standard 8x8 board.
+pub type Breakthrough = Position<board8x8::BitBoard, true>;
+
+/// MiniBreakthrough is breakthrough played on a 6x6 board.
+pub type MiniBreakthrough = Position<board6x6::BitBoard, true>;
+
+/// Hexapawn is played on a 3x3 board with a single Rank of pieces for each
+/// side, which move straight forward and capture diagonally forward.
+pub type Hexapawn = Position<board3x3::BitBoard, false>;
@@ -0,0 +1,178 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::string::ToString;
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use thiserror::Error;
+
+use crate::breakthrough::board8x8::MOVE_LIST_CAPACITY;
+use crate::interface::{MoveList, MoveType, SquareType, TypeParseError};
+
+/// Move represents a breakthrough move which can be played on the Board. Every
+/// Move moves a piece from its source Square to its target Square, capturing
+/// any enemy piece on the target Square. Move is generic over the Square type
+/// of the Board it is played on, which can have at most 64 Squares.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Move<S>(u16, PhantomData<S>);
+
+impl<S: SquareType> MoveType for Move<S> {
+    type Repr = u16;
+
+    const NULL: Self = Move(1 << 15, PhantomData);
+    const MAX_IN_GAME: usize = S::N * 4;
+    const MAX_IN_POSITION: usize = S::N * 3;
+
+    type List = MoveList<Self, MOVE_LIST_CAPACITY>;
+}
+
+impl<S> From<u16> for Move<S> {
+    fn from(value: u16) -> Self {
+        Move(value, PhantomData)
+    }
+}
+
+impl<S> From<Move<S>> for u16 {
+    fn from(value: Move<S>) -> Self {
+        value.0
+    }
+}
+
+impl<S: SquareType> Move<S> {
+    // Bit-widths of fields.
+    const SOURCE_WIDTH: u16 = 6;
+    const TARGET_WIDTH: u16 = 6;
+
+    // Bit-masks of fields.
+    const SOURCE_MASK: u16 = (1 << Self::SOURCE_WIDTH) - 1;
+    const TARGET_MASK: u16 = (1 << Self::TARGET_WIDTH) - 1;
+
+    // Bit-offsets of fields.
+    const SOURCE_OFFSET: u16 = 0;
+    const TARGET_OFFSET: u16 = Self::SOURCE_OFFSET + Self::SOURCE_WIDTH;
+
+    /// NULL Move represents an invalid move.
+    pub const NULL: Move<S> = Move(1 << 15, PhantomData);
+
+    /// new returns a new Move from the given source Square to the given target
+    /// Square. These Squares can be recovered with the [`Move::source`] and
+    /// [`Move::target`] methods respectively.
+    /// ```
+    /// use tetka_games::breakthrough::board8x8::Square;
+    /// use tetka_games::breakthrough::Move;
+    ///
+    /// let mov = Move::new(Square::A2, Square::B3);
+    ///
+    /// assert_eq!(mov.source(), Square::A2);
+    /// assert_eq!(mov.target(), Square::B3);
+    /// ```
+    #[inline(always)]
+    #[rustfmt::skip]
+    pub fn new(source: S, target: S) -> Move<S> {
+        Move(
+            (source.into() as u16) << Self::SOURCE_OFFSET |
+            (target.into() as u16) << Self::TARGET_OFFSET,
+            PhantomData,
+        )
+    }
+
+    /// Source returns the source Square of the moving piece.
+    pub fn source(self) -> S {
+        unsafe {
+            S::unsafe_from((self.0 >> Self::SOURCE_OFFSET) & Self::SOURCE_MASK)
+        }
+    }
+
+    /// Target returns the target Square of the moving piece.
+    pub fn target(self) -> S {
+        unsafe {
+            S::unsafe_from((self.0 >> Self::TARGET_OFFSET) & Self::TARGET_MASK)
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum MoveParseError {
+    #[error("move string should contain a source and a target square")]
+    MissingSquare,
+    #[error("bad square string \"{0}\"")]
+    BadSquare(#[from] TypeParseError),
+}
+
+impl<S: SquareType> FromStr for Move<S> {
+    type Err = MoveParseError;
+
+    /// from_str converts the given string representation of a Move into a
+    /// [Move]. The format supported is `<source><target>`. This function can
+    /// be treated as the inverse of the [`fmt::Display`] trait for [Move].
+    /// ```
+    /// use tetka_games::breakthrough::board8x8::Square;
+    /// use tetka_games::breakthrough::Move;
+    /// use core::str::FromStr;
+    ///
+    /// let mov = Move::new(Square::D7, Square::C6);
+    ///
+    /// assert_eq!(Move::from_str(&mov.to_string()).unwrap(), mov);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The target Square starts with the second File letter in the string.
+        let split = s
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| c.is_ascii_alphabetic())
+            .map(|(i, _)| i)
+            .ok_or(MoveParseError::MissingSquare)?;
+
+        let parse = |s: &str| {
+            S::from_str(s).map_err(|_| {
+                TypeParseError::StrError(stringify!(Square).to_string())
+            })
+        };
+
+        Ok(Move::new(parse(&s[..split])?, parse(&s[split..])?))
+    }
+}
+
+impl<S: SquareType> fmt::Display for Move<S> {
+    /// Display formats the given Move in a human-readable manner. The format
+    /// used for displaying moves is `<source><target>`, while [`Move::NULL`]
+    /// is formatted as `null`.
+    /// ```
+    /// use tetka_games::breakthrough::board3x3::Square;
+    /// use tetka_games::breakthrough::Move;
+    ///
+    /// assert_eq!(Move::<Square>::NULL.to_string(), "null");
+    /// assert_eq!(Move::new(Square::A1, Square::A2).to_string(), "a1a2");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Move::NULL {
+            write!(f, "null")
+        } else {
+            write!(f, "{}{}", self.source(), self.target())
+        }
+    }
+}
+
+// Moves are serialized as their move strings.
+crate::interface::serde_string!([S: SquareType] Move<S>);
+
+impl<S: SquareType> fmt::Debug for Move<S> {
+    /// Debug formats the given Move into a human-readable debug string. It uses
+    /// `Move::Display` trait under the hood for formatting the Move.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::ops;
+use core::str::FromStr;
+
+use crate::interface::representable_type;
+use crate::interface::ColoredPieceType;
+use crate::interface::RepresentableType;
+
+representable_type!(
+    /// Color represents all the possible colors that a breakthrough piece can
+    /// have, specifically, White and Black. White moves first, and its pieces
+    /// move towards the last Rank, while Black's move towards the first Rank.
+    enum Color: u8 { White "w", Black "b", }
+);
+
+impl ops::Not for Color {
+    type Output = Color;
+
+    /// not implements the not unary operator (!) which switches the current
+    /// Color to its opposite, i.e. [`Color::White`] to [`Color::Black`] and
+    /// vice versa.
+    fn not(self) -> Self::Output {
+        unsafe { Color::unsafe_from(self as usize ^ 1) }
+    }
+}
+
+representable_type!(
+    /// Piece represents the types of pieces in breakthrough. Since all the
+    /// pieces move like pawns, there is only a single type of Piece.
+    enum Piece: u8 { Pawn "p", }
+);
+
+representable_type!(
+    /// ColoredPiece represents all the possible breakthrough pieces.
+    enum ColoredPiece: u8 { White "P", Black "p", }
+);
+
+impl ColoredPieceType for ColoredPiece {
+    type Piece = Piece;
+    type Color = Color;
+
+    fn piece(self) -> Piece {
+        Piece::Pawn
+    }
+
+    fn color(self) -> Option<Color> {
+        Some(match self {
+            ColoredPiece::White => Color::White,
+            ColoredPiece::Black => Color::Black,
+        })
+    }
+}
@@ -0,0 +1,348 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::str::FromStr;
+
+use num_traits::ToPrimitive;
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use crate::interface;
+use crate::interface::{
+    BitBoardType, Hash, MoveStore, PiecePlacementParseError, PositionType,
+    RepresentableType, SquareType, TypeParseError,
+};
+
+use crate::breakthrough::board8x8::MOVE_LIST_CAPACITY;
+use crate::breakthrough::{Color, ColoredPiece, Move, Piece};
+
+type File<B> = <<B as BitBoardType>::Square as SquareType>::File;
+type Rank<B> = <<B as BitBoardType>::Square as SquareType>::Rank;
+
+/// Position represents the snapshot of a breakthrough Board, the state of the
+/// game at a single point in time. The size of the Board is decided by the
+/// BitBoard type `B`, while `DIAGONAL_STEPS` decides if pieces can also move
+/// diagonally forward to empty Squares, or only capture diagonally forward like
+/// pawns in chess. The latter is the case in hexapawn.
+///
+/// A side wins by moving a piece to the opponent's home Rank. A side which has
+/// no legal moves, including if it has no pieces left, loses the game.
+#[derive(Copy, Clone)]
+pub struct Position<B, const DIAGONAL_STEPS: bool> {
+    /// bitboards stores BitBoards for the pieces of each Color.
+    pub bitboards: [B; ColoredPiece::N],
+    /// checksum stores the semi-unique [struct@Hash] of the current Position.
+    pub checksum: Hash,
+    /// side_to_move stores the piece whose turn to move it currently is.
+    pub side_to_move: Color,
+}
+
+impl<B: BitBoardType, const DIAGONAL_STEPS: bool> PositionType
+    for Position<B, DIAGONAL_STEPS>
+{
+    type BitBoard = B;
+    type ColoredPiece = ColoredPiece;
+    type Move = Move<B::Square>;
+
+    fn insert(&mut self, sq: B::Square, piece: ColoredPiece) {
+        self.bitboards[piece as usize].insert(sq);
+    }
+
+    fn remove(&mut self, sq: B::Square) -> Option<ColoredPiece> {
+        match self.at(sq) {
+            Some(piece) => {
+                self.bitboards[piece as usize].remove(sq);
+                Some(piece)
+            }
+            None => None,
+        }
+    }
+
+    fn at(&self, sq: B::Square) -> Option<ColoredPiece> {
+        ColoredPiece::iter()
+            .find(|piece| self.colored_piece_bb(*piece).contains(sq))
+    }
+
+    fn piece_bb(&self, piece: Piece) -> B {
+        match piece {
+            Piece::Pawn => self.bitboards[0] | self.bitboards[1],
+        }
+    }
+
+    fn color_bb(&self, color: Color) -> B {
+        self.bitboards[color as usize]
+    }
+
+    fn colored_piece_bb(&self, piece: ColoredPiece) -> B {
+        self.bitboards[piece as usize]
+    }
+
+    fn hash(&self) -> Hash {
+        self.checksum
+    }
+
+    fn side_to_move(&self) -> Color {
+        self.side_to_move
+    }
+
+    fn fen(&self) -> String {
+        format!(
+            "{} {}",
+            interface::format_piece_placement(self),
+            self.side_to_move
+        )
+    }
+
+    fn winner(&self) -> Option<Color> {
+        let last_rank =
+            B::rank(unsafe { Rank::<B>::unsafe_from(Rank::<B>::N - 1) });
+
+        if !self.color_bb(Color::White).is_disjoint(last_rank) {
+            // White reached Black's home Rank.
+            Some(Color::White)
+        } else if !self.color_bb(Color::Black).is_disjoint(B::FIRST_RANK) {
+            // Black reached White's home Rank.
+            Some(Color::Black)
+        } else if self.count_targets::<true, true>() == 0 {
+            // The side to move has no legal moves.
+            Some(!self.side_to_move)
+        } else {
+            None
+        }
+    }
+
+    fn after_move<const UPDATE_HASH: bool>(&self, m: Self::Move) -> Self {
+        let stm = self.side_to_move;
+
+        let mut bitboards = self.bitboards;
+        bitboards[stm as usize].remove(m.source());
+        bitboards[stm as usize].insert(m.target());
+        bitboards[!stm as usize].remove(m.target());
+
+        Position {
+            bitboards,
+            checksum: if UPDATE_HASH {
+                Self::get_hash(bitboards[0], bitboards[1], !stm)
+            } else {
+                Default::default()
+            },
+            side_to_move: !stm,
+        }
+    }
+
+    fn generate_moves_into<
+        const ALLOW_ILLEGAL: bool,
+        const QUIET: bool,
+        const NOISY: bool,
+        T: MoveStore<Self::Move>,
+    >(
+        &self,
+        movelist: &mut T,
+    ) {
+        const {
+            assert!(
+                <Self::Move as interface::MoveType>::MAX_IN_POSITION
+                    <= MOVE_LIST_CAPACITY
+            )
+        };
+
+        if self.is_game_over() {
+            // Game is over, so don't generate any moves.
+            return;
+        }
+
+        let white = self.side_to_move == Color::White;
+        let back = |sq: B::Square| {
+            if white { sq.south() } else { sq.north() }.unwrap()
+        };
+
+        let (straight, west, east) = self.targets::<QUIET, NOISY>();
+
+        // The source Square of a Move is found by stepping back from the target
+        // Square in the opposite of the direction the piece moved in.
+        for target in straight {
+            movelist.push(Move::new(back(target), target));
+        }
+
+        for target in west {
+            movelist.push(Move::new(back(target).east().unwrap(), target));
+        }
+
+        for target in east {
+            movelist.push(Move::new(back(target).west().unwrap(), target));
+        }
+    }
+
+    fn count_moves<const QUIET: bool, const NOISY: bool>(&self) -> usize {
+        if self.is_game_over() {
+            // Game is over, so don't generate any moves.
+            return 0;
+        }
+
+        self.count_targets::<QUIET, NOISY>()
+    }
+}
+
+impl<B: BitBoardType, const DIAGONAL_STEPS: bool> Position<B, DIAGONAL_STEPS> {
+    /// targets returns the target Squares of the Moves of the side to move,
+    /// separately for the pieces moving straight forward, forward and to the
+    /// west, and forward and to the east. The `QUIET` and `NOISY` flags toggle
+    /// the inclusion of non-capturing and capturing Moves respectively.
+    fn targets<const QUIET: bool, const NOISY: bool>(&self) -> (B, B, B) {
+        let stm = self.color_bb(self.side_to_move);
+        let xtm = self.color_bb(!self.side_to_move);
+        let empty = !(stm | xtm);
+
+        let forward = if self.side_to_move == Color::White {
+            stm.north()
+        } else {
+            stm.south()
+        };
+
+        // Pieces can only move straight forward to empty Squares, and capture
+        // diagonally forward. Optionally, they can also move diagonally forward
+        // to empty Squares.
+        let straight = if QUIET { empty } else { B::EMPTY };
+        let diagonal = match (QUIET && DIAGONAL_STEPS, NOISY) {
+            (true, true) => empty | xtm,
+            (true, false) => empty,
+            (false, true) => xtm,
+            (false, false) => B::EMPTY,
+        };
+
+        (
+            forward & straight,
+            forward.west() & diagonal,
+            forward.east() & diagonal,
+        )
+    }
+
+    /// count_targets returns the number of Moves available to the side to
+    /// move, without checking if the game is over.
+    fn count_targets<const QUIET: bool, const NOISY: bool>(&self) -> usize {
+        let (straight, west, east) = self.targets::<QUIET, NOISY>();
+        straight.len() + west.len() + east.len()
+    }
+
+    fn get_hash(white: B, black: B, stm: Color) -> Hash {
+        let hash = Hash::from_bitsets(
+            white.into().to_u128().unwrap(),
+            black.into().to_u128().unwrap(),
+        );
+
+        // The Hash is bitwise complemented if the given side to move is White.
+        // Therefore, if two Positions only differ in side to move,
+        // `a.Hash == !b.Hash`.
+        if stm == Color::White {
+            !hash
+        } else {
+            hash
+        }
+    }
+}
+
+/// PositionParseErr represents an error encountered while parsing
+/// the given FEN position field into a valid Position.
+#[derive(Error, Debug)]
+pub enum PositionParseError {
+    #[error("expected 2 fields, found {0}")]
+    WrongFieldNumber(usize),
+
+    #[error("parsing piece placement: {0}")]
+    BadPiecePlacement(#[from] PiecePlacementParseError),
+
+    #[error("parsing side to move: {0}")]
+    BadSideToMove(#[from] TypeParseError),
+}
+
+// FromStr implements parsing of the position field in a FEN. The FEN for a
+// breakthrough game contains the piece placement and the side to move, for
+// example `ppp/3/PPP w` for the starting position of hexapawn.
+impl<B: BitBoardType, const DIAGONAL_STEPS: bool> FromStr
+    for Position<B, DIAGONAL_STEPS>
+{
+    type Err = PositionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split(' ').collect::<Vec<&str>>();
+
+        if parts.len() != 2 {
+            return Err(PositionParseError::WrongFieldNumber(parts.len()));
+        }
+
+        let pos = parts[0];
+        let stm = parts[1];
+
+        let mut position = Position {
+            bitboards: [B::EMPTY; ColoredPiece::N],
+            checksum: Default::default(),
+            side_to_move: Color::White,
+        };
+
+        interface::parse_piece_placement(&mut position, pos)?;
+
+        position.side_to_move = Color::from_str(stm)?;
+
+        // Calculate the Hash value for the Position.
+        position.checksum = Self::get_hash(
+            position.colored_piece_bb(ColoredPiece::White),
+            position.colored_piece_bb(ColoredPiece::Black),
+            position.side_to_move,
+        );
+
+        Ok(position)
+    }
+}
+
+// Display implements displaying a Position using ASCII art.
+impl<B: BitBoardType, const DIAGONAL_STEPS: bool> fmt::Display
+    for Position<B, DIAGONAL_STEPS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut string_rep = String::from(" ");
+
+        for rank in Rank::<B>::iter().rev() {
+            for file in File::<B>::iter() {
+                let square = B::Square::new(file, rank);
+                let square_str = match self.at(square) {
+                    Some(piece) => format!("{} ", piece),
+                    None => ". ".to_string(),
+                };
+                string_rep += &square_str;
+            }
+
+            // Append the rank marker.
+            string_rep += &format!(" {} \n ", rank);
+        }
+
+        // Append the file markers.
+        for file in File::<B>::iter() {
+            string_rep += &format!("{} ", file);
+        }
+
+        writeln!(f, "{}", string_rep.trim_end()).unwrap();
+        writeln!(f, "Side To Move: {}", self.side_to_move)
+    }
+}
+
+// Positions are serialized as their FEN strings.
+crate::interface::serde_string!(
+    [B: BitBoardType, const DIAGONAL_STEPS: bool]
+    Position<B, DIAGONAL_STEPS>,
+    crate::interface::PositionType::fen
+);
@@ -0,0 +1,77 @@
+use crate::breakthrough::{Breakthrough, Color, Hexapawn, MiniBreakthrough};
+use crate::interface::{BitBoardType, PositionType};
+use crate::perft;
+use std::str::FromStr;
+
+macro_rules! perft_test {
+    ($name:ident $game:ident $pos:literal $depth:literal $nodes:literal) => {
+        #[test]
+        fn $name() {
+            let position = $game::from_str($pos).unwrap();
+            assert_eq!(perft::<true, true, _>(position, $depth), $nodes)
+        }
+    };
+}
+
+macro_rules! winner_test {
+    ($name:ident $game:ident $pos:literal $winner:expr) => {
+        #[test]
+        fn $name() {
+            let position = $game::from_str($pos).unwrap();
+            let winner: Option<Color> = $winner;
+            assert_eq!(position.winner(), winner);
+            assert_eq!(position.is_game_over(), winner.is_some());
+        }
+    };
+}
+
+macro_rules! split_test {
+    ($name:ident $game:ident $pos:literal) => {
+        #[test]
+        fn $name() {
+            let position = $game::from_str($pos).unwrap();
+            let quiet = position.generate_moves::<false, true, false>();
+            let noisy = position.generate_moves::<false, false, true>();
+
+            assert_eq!(quiet.len(), position.count_moves::<true, false>());
+            assert_eq!(noisy.len(), position.count_moves::<false, true>());
+            assert_eq!(
+                quiet.len() + noisy.len(),
+                position.count_moves::<true, true>()
+            );
+
+            // Noisy moves capture a piece, quiet moves don't.
+            let enemies = position.color_bb(!position.side_to_move);
+            assert!(quiet.iter().all(|m| !enemies.contains(m.target())));
+            assert!(noisy.iter().all(|m| enemies.contains(m.target())));
+        }
+    };
+}
+
+perft_test!(breakthrough_01 Breakthrough
+    "pppppppp/pppppppp/8/8/8/8/PPPPPPPP/PPPPPPPP w" 4 256036);
+perft_test!(breakthrough_02 Breakthrough
+    "1pp1pp1p/p1p2p2/3pP3/1P6/2p1p1P1/p4p2/PP1PPPP1/P1P4P b" 4 448320);
+perft_test!(breakthrough_03 Breakthrough "8/2p5/8/8/8/8/5P2/8 w" 6 631);
+
+perft_test!(mini_breakthrough_01 MiniBreakthrough
+    "pppppp/pppppp/6/6/PPPPPP/PPPPPP w" 4 71478);
+
+perft_test!(hexapawn_01 Hexapawn "ppp/3/PPP w" 3 28);
+perft_test!(hexapawn_02 Hexapawn "ppp/3/PPP w" 5 70);
+perft_test!(hexapawn_03 Hexapawn "ppp/3/PPP w" 7 20);
+perft_test!(hexapawn_04 Hexapawn "ppp/3/PPP w" 8 0);
+
+winner_test!(breakthrough_home_rank Breakthrough
+    "pppP4/pp6/8/8/8/8/8/PPPPPPPP b" Some(Color::White));
+winner_test!(breakthrough_captured Breakthrough "8/8/8/8/8/8/8/PPP5 b"
+    Some(Color::White));
+winner_test!(breakthrough_ongoing Breakthrough "p7/8/8/8/8/8/8/7P w" None);
+
+winner_test!(hexapawn_blocked Hexapawn "1p1/1P1/3 w" Some(Color::Black));
+winner_test!(hexapawn_home_rank Hexapawn "2P/p2/3 b" Some(Color::White));
+winner_test!(hexapawn_capture Hexapawn "1p1/P2/3 w" None);
+
+split_test!(breakthrough_moves Breakthrough
+    "1pp1pp1p/p1p2p2/3pP3/1P6/2p1p1P1/p4p2/PP1PPPP1/P1P4P b");
+split_test!(hexapawn_moves Hexapawn "1p1/P1P/3 b");
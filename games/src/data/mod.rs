@@ -0,0 +1,27 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Records of scored positions and the sinks they are written into.
+//!
+//! Data generators like [`SelfPlay`](crate::selfplay::SelfPlay) produce
+//! [`SelfPlayRecord`]s, which are written into any [`RecordSink`], like the
+//! training data writers of the games. Keeping them here lets the games
+//! implement sinks without depending on the generators.
+
+// Make the contents of the non-namespaced
+// modules public, so they can be accessed
+// without their parent namespace.
+pub use self::sink::*;
+
+// Non-namespaced modules.
+mod sink;
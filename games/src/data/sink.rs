@@ -0,0 +1,38 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io;
+
+use crate::interface::Wdl;
+
+/// SelfPlayRecord is a position from a self-play game, labelled with its
+/// search score and the final result of the game, both for the side to move.
+#[derive(Clone, Debug)]
+pub struct SelfPlayRecord<P> {
+    pub position: P,
+    pub score: i32,
+    pub result: Wdl,
+}
+
+/// RecordSink is a destination for [`SelfPlayRecord`]s.
+pub trait RecordSink<P>: Send {
+    /// write_record writes the given record into the sink.
+    fn write_record(&mut self, record: &SelfPlayRecord<P>) -> io::Result<()>;
+}
+
+impl<P: Clone + Send> RecordSink<P> for Vec<SelfPlayRecord<P>> {
+    fn write_record(&mut self, record: &SelfPlayRecord<P>) -> io::Result<()> {
+        self.push(record.clone());
+        Ok(())
+    }
+}
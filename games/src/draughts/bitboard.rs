@@ -0,0 +1,58 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::interface::bitboard_type;
+
+use super::{Direction, Square};
+
+bitboard_type! {
+    /// A set of Squares implemented as a bitset where the `1 << sq.into()` bit
+    /// represents whether `sq` is in the BitBoard or not.
+    struct BitBoard : u32 {
+        // The BitBoard's Square type.
+        Square = Square;
+
+        // BitBoards representing the null and the universe sets.
+        Empty = Self(0);
+        Universe = Self(0xffffffff);
+
+        // BitBoards containing the squares of the first file and the first rank.
+        FirstFile = Self(0x11111111);
+        FirstRank = Self(0x0000000f);
+    }
+}
+
+use crate::interface::{BitBoardType, RepresentableType};
+
+impl BitBoard {
+    /// EVEN_RANKS contains all the Squares on the 1st, 3rd, 5th and 7th Ranks.
+    const EVEN_RANKS: BitBoard = BitBoard(0x0f0f0f0f);
+    /// ODD_RANKS contains all the Squares on the 2nd, 4th, 6th and 8th Ranks.
+    const ODD_RANKS: BitBoard = BitBoard(0xf0f0f0f0);
+
+    /// step returns a new BitBoard with all the squares shifted diagonally in
+    /// the given Direction. See [`Square::step`] for how the Squares of the
+    /// even and the odd Ranks move differently.
+    #[must_use]
+    pub fn step(self, direction: Direction) -> BitBoard {
+        let even = self & Self::EVEN_RANKS;
+        let odd = self & Self::ODD_RANKS;
+
+        match direction {
+            Direction::NorthEast => even.north() | odd.north().east(),
+            Direction::NorthWest => even.north().west() | odd.north(),
+            Direction::SouthEast => even.south() | odd.south().east(),
+            Direction::SouthWest => even.south().west() | odd.south(),
+        }
+    }
+}
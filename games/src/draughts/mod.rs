@@ -0,0 +1,31 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Make the contents of the non-namespaced
+// modules public, so they can be accessed
+// without their parent namespace.
+pub use self::bitboard::*;
+pub use self::piece::*;
+pub use self::position::*;
+pub use self::r#move::*;
+pub use self::square::*;
+
+// Non-namespaced modules.
+mod bitboard;
+mod r#move;
+mod piece;
+mod position;
+mod square;
+
+#[cfg(test)]
+mod tests;
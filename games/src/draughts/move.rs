@@ -0,0 +1,354 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::str::FromStr;
+
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use crate::interface::{
+    BitBoardType, MoveList, MoveType, RepresentableType, TypeParseError,
+};
+
+use super::{BitBoard, Direction, Square};
+
+/// Move represents a draughts move which can be played on the Board. A Move is
+/// either a simple move of a piece to a diagonally adjacent Square, or a
+/// capture, which consists of one or more jumps over enemy pieces. The path of
+/// a capture is stored as the Direction of each of its jumps, which is why a
+/// Move needs a 64-bit representation.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Move(u64);
+
+impl MoveType for Move {
+    type Repr = u64;
+
+    const NULL: Self = Move(1 << 63);
+    const MAX_IN_GAME: usize = 1024;
+    const MAX_IN_POSITION: usize = 128;
+
+    type List = MoveList<Self, { Self::MAX_IN_POSITION }>;
+}
+
+impl From<u64> for Move {
+    fn from(value: u64) -> Self {
+        Move(value)
+    }
+}
+
+impl From<Move> for u64 {
+    fn from(value: Move) -> Self {
+        value.0
+    }
+}
+
+impl Move {
+    // Bit-widths of fields.
+    const SOURCE_WIDTH: u64 = 5;
+    const TARGET_WIDTH: u64 = 5;
+    const JUMPS_WIDTH: u64 = 4;
+    const DIRECTION_WIDTH: u64 = 2;
+
+    // Bit-masks of fields.
+    const SOURCE_MASK: u64 = (1 << Self::SOURCE_WIDTH) - 1;
+    const TARGET_MASK: u64 = (1 << Self::TARGET_WIDTH) - 1;
+    const JUMPS_MASK: u64 = (1 << Self::JUMPS_WIDTH) - 1;
+    const DIRECTION_MASK: u64 = (1 << Self::DIRECTION_WIDTH) - 1;
+
+    // Bit-offsets of fields.
+    const SOURCE_OFFSET: u64 = 0;
+    const TARGET_OFFSET: u64 = Self::SOURCE_OFFSET + Self::SOURCE_WIDTH;
+    const JUMPS_OFFSET: u64 = Self::TARGET_OFFSET + Self::TARGET_WIDTH;
+    const PATH_OFFSET: u64 = Self::JUMPS_OFFSET + Self::JUMPS_WIDTH;
+
+    // Directions indexed by their representation in a Move's path.
+    const DIRECTIONS: [Direction; 4] = [
+        Direction::NorthEast,
+        Direction::NorthWest,
+        Direction::SouthEast,
+        Direction::SouthWest,
+    ];
+
+    /// MAX_JUMPS is the maximum number of jumps which can be stored in a Move.
+    pub const MAX_JUMPS: usize = Self::JUMPS_MASK as usize;
+
+    /// NULL Move represents an invalid move.
+    pub const NULL: Move = Move(1 << 63);
+
+    /// new returns a new simple Move from the given source Square to the given
+    /// target Square. These Squares can be recovered with the [`Move::source`]
+    /// and [`Move::target`] methods respectively.
+    /// ```
+    /// use tetka_games::draughts::*;
+    ///
+    /// let mov = Move::new(Square::C3, Square::D4);
+    ///
+    /// assert_eq!(mov.source(), Square::C3);
+    /// assert_eq!(mov.target(), Square::D4);
+    /// assert!(!mov.is_capture());
+    /// ```
+    #[inline(always)]
+    #[rustfmt::skip]
+    pub fn new(source: Square, target: Square) -> Move {
+        Move(
+            (source as u64) << Self::SOURCE_OFFSET |
+            (target as u64) << Self::TARGET_OFFSET
+        )
+    }
+
+    /// new_jump returns a new capture Move which jumps from the given source
+    /// Square over the adjacent Square in the given Direction. Further jumps can
+    /// be added to the capture with the [`Move::with_jump`] method.
+    /// ```
+    /// use tetka_games::draughts::*;
+    ///
+    /// let mov = Move::new_jump(Square::C3, Direction::NorthEast);
+    ///
+    /// assert_eq!(mov.source(), Square::C3);
+    /// assert_eq!(mov.target(), Square::E5);
+    /// assert_eq!(mov.jumps(), 1);
+    /// ```
+    pub fn new_jump(source: Square, direction: Direction) -> Move {
+        Move::new(source, source).with_jump(direction)
+    }
+
+    /// with_jump returns a new Move which continues the current capture with a
+    /// jump from its target Square in the given Direction. The jump must stay
+    /// on the Board, and the Move must have less than [`Move::MAX_JUMPS`] jumps.
+    /// ```
+    /// use tetka_games::draughts::*;
+    ///
+    /// let mov = Move::new_jump(Square::C3, Direction::NorthEast)
+    ///     .with_jump(Direction::NorthWest);
+    ///
+    /// assert_eq!(mov.target(), Square::C7);
+    /// assert_eq!(mov.captures(), BitBoard::from(Square::D4) | Square::D6);
+    /// ```
+    #[must_use]
+    pub fn with_jump(self, direction: Direction) -> Move {
+        let jumps = self.jumps() as u64;
+        debug_assert!(jumps < Self::JUMPS_MASK);
+
+        let target = self
+            .target()
+            .step(direction)
+            .and_then(|sq| sq.step(direction))
+            .expect("jump should stay on the board");
+
+        let fields = Self::TARGET_MASK << Self::TARGET_OFFSET
+            | Self::JUMPS_MASK << Self::JUMPS_OFFSET;
+        Move(
+            self.0 & !fields
+                | (target as u64) << Self::TARGET_OFFSET
+                | (jumps + 1) << Self::JUMPS_OFFSET
+                | (direction as u64)
+                    << (Self::PATH_OFFSET + jumps * Self::DIRECTION_WIDTH),
+        )
+    }
+
+    /// Source returns the source Square of the moving piece.
+    pub fn source(self) -> Square {
+        unsafe {
+            Square::unsafe_from(
+                ((self.0 >> Self::SOURCE_OFFSET) & Self::SOURCE_MASK) as u8,
+            )
+        }
+    }
+
+    /// Target returns the target Square of the moving piece.
+    pub fn target(self) -> Square {
+        unsafe {
+            Square::unsafe_from(
+                ((self.0 >> Self::TARGET_OFFSET) & Self::TARGET_MASK) as u8,
+            )
+        }
+    }
+
+    /// jumps returns the number of jumps, and thus the number of pieces
+    /// captured, in the Move. It is zero for simple moves.
+    pub fn jumps(self) -> usize {
+        ((self.0 >> Self::JUMPS_OFFSET) & Self::JUMPS_MASK) as usize
+    }
+
+    /// is_capture checks if the Move is a capture.
+    pub fn is_capture(self) -> bool {
+        self.jumps() > 0
+    }
+
+    /// directions returns an iterator over the Directions of the jumps of the
+    /// Move in the order they are made.
+    pub fn directions(self) -> impl Iterator<Item = Direction> {
+        (0..self.jumps() as u64).map(move |i| {
+            let offset = Self::PATH_OFFSET + i * Self::DIRECTION_WIDTH;
+            Self::DIRECTIONS
+                [((self.0 >> offset) & Self::DIRECTION_MASK) as usize]
+        })
+    }
+
+    /// path returns an iterator over the Squares which the moving piece lands
+    /// on, ending with the target Square of the Move.
+    pub fn path(self) -> impl Iterator<Item = Square> {
+        let simple = (!self.is_capture()).then_some(self.target());
+        let mut square = self.source();
+        simple
+            .into_iter()
+            .chain(self.directions().map(move |direction| {
+                square =
+                    square.step(direction).unwrap().step(direction).unwrap();
+                square
+            }))
+    }
+
+    /// captures returns a BitBoard containing the Squares of all the pieces
+    /// which are captured by the Move.
+    pub fn captures(self) -> BitBoard {
+        let mut captures = BitBoard::EMPTY;
+        let mut square = self.source();
+        for direction in self.directions() {
+            let jumped = square.step(direction).unwrap();
+            captures.insert(jumped);
+            square = jumped.step(direction).unwrap();
+        }
+
+        captures
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum MoveParseError {
+    #[error("move string should contain at least two squares")]
+    MissingSquare,
+    #[error("simple moves should contain exactly two squares")]
+    TooManySquares,
+    #[error("captures can't have more than {} jumps", Move::MAX_JUMPS)]
+    TooManyJumps,
+    #[error("squares {0} and {1} are not connected diagonally")]
+    NotDiagonal(Square, Square),
+    #[error("bad square string \"{0}\"")]
+    BadSquare(#[from] TypeParseError),
+}
+
+impl FromStr for Move {
+    type Err = MoveParseError;
+
+    /// from_str converts the given string representation of a Move into a
+    /// [Move]. The formats supported are `<source>-<target>` for simple moves
+    /// and `<source>x<square>x...x<target>` for captures, where every Square
+    /// that the capturing piece lands on is listed. This function can be
+    /// treated as the inverse of the [`fmt::Display`] trait for [Move].
+    /// ```
+    /// use tetka_games::draughts::*;
+    /// use core::str::FromStr;
+    ///
+    /// let mov = Move::new_jump(Square::C3, Direction::NorthEast)
+    ///     .with_jump(Direction::NorthWest);
+    ///
+    /// assert_eq!(Move::from_str("22x15x6").unwrap(), mov);
+    /// assert_eq!(Move::from_str(&mov.to_string()).unwrap(), mov);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let capture = s.contains('x');
+        let squares = s
+            .split(if capture { 'x' } else { '-' })
+            .map(|s| {
+                Square::from_str(s).map_err(|_| {
+                    TypeParseError::StrError(stringify!(Square).to_string())
+                })
+            })
+            .collect::<Result<Vec<Square>, TypeParseError>>()?;
+
+        if squares.len() < 2 {
+            return Err(MoveParseError::MissingSquare);
+        }
+
+        // The distance between two consecutive Squares in the Move is one step
+        // for simple moves and two steps for jumps.
+        let direction = |from: Square, to: Square| {
+            Direction::iter()
+                .find(|&direction| {
+                    let next = from.step(direction);
+                    if capture {
+                        next.and_then(|sq| sq.step(direction)) == Some(to)
+                    } else {
+                        next == Some(to)
+                    }
+                })
+                .ok_or(MoveParseError::NotDiagonal(from, to))
+        };
+
+        if !capture {
+            if squares.len() > 2 {
+                return Err(MoveParseError::TooManySquares);
+            }
+
+            direction(squares[0], squares[1])?;
+            return Ok(Move::new(squares[0], squares[1]));
+        }
+
+        if squares.len() - 1 > Move::MAX_JUMPS {
+            return Err(MoveParseError::TooManyJumps);
+        }
+
+        let mut mov = Move::new(squares[0], squares[0]);
+        for pair in squares.windows(2) {
+            mov = mov.with_jump(direction(pair[0], pair[1])?);
+        }
+
+        Ok(mov)
+    }
+}
+
+impl fmt::Display for Move {
+    /// Display formats the given Move in PDN move notation. Simple moves are
+    /// formatted as `<source>-<target>`, while captures list every Square the
+    /// capturing piece lands on separated by an `x`. [`Move::NULL`] is
+    /// formatted as `null`.
+    /// ```
+    /// use tetka_games::draughts::*;
+    ///
+    /// let jump = Move::new_jump(Square::C3, Direction::NorthEast);
+    ///
+    /// assert_eq!(Move::NULL.to_string(), "null");
+    /// assert_eq!(Move::new(Square::C3, Square::D4).to_string(), "22-18");
+    /// assert_eq!(jump.to_string(), "22x15");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Move::NULL {
+            return write!(f, "null");
+        }
+
+        let separator = if self.is_capture() { "x" } else { "-" };
+
+        write!(f, "{}", self.source())?;
+        for square in self.path() {
+            write!(f, "{}{}", separator, square)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Moves are serialized as their move strings.
+crate::interface::serde_string!(Move);
+
+impl fmt::Debug for Move {
+    /// Debug formats the given Move into a human-readable debug string. It uses
+    /// `Move::Display` trait under the hood for formatting the Move.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
@@ -0,0 +1,579 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::str::FromStr;
+
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use crate::interface::{
+    BitBoardType, ColoredPieceType, Hash, MoveStore, MoveType, PositionType,
+    RepresentableType, SquareType, TypeParseError,
+};
+
+use crate::draughts::{
+    BitBoard, Color, ColoredPiece, Direction, File, Move, Piece, Rank, Square,
+};
+
+/// Position represents the snapshot of an English draughts Board, the state of
+/// the game at a single point in time.
+///
+/// Men move diagonally forward, while Kings can also move diagonally backward.
+/// Capturing is compulsory, and a capturing piece must keep jumping until it
+/// can't capture any more pieces, except for a Man which gets crowned to a King
+/// and ends its Move. A side which has no legal moves, including if it has no
+/// pieces left, loses the game.
+#[derive(Copy, Clone)]
+pub struct Position {
+    /// bitboards stores BitBoards for all the ColoredPieces.
+    pub bitboards: [BitBoard; ColoredPiece::N],
+    /// checksum stores the semi-unique [struct@Hash] of the current Position.
+    pub checksum: Hash,
+    /// side_to_move stores the piece whose turn to move it currently is.
+    pub side_to_move: Color,
+}
+
+impl PositionType for Position {
+    type BitBoard = BitBoard;
+    type ColoredPiece = ColoredPiece;
+    type Move = Move;
+
+    fn insert(&mut self, sq: Square, piece: ColoredPiece) {
+        self.bitboards[piece as usize].insert(sq);
+    }
+
+    fn remove(&mut self, sq: Square) -> Option<ColoredPiece> {
+        match self.at(sq) {
+            Some(piece) => {
+                self.bitboards[piece as usize].remove(sq);
+                Some(piece)
+            }
+            None => None,
+        }
+    }
+
+    fn at(&self, sq: Square) -> Option<ColoredPiece> {
+        ColoredPiece::iter()
+            .find(|piece| self.colored_piece_bb(*piece).contains(sq))
+    }
+
+    fn piece_bb(&self, piece: Piece) -> BitBoard {
+        self.colored_piece_bb(ColoredPiece::new(piece, Color::Black))
+            | self.colored_piece_bb(ColoredPiece::new(piece, Color::White))
+    }
+
+    fn color_bb(&self, color: Color) -> BitBoard {
+        self.colored_piece_bb(ColoredPiece::new(Piece::Man, color))
+            | self.colored_piece_bb(ColoredPiece::new(Piece::King, color))
+    }
+
+    fn colored_piece_bb(&self, piece: ColoredPiece) -> BitBoard {
+        self.bitboards[piece as usize]
+    }
+
+    fn hash(&self) -> Hash {
+        self.checksum
+    }
+
+    fn side_to_move(&self) -> Color {
+        self.side_to_move
+    }
+
+    fn fen(&self) -> String {
+        let mut fen = self.side_to_move.to_string();
+        for color in [Color::White, Color::Black] {
+            fen += &format!(":{}", color);
+
+            // Runs of at least three consecutive Squares with the same Piece
+            // are written as ranges, like `1-12` in the starting position.
+            let mut runs: Vec<(Piece, u8, u8)> = vec![];
+            for number in 1..=Square::N as u8 {
+                let square = Square::from_str(&number.to_string()).unwrap();
+                let piece = match self.at(square) {
+                    Some(piece) if piece.color() == Some(color) => {
+                        piece.piece()
+                    }
+                    _ => continue,
+                };
+
+                match runs.last_mut() {
+                    Some((last, _, end))
+                        if *last == piece && *end + 1 == number =>
+                    {
+                        *end = number
+                    }
+                    _ => runs.push((piece, number, number)),
+                }
+            }
+
+            let mut tokens = vec![];
+            for (piece, first, last) in runs {
+                let king = if piece == Piece::King { "K" } else { "" };
+                if last - first >= 2 {
+                    tokens.push(format!("{}{}-{}", king, first, last));
+                } else {
+                    for number in first..=last {
+                        tokens.push(format!("{}{}", king, number));
+                    }
+                }
+            }
+
+            fen += &tokens.join(",");
+        }
+
+        fen
+    }
+
+    fn winner(&self) -> Option<Color> {
+        if self.count_moves::<true, true>() == 0 {
+            // The side to move has no legal moves.
+            Some(!self.side_to_move)
+        } else {
+            None
+        }
+    }
+
+    fn after_move<const UPDATE_HASH: bool>(&self, m: Move) -> Self {
+        let stm = self.side_to_move;
+        let source = m.source();
+        let target = m.target();
+
+        let mut bitboards = self.bitboards;
+
+        // Remove all the captured enemy pieces from the Board.
+        let captures = m.captures();
+        for piece in [Piece::Man, Piece::King] {
+            let piece = ColoredPiece::new(piece, !stm);
+            bitboards[piece as usize] &= !captures;
+        }
+
+        // Move the piece, crowning it if it's a Man which reached the far end.
+        let piece = if self
+            .colored_piece_bb(ColoredPiece::new(Piece::Man, stm))
+            .contains(source)
+        {
+            Piece::Man
+        } else {
+            Piece::King
+        };
+
+        bitboards[ColoredPiece::new(piece, stm) as usize].remove(source);
+        let piece = if Self::crowning_rank(stm).contains(target) {
+            Piece::King
+        } else {
+            piece
+        };
+        bitboards[ColoredPiece::new(piece, stm) as usize].insert(target);
+
+        Position {
+            bitboards,
+            checksum: if UPDATE_HASH {
+                Self::get_hash(bitboards, !stm)
+            } else {
+                Default::default()
+            },
+            side_to_move: !stm,
+        }
+    }
+
+    fn generate_moves_into<
+        const ALLOW_ILLEGAL: bool,
+        const QUIET: bool,
+        const NOISY: bool,
+        T: MoveStore<Move>,
+    >(
+        &self,
+        movelist: &mut T,
+    ) {
+        // There is no need to check if the game is over here, since the game is
+        // only over when the side to move has no moves to generate.
+        let has_captures = self.has_captures();
+
+        if NOISY && has_captures {
+            self.generate_captures(movelist);
+        }
+
+        // Capturing is compulsory, so simple moves are illegal if the side to
+        // move has any captures available.
+        if QUIET && (ALLOW_ILLEGAL || !has_captures) {
+            let stm = self.side_to_move;
+            let empty = !self.occupied();
+            let men = self.colored_piece_bb(ColoredPiece::new(Piece::Man, stm));
+            let kings =
+                self.colored_piece_bb(ColoredPiece::new(Piece::King, stm));
+
+            for direction in Direction::iter() {
+                let movers = if Self::is_forward(stm, direction) {
+                    men | kings
+                } else {
+                    kings
+                };
+
+                // The source Square of a Move is found by stepping back from
+                // its target Square in the opposite Direction.
+                for target in movers.step(direction) & empty {
+                    let source = target.step(direction.opposite()).unwrap();
+                    movelist.push(Move::new(source, target));
+                }
+            }
+        }
+    }
+
+    fn count_moves<const QUIET: bool, const NOISY: bool>(&self) -> usize {
+        if self.has_captures() {
+            if !NOISY {
+                return 0;
+            }
+
+            let mut movelist = <Move as MoveType>::List::new();
+            self.generate_captures(&mut movelist);
+            return movelist.len();
+        }
+
+        if !QUIET {
+            return 0;
+        }
+
+        let stm = self.side_to_move;
+        let empty = !self.occupied();
+        let men = self.colored_piece_bb(ColoredPiece::new(Piece::Man, stm));
+        let kings = self.colored_piece_bb(ColoredPiece::new(Piece::King, stm));
+
+        Direction::iter()
+            .map(|direction| {
+                let movers = if Self::is_forward(stm, direction) {
+                    men | kings
+                } else {
+                    kings
+                };
+
+                (movers.step(direction) & empty).len()
+            })
+            .sum()
+    }
+}
+
+impl Position {
+    /// occupied returns a BitBoard containing all the occupied Squares.
+    pub fn occupied(&self) -> BitBoard {
+        self.color_bb(Color::Black) | self.color_bb(Color::White)
+    }
+
+    /// has_captures checks if the side to move has any captures available.
+    pub fn has_captures(&self) -> bool {
+        let stm = self.side_to_move;
+        let enemies = self.color_bb(!stm);
+        let empty = !self.occupied();
+        let men = self.colored_piece_bb(ColoredPiece::new(Piece::Man, stm));
+        let kings = self.colored_piece_bb(ColoredPiece::new(Piece::King, stm));
+
+        Direction::iter().any(|direction| {
+            let movers = if Self::is_forward(stm, direction) {
+                men | kings
+            } else {
+                kings
+            };
+
+            let jumped = movers.step(direction) & enemies;
+            !(jumped.step(direction) & empty).is_empty()
+        })
+    }
+
+    /// generate_captures adds all the captures available to the side to move
+    /// into the given move storage. Every distinct path of jumps is a separate
+    /// Move, even if two paths capture the same pieces.
+    fn generate_captures<T: MoveStore<Move>>(&self, movelist: &mut T) {
+        let stm = self.side_to_move;
+        let enemies = self.color_bb(!stm);
+
+        for piece in [Piece::Man, Piece::King] {
+            for source in self.colored_piece_bb(ColoredPiece::new(piece, stm)) {
+                // The capturing piece leaves its source Square, so it can
+                // land on it again during the capture.
+                let empty = !self.occupied() | BitBoard::from(source);
+
+                self.extend_capture(
+                    movelist,
+                    Move::new(source, source),
+                    piece,
+                    enemies,
+                    empty,
+                );
+            }
+        }
+    }
+
+    /// extend_capture recursively extends the given capture with all the
+    /// possible jumps from its target Square, and adds the captures which
+    /// can't be extended any further into the given move storage. The pieces
+    /// captured during a Move are only removed once it is complete, so they
+    /// can't be jumped over twice and they block the capturing piece.
+    fn extend_capture<T: MoveStore<Move>>(
+        &self,
+        movelist: &mut T,
+        mov: Move,
+        piece: Piece,
+        enemies: BitBoard,
+        empty: BitBoard,
+    ) {
+        let stm = self.side_to_move;
+        let square = mov.target();
+
+        // A Man which is crowned during a capture ends its Move.
+        let crowned = piece == Piece::Man
+            && mov.is_capture()
+            && Self::crowning_rank(stm).contains(square);
+
+        let mut extended = false;
+        if !crowned {
+            for direction in Direction::iter() {
+                if piece == Piece::Man && !Self::is_forward(stm, direction) {
+                    continue;
+                }
+
+                let jumped = match square.step(direction) {
+                    Some(sq) if enemies.contains(sq) => sq,
+                    _ => continue,
+                };
+
+                match jumped.step(direction) {
+                    Some(landing) if empty.contains(landing) => {
+                        extended = true;
+                        self.extend_capture(
+                            movelist,
+                            mov.with_jump(direction),
+                            piece,
+                            enemies & !BitBoard::from(jumped),
+                            empty,
+                        );
+                    }
+                    _ => continue,
+                }
+            }
+        }
+
+        if !extended && mov.is_capture() {
+            movelist.push(mov);
+        }
+    }
+
+    /// is_forward checks if the given Direction is forward for the Men of the
+    /// given Color. Black moves towards the first Rank, White towards the last.
+    fn is_forward(color: Color, direction: Direction) -> bool {
+        match color {
+            Color::Black => {
+                matches!(direction, Direction::SouthEast | Direction::SouthWest)
+            }
+            Color::White => {
+                matches!(direction, Direction::NorthEast | Direction::NorthWest)
+            }
+        }
+    }
+
+    /// crowning_rank returns the Rank on which the Men of the given Color are
+    /// crowned to Kings.
+    fn crowning_rank(color: Color) -> BitBoard {
+        match color {
+            Color::Black => BitBoard::rank(Rank::First),
+            Color::White => BitBoard::rank(Rank::Eighth),
+        }
+    }
+
+    fn get_hash(bitboards: [BitBoard; ColoredPiece::N], stm: Color) -> Hash {
+        let bitset = |color: Color| {
+            let men = ColoredPiece::new(Piece::Man, color) as usize;
+            let kings = ColoredPiece::new(Piece::King, color) as usize;
+            u32::from(bitboards[men]) as u128
+                | (u32::from(bitboards[kings]) as u128) << 32
+        };
+
+        let hash =
+            Hash::from_bitsets(bitset(Color::Black), bitset(Color::White));
+
+        // The Hash is bitwise complemented if the given side to move is Black.
+        // Therefore, if two Positions only differ in side to move,
+        // `a.Hash == !b.Hash`.
+        if stm == Color::Black {
+            !hash
+        } else {
+            hash
+        }
+    }
+}
+
+impl Default for Position {
+    /// Returns the starting Position of the game, with Black's Men on the
+    /// Squares 1 to 12, White's Men on the Squares 21 to 32 and Black to move.
+    fn default() -> Self {
+        let mut bitboards = [BitBoard::EMPTY; ColoredPiece::N];
+        bitboards[ColoredPiece::BlackMan as usize] = BitBoard::from(0xfff00000);
+        bitboards[ColoredPiece::WhiteMan as usize] = BitBoard::from(0x00000fff);
+
+        Position {
+            bitboards,
+            checksum: Self::get_hash(bitboards, Color::Black),
+            side_to_move: Color::Black,
+        }
+    }
+}
+
+/// PositionParseErr represents an error encountered while parsing
+/// the given FEN position field into a valid Position.
+#[derive(Error, Debug)]
+pub enum PositionParseError {
+    #[error("expected 3 fields, found {0}")]
+    WrongFieldNumber(usize),
+
+    #[error("parsing side to move: {0}")]
+    BadSideToMove(#[from] TypeParseError),
+
+    #[error("bad color in piece list \"{0}\"")]
+    BadColor(String),
+
+    #[error("more than one piece list for color {0}")]
+    DuplicateColor(Color),
+
+    #[error("bad square \"{0}\" in piece list")]
+    BadSquare(String),
+
+    #[error("square {0} occupied more than once")]
+    DuplicateSquare(Square),
+}
+
+// FromStr implements parsing of a PDN FEN. The FEN contains the side to move,
+// followed by a piece list for each color, separated by colons. A piece list
+// starts with its color and contains comma separated Square numbers, or ranges
+// of Square numbers, prefixed with a `K` for Kings. For example, the starting
+// position is `B:W21-32:B1-12` or `B:W21,22,...,32:B1,2,...,12`.
+impl FromStr for Position {
+    type Err = PositionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // A PDN FEN may be quoted and terminated by a period.
+        let s = s.trim().trim_matches('"').trim_end_matches('.');
+        let parts = s.split(':').collect::<Vec<&str>>();
+
+        if parts.len() != 3 {
+            return Err(PositionParseError::WrongFieldNumber(parts.len()));
+        }
+
+        let mut position = Position {
+            bitboards: [BitBoard::EMPTY; ColoredPiece::N],
+            checksum: Default::default(),
+            side_to_move: Color::from_str(parts[0])?,
+        };
+
+        let mut seen = [false; Color::N];
+        for list in &parts[1..] {
+            let (color, squares) = list.split_at(
+                list.char_indices().nth(1).map_or(list.len(), |(i, _)| i),
+            );
+            let color = Color::from_str(color)
+                .map_err(|_| PositionParseError::BadColor(list.to_string()))?;
+
+            if seen[color as usize] {
+                return Err(PositionParseError::DuplicateColor(color));
+            }
+            seen[color as usize] = true;
+
+            for token in squares.split(',').filter(|t| !t.is_empty()) {
+                let (piece, range) = match token.strip_prefix('K') {
+                    Some(range) => (Piece::King, range),
+                    None => (Piece::Man, token),
+                };
+
+                let bad_square =
+                    || PositionParseError::BadSquare(token.to_string());
+                let number = |s: &str| {
+                    s.parse::<u8>()
+                        .ok()
+                        .filter(|n| (1..=Square::N as u8).contains(n))
+                        .ok_or_else(bad_square)
+                };
+
+                let (first, last) = match range.split_once('-') {
+                    Some((first, last)) => (number(first)?, number(last)?),
+                    None => (number(range)?, number(range)?),
+                };
+
+                if first > last {
+                    return Err(bad_square());
+                }
+
+                for number in first..=last {
+                    let square = Square::from_str(&number.to_string()).unwrap();
+                    if position.at(square).is_some() {
+                        return Err(PositionParseError::DuplicateSquare(
+                            square,
+                        ));
+                    }
+
+                    position.insert(square, ColoredPiece::new(piece, color));
+                }
+            }
+        }
+
+        // Calculate the Hash value for the Position.
+        position.checksum =
+            Self::get_hash(position.bitboards, position.side_to_move);
+
+        Ok(position)
+    }
+}
+
+// Display implements displaying a Position using ASCII art. Only the dark
+// Squares of the Board can be occupied, so the light Squares are left blank.
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut string_rep = String::from(" ");
+
+        for rank in Rank::iter().rev() {
+            // The dark Squares of even Ranks are on the first file of each
+            // File, while those of odd Ranks are on the second file.
+            let odd = rank as usize % 2 == 1;
+            for file in File::iter() {
+                let square = Square::new(file, rank);
+                let square_str = match self.at(square) {
+                    Some(piece) => format!("{} ", piece),
+                    None => ". ".to_string(),
+                };
+
+                if odd {
+                    string_rep += "  ";
+                    string_rep += &square_str;
+                } else {
+                    string_rep += &square_str;
+                    string_rep += "  ";
+                }
+            }
+
+            // Append the rank marker.
+            string_rep += &format!(" {} \n ", rank);
+        }
+
+        // Append the file markers.
+        string_rep += "a b c d e f g h";
+
+        writeln!(f, "{}", string_rep.trim_end()).unwrap();
+        writeln!(f, "Side To Move: {}", self.side_to_move)
+    }
+}
+
+// Positions are serialized as their FEN strings.
+crate::interface::serde_string!(Position, crate::interface::PositionType::fen);
@@ -0,0 +1,125 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::interface::{representable_type, RepresentableType, SquareType};
+
+representable_type!(
+    /// Square represents all the dark squares present on a draughts Board, the
+    /// only ones which can be occupied by pieces. Each Square is named after its
+    /// algebraic coordinate, and is represented by its PDN number as a string.
+    /// The index of each Square is equal to `rank-index * 4 + file-index`.
+    enum Square: u8 {
+        A1 "29", C1 "30", E1 "31", G1 "32",
+        B2 "25", D2 "26", F2 "27", H2 "28",
+        A3 "21", C3 "22", E3 "23", G3 "24",
+        B4 "17", D4 "18", F4 "19", H4 "20",
+        A5 "13", C5 "14", E5 "15", G5 "16",
+        B6 "9", D6 "10", F6 "11", H6 "12",
+        A7 "5", C7 "6", E7 "7", G7 "8",
+        B8 "1", D8 "2", F8 "3", H8 "4",
+    }
+);
+
+impl SquareType for Square {
+    type File = File;
+    type Rank = Rank;
+}
+
+representable_type!(
+    /// File represents a file on the draughts Board. Since only the dark squares
+    /// are represented, each File contains the dark squares from a pair of the
+    /// algebraic files. There are 4 of them in total.
+    enum File: u8 { AB "ab", CD "cd", EF "ef", GH "gh", }
+);
+
+representable_type!(
+    /// Rank represents a rank on the draughts Board. Each horizontal row of
+    /// Squares on a draughts Board is known as a Rank. There are 8 of them in
+    /// total, with Black's pieces starting on the last 3 and White's pieces on
+    /// the first 3.
+    enum Rank: u8 {
+        First "1", Second "2", Third "3", Fourth "4",
+        Fifth "5", Sixth "6", Seventh "7", Eighth "8",
+    }
+);
+
+/// Direction represents the four diagonal directions in which the pieces on a
+/// draughts Board can move.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, strum_macros::EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Direction {
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+impl Direction {
+    /// opposite returns the Direction which points the opposite way.
+    /// ```
+    /// use tetka_games::draughts::*;
+    ///
+    /// assert_eq!(Direction::NorthEast.opposite(), Direction::SouthWest);
+    /// ```
+    #[must_use]
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::NorthEast => Direction::SouthWest,
+            Direction::NorthWest => Direction::SouthEast,
+            Direction::SouthEast => Direction::NorthWest,
+            Direction::SouthWest => Direction::NorthEast,
+        }
+    }
+}
+
+impl Square {
+    /// step returns the Square which is diagonally adjacent to self in the given
+    /// Direction. If there is no such Square, it returns None.
+    /// ```
+    /// use tetka_games::draughts::*;
+    ///
+    /// assert_eq!(Square::C3.step(Direction::NorthEast), Some(Square::D4));
+    /// assert_eq!(Square::C3.step(Direction::SouthWest), Some(Square::B2));
+    /// assert_eq!(Square::A1.step(Direction::SouthWest), None);
+    /// ```
+    #[must_use]
+    pub fn step(self, direction: Direction) -> Option<Square> {
+        let rank = self.rank() as u8;
+        let file = self.file() as u8;
+
+        // The dark squares of even Ranks are on the first file of each File,
+        // while those of odd Ranks are on the second file. So, moving diagonally
+        // only changes the File when moving towards its other half.
+        let even = rank.is_multiple_of(2);
+        let (rank, file) = match direction {
+            Direction::NorthEast => (rank.checked_add(1)?, file + !even as u8),
+            Direction::NorthWest => {
+                (rank.checked_add(1)?, file.checked_sub(even as u8)?)
+            }
+            Direction::SouthEast => (rank.checked_sub(1)?, file + !even as u8),
+            Direction::SouthWest => {
+                (rank.checked_sub(1)?, file.checked_sub(even as u8)?)
+            }
+        };
+
+        if rank as usize >= Rank::N || file as usize >= File::N {
+            return None;
+        }
+
+        Some(unsafe { Square::unsafe_from(rank * File::N as u8 + file) })
+    }
+}
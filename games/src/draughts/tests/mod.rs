@@ -0,0 +1,135 @@
+use crate::draughts::{Color, Move, Position};
+use crate::interface::PositionType;
+use crate::perft;
+use std::str::FromStr;
+
+macro_rules! perft_test {
+    ($name:ident $pos:literal $depth:literal $nodes:literal) => {
+        #[test]
+        fn $name() {
+            let position = Position::from_str($pos).unwrap();
+            assert_eq!(perft::<true, true, _>(position, $depth), $nodes)
+        }
+    };
+}
+
+macro_rules! moves_test {
+    ($name:ident $pos:literal [$($mov:literal),*]) => {
+        #[test]
+        fn $name() {
+            let position = Position::from_str($pos).unwrap();
+            let mut moves = position
+                .generate_moves::<false, true, true>()
+                .iter()
+                .map(|m| m.to_string())
+                .collect::<Vec<String>>();
+            moves.sort();
+
+            let mut expected: Vec<String> = vec![$($mov.to_string()),*];
+            expected.sort();
+
+            assert_eq!(moves, expected);
+            assert_eq!(position.count_moves::<true, true>(), expected.len());
+        }
+    };
+}
+
+perft_test!(startpos_01 "B:W21-32:B1-12" 1 7);
+perft_test!(startpos_02 "B:W21-32:B1-12" 2 49);
+perft_test!(startpos_03 "B:W21-32:B1-12" 3 302);
+perft_test!(startpos_04 "B:W21-32:B1-12" 4 1469);
+perft_test!(startpos_05 "B:W21-32:B1-12" 5 7361);
+perft_test!(startpos_06 "B:W21-32:B1-12" 6 36768);
+perft_test!(startpos_07 "B:W21-32:B1-12" 7 179740);
+
+perft_test!(kings_01 "W:W18,24,27,28,K10,K15:B12,16,20,K22,K25,K29" 6 30195);
+perft_test!(kings_02 "B:WK1,K2,K3,K4,17,18:BK29,K30,K31,K32,14,15" 5 9540);
+perft_test!(multi_jump_01 "B:W6,7,14,15,22,23:B1,K32" 6 6260);
+perft_test!(crowning_01 "W:W9,10,11:B1,5,K28" 6 2066);
+
+// Every path of a multi-jump is a separate move, even if both paths capture
+// the same pieces and end on the same square.
+moves_test!(king_paths "B:W6,7,14,15,22,23:B1,K32"
+    ["1x10x17x26", "1x10x19x26"]);
+
+// A man which is crowned during a capture ends its move there.
+moves_test!(crowning_ends_capture "W:W9,10:B5,6,K28" ["10x1", "9x2"]);
+
+// Captures are compulsory.
+moves_test!(forced_capture "B:W18,30:B14,K10" ["14x23"]);
+
+#[test]
+fn start_position() {
+    let position = Position::default();
+    let parsed = Position::from_str(
+        "B:W21,22,23,24,25,26,27,28,29,30,31,32:B1,2,3,4,5,6,7,8,9,10,11,12",
+    )
+    .unwrap();
+
+    assert_eq!(position.bitboards, parsed.bitboards);
+    assert_eq!(position.hash(), parsed.hash());
+    assert_eq!(position.side_to_move, Color::Black);
+}
+
+#[test]
+fn fen_errors() {
+    for fen in [
+        "B:W21-32",
+        "X:W21-32:B1-12",
+        "B:W21-32:W1-12",
+        "B:W21-32:X1-12",
+        "B:W21-33:B1-12",
+        "B:W21-32:B1-21",
+        "B:W12-1:B13",
+    ] {
+        assert!(Position::from_str(fen).is_err(), "{}", fen);
+    }
+}
+
+#[test]
+fn winner() {
+    let blocked = Position::from_str("W:W29:B25,22").unwrap();
+    assert_eq!(blocked.winner(), Some(Color::Black));
+
+    let captured = Position::from_str("B:WK1:B").unwrap();
+    assert_eq!(captured.winner(), Some(Color::White));
+
+    let ongoing = Position::default();
+    assert_eq!(ongoing.winner(), None);
+}
+
+#[test]
+fn move_strings() {
+    for mov in ["11-15", "22x15x6", "1x10x17x26", "9x2"] {
+        assert_eq!(Move::from_str(mov).unwrap().to_string(), mov);
+    }
+
+    for mov in ["11-19", "11-18", "22x18", "11", "11-15-18", "null"] {
+        assert!(Move::from_str(mov).is_err(), "{}", mov);
+    }
+
+    let mov = Move::from_str("1x10x19x26").unwrap();
+    assert_eq!(Move::from(u64::from(mov)), mov);
+    assert_eq!(mov.jumps(), 3);
+    assert_ne!(mov, Move::NULL);
+}
+
+#[test]
+fn fen() {
+    for (fen, canonical) in [
+        ("B:W21-32:B1-12", "B:W21-32:B1-12"),
+        (
+            "W:W18,24,27,28,K10,K15:B12,16,20,K22,K25,K29",
+            "W:WK10,K15,18,24,27,28:B12,16,20,K22,K25,K29",
+        ),
+        ("B:W1,2,K3,K4,K5:B", "B:W1,2,K3-5:B"),
+        ("\"B:W:B32.\"", "B:W:B32"),
+    ] {
+        let position = Position::from_str(fen).unwrap();
+        assert_eq!(position.fen(), canonical);
+        assert_eq!(
+            Position::from_str(canonical).unwrap().hash(),
+            position.hash()
+        );
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime selection of games.
+//!
+//! The [`PositionType`](crate::interface::PositionType) interface is fully
+//! static, which makes it fast, but means that a program has to know which
+//! games it works with at compile time. [`DynPosition`] is an object-safe
+//! wrapper over it, which works with strings instead of the types of a game,
+//! and is implemented for every position type. A [`Registry`] maps the names
+//! of games to their positions, so that games can be picked by name at runtime.
+
+// Make the contents of the non-namespaced
+// modules public, so they can be accessed
+// without their parent namespace.
+pub use self::position::*;
+pub use self::registry::*;
+
+// Non-namespaced modules.
+mod position;
+mod registry;
+
+#[cfg(test)]
+mod tests;
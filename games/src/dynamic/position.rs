@@ -0,0 +1,152 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+
+use crate::interface::{Hash, MoveError, PositionType, Wdl};
+
+/// DynPosition is an object-safe version of
+/// [`PositionType`](crate::interface::PositionType), which represents moves,
+/// colors and positions as strings. It is implemented for every position type,
+/// and is mostly used as a `Box<dyn DynPosition>` when the game being played
+/// is only known at runtime.
+/// ```
+/// use tetka_games::dynamic::DynPosition;
+/// use tetka_games::mnk::TicTacToe;
+/// use core::str::FromStr;
+///
+/// let mut position: Box<dyn DynPosition> =
+///     Box::new(TicTacToe::from_str("3/3/3 x").unwrap());
+///
+/// for mov in ["a1", "b1", "b2", "c1", "c3"] {
+///     position.play(mov).unwrap();
+/// }
+///
+/// assert_eq!(position.fen(), "2x/1x1/xoo o");
+/// assert_eq!(position.winner(), Some("x".to_string()));
+/// assert!(position.play("a2").is_err());
+/// ```
+pub trait DynPosition: Display + Send + Sync {
+    /// fen returns the FEN of the position.
+    fn fen(&self) -> String;
+
+    /// hash returns a semi-unique checksum of the position.
+    fn hash(&self) -> Hash;
+
+    /// side_to_move returns the color of the side to move.
+    fn side_to_move(&self) -> String;
+
+    /// winner returns the color of the side which has won, if any.
+    fn winner(&self) -> Option<String>;
+
+    /// result returns the result of the game for the side to move, if the game
+    /// is over.
+    fn result(&self) -> Option<Wdl>;
+
+    /// is_game_over checks if the game is over in the position.
+    fn is_game_over(&self) -> bool;
+
+    /// legal_moves returns all the legal moves in the position.
+    fn legal_moves(&self) -> Vec<String>;
+
+    /// play plays the given move on the position, if it is legal. The
+    /// squares in the returned error are formatted as strings.
+    fn play(&mut self, mov: &str) -> Result<(), MoveError<String>>;
+
+    /// perft counts the leaf nodes of the move generation tree of the given
+    /// depth, as in [`perft`](crate::perft).
+    fn perft(&self, depth: u8) -> u64;
+
+    /// box_clone returns a boxed copy of the position.
+    fn box_clone(&self) -> Box<dyn DynPosition>;
+}
+
+impl<P> DynPosition for P
+where
+    P: PositionType + Clone + Send + Sync + 'static,
+{
+    fn fen(&self) -> String {
+        PositionType::fen(self)
+    }
+
+    fn hash(&self) -> Hash {
+        PositionType::hash(self)
+    }
+
+    fn side_to_move(&self) -> String {
+        PositionType::side_to_move(self).to_string()
+    }
+
+    fn winner(&self) -> Option<String> {
+        PositionType::winner(self).map(|color| color.to_string())
+    }
+
+    fn result(&self) -> Option<Wdl> {
+        PositionType::result(self)
+    }
+
+    fn is_game_over(&self) -> bool {
+        PositionType::is_game_over(self)
+    }
+
+    fn legal_moves(&self) -> Vec<String> {
+        self.generate_moves::<false, true, true>()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn play(&mut self, mov: &str) -> Result<(), MoveError<String>> {
+        let mov = self.parse_move(mov).map_err(stringify)?;
+        *self = self.after_move::<true>(mov);
+        Ok(())
+    }
+
+    fn perft(&self, depth: u8) -> u64 {
+        crate::perft::<false, true, P>(self.clone(), depth)
+    }
+
+    fn box_clone(&self) -> Box<dyn DynPosition> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn DynPosition> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
+/// stringify converts the squares in the given MoveError into strings.
+fn stringify<S: Display>(err: MoveError<S>) -> MoveError<String> {
+    match err {
+        MoveError::BadSyntax(string) => MoveError::BadSyntax(string),
+        MoveError::GameOver => MoveError::GameOver,
+        MoveError::NoPieceOnSource(sq) => {
+            MoveError::NoPieceOnSource(sq.to_string())
+        }
+        MoveError::OccupiedTarget(sq) => {
+            MoveError::OccupiedTarget(sq.to_string())
+        }
+        MoveError::TooFar(source, target) => {
+            MoveError::TooFar(source.to_string(), target.to_string())
+        }
+        MoveError::Unreachable(sq) => MoveError::Unreachable(sq.to_string()),
+        MoveError::PassWithMovesAvailable => MoveError::PassWithMovesAvailable,
+        MoveError::Illegal(mov) => MoveError::Illegal(mov),
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::str::FromStr;
+
+use thiserror::Error;
+
+use crate::dynamic::DynPosition;
+use crate::interface::PositionType;
+use crate::{amazons, ataxx, breakthrough, draughts, hex, mnk};
+
+/// RegistryError represents an error encountered while creating a position
+/// from a [`Registry`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum RegistryError {
+    #[error("unknown game {0}")]
+    UnknownGame(String),
+    #[error("invalid {0} position: {1}")]
+    BadPosition(&'static str, String),
+}
+
+/// Game describes a game in a [`Registry`], and creates its positions.
+#[derive(Copy, Clone, Debug)]
+pub struct Game {
+    name: &'static str,
+    startpos: &'static str,
+    parse: fn(&str) -> Result<Box<dyn DynPosition>, String>,
+}
+
+impl Game {
+    /// new creates a new Game with the given name and starting position, whose
+    /// positions are represented by the type `P`.
+    pub fn new<P>(name: &'static str, startpos: &'static str) -> Self
+    where
+        P: PositionType + Clone + Send + Sync + 'static,
+        <P as FromStr>::Err: Display,
+    {
+        Game {
+            name,
+            startpos,
+            parse: |fen| match P::from_str(fen) {
+                Ok(position) => Ok(Box::new(position)),
+                Err(err) => Err(err.to_string()),
+            },
+        }
+    }
+
+    /// name returns the name of the Game.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// startpos returns the FEN of the starting position of the Game.
+    pub fn startpos(&self) -> &'static str {
+        self.startpos
+    }
+
+    /// parse parses the given FEN into a position of the Game.
+    pub fn parse(
+        &self,
+        fen: &str,
+    ) -> Result<Box<dyn DynPosition>, RegistryError> {
+        (self.parse)(fen)
+            .map_err(|err| RegistryError::BadPosition(self.name, err))
+    }
+
+    /// start returns the starting position of the Game.
+    pub fn start(&self) -> Box<dyn DynPosition> {
+        self.parse(self.startpos)
+            .expect("starting position of game should be valid")
+    }
+}
+
+/// Registry maps the names of games to their positions. The default Registry
+/// contains every game implemented by this crate, and new games can be added
+/// to a Registry with [`register`](Registry::register).
+/// ```
+/// use tetka_games::dynamic::Registry;
+///
+/// let registry = Registry::default();
+/// assert!(registry.names().any(|name| name == "ataxx"));
+///
+/// let position = registry.parse("ataxx", "x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+/// assert_eq!(position.legal_moves().len(), 16);
+/// assert_eq!(position.perft(2), 256);
+///
+/// assert!(registry.parse("chess", "8/8/8/8/8/8/8/8 w").is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Registry {
+    games: Vec<Game>,
+}
+
+impl Registry {
+    /// new creates a new Registry without any games.
+    #[must_use]
+    pub fn new() -> Self {
+        Registry { games: vec![] }
+    }
+
+    /// register adds the given Game to the Registry. A Game which was already
+    /// registered under the same name is replaced and returned.
+    pub fn register(&mut self, game: Game) -> Option<Game> {
+        match self.games.iter_mut().find(|old| old.name == game.name) {
+            Some(old) => Some(core::mem::replace(old, game)),
+            None => {
+                self.games.push(game);
+                None
+            }
+        }
+    }
+
+    /// get returns the Game registered under the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&Game> {
+        self.games.iter().find(|game| game.name == name)
+    }
+
+    /// games returns an iterator over the registered Games, in the order of
+    /// their registration.
+    pub fn games(&self) -> impl Iterator<Item = &Game> {
+        self.games.iter()
+    }
+
+    /// names returns an iterator over the names of the registered Games.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.games.iter().map(|game| game.name)
+    }
+
+    /// parse parses the given FEN into a position of the named game.
+    pub fn parse(
+        &self,
+        name: &str,
+        fen: &str,
+    ) -> Result<Box<dyn DynPosition>, RegistryError> {
+        self.game(name)?.parse(fen)
+    }
+
+    /// start returns the starting position of the named game.
+    pub fn start(
+        &self,
+        name: &str,
+    ) -> Result<Box<dyn DynPosition>, RegistryError> {
+        Ok(self.game(name)?.start())
+    }
+
+    fn game(&self, name: &str) -> Result<&Game, RegistryError> {
+        self.get(name)
+            .ok_or_else(|| RegistryError::UnknownGame(name.to_string()))
+    }
+}
+
+impl Default for Registry {
+    /// default returns a Registry containing every game implemented by this
+    /// crate, under their lowercase names with words separated by `-`.
+    fn default() -> Self {
+        Registry {
+            games: vec![
+                Game::new::<ataxx::Position>(
+                    "ataxx",
+                    "x5o/7/7/7/7/7/o5x x 0 1",
+                ),
+                Game::new::<amazons::Position>(
+                    "amazons",
+                    "3b2b3/10/10/b8b/10/10/w8w/10/10/3w2w3 w",
+                ),
+                Game::new::<breakthrough::Breakthrough>(
+                    "breakthrough",
+                    "pppppppp/pppppppp/8/8/8/8/PPPPPPPP/PPPPPPPP w",
+                ),
+                Game::new::<breakthrough::MiniBreakthrough>(
+                    "mini-breakthrough",
+                    "pppppp/pppppp/6/6/PPPPPP/PPPPPP w",
+                ),
+                Game::new::<breakthrough::Hexapawn>("hexapawn", "ppp/3/PPP w"),
+                Game::new::<draughts::Position>("draughts", "B:W21-32:B1-12"),
+                Game::new::<hex::Hex7x7>("hex7x7", "7/7/7/7/7/7/7 x"),
+                Game::new::<hex::Hex11x11>(
+                    "hex11x11",
+                    "11/11/11/11/11/11/11/11/11/11/11 x",
+                ),
+                Game::new::<mnk::TicTacToe>("tictactoe", "3/3/3 x"),
+                Game::new::<mnk::ConnectFour>("connect-four", "7/7/7/7/7/7 x"),
+                Game::new::<mnk::Gomoku>(
+                    "gomoku",
+                    "11/11/11/11/11/11/11/11/11/11/11 x",
+                ),
+            ],
+        }
+    }
+}
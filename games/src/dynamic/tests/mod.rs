@@ -0,0 +1,121 @@
+use crate::dynamic::*;
+use crate::interface::{MoveError, Wdl};
+use crate::mnk::TicTacToe;
+use crate::random::Rng;
+
+#[test]
+fn startpos_perft() {
+    let registry = Registry::default();
+    let expected = [
+        ("ataxx", 2, 256),
+        ("amazons", 1, 2176),
+        ("breakthrough", 4, 256036),
+        ("mini-breakthrough", 4, 71478),
+        ("hexapawn", 3, 28),
+        ("draughts", 5, 7361),
+        ("hex7x7", 3, 112896),
+        ("hex11x11", 2, 14641),
+        ("tictactoe", 5, 15120),
+        ("connect-four", 6, 117649),
+        ("gomoku", 2, 14520),
+    ];
+
+    assert_eq!(registry.games().count(), expected.len());
+    for (name, depth, nodes) in expected {
+        let position = registry.start(name).unwrap();
+        assert_eq!(position.perft(depth), nodes, "{}", name);
+    }
+}
+
+#[test]
+fn fen_round_trip() {
+    let registry = Registry::default();
+    let mut rng = Rng::new(0x7E7CA);
+
+    // Play random games of every game, checking that the FEN of every position
+    // on the way parses back into the same position.
+    for game in registry.games() {
+        for _ in 0..10 {
+            let mut position = game.start();
+            while !position.is_game_over() {
+                let fen = position.fen();
+                let reparsed = game.parse(&fen).unwrap();
+                assert_eq!(reparsed.fen(), fen, "{}", game.name());
+                assert_eq!(reparsed.hash(), position.hash(), "{}", fen);
+
+                let moves = position.legal_moves();
+                let mov = &moves[rng.below(moves.len())];
+                position.play(mov).unwrap();
+            }
+        }
+    }
+}
+
+#[test]
+fn play_moves() {
+    let mut position = Registry::default().start("tictactoe").unwrap();
+
+    assert_eq!(position.legal_moves().len(), 9);
+    assert_eq!(position.side_to_move(), "x");
+    assert_eq!(position.winner(), None);
+    assert_eq!(position.result(), None);
+
+    position.play("b2").unwrap();
+    assert_eq!(position.side_to_move(), "o");
+    assert_eq!(
+        position.play("b2"),
+        Err(MoveError::Illegal("b2".to_string()))
+    );
+    assert!(matches!(position.play("z9"), Err(MoveError::BadSyntax(_))));
+
+    // Clones of a position are independent of it.
+    let before = position.clone();
+    for mov in ["a1", "a3", "c3", "c1"] {
+        position.play(mov).unwrap();
+    }
+
+    assert_eq!(before.legal_moves().len(), 8);
+    assert_eq!(position.winner(), Some("x".to_string()));
+    assert_eq!(position.result(), Some(Wdl::Loss));
+    assert!(position.is_game_over());
+    assert!(position.legal_moves().is_empty());
+    assert_eq!(position.play("a2"), Err(MoveError::GameOver));
+}
+
+#[test]
+fn registry_errors() {
+    let registry = Registry::default();
+
+    // The squares in move errors are converted into strings.
+    let mut position = registry.start("ataxx").unwrap();
+    assert_eq!(
+        position.play("d4"),
+        Err(MoveError::Unreachable("d4".to_string()))
+    );
+
+    assert_eq!(
+        registry.start("chess").err(),
+        Some(RegistryError::UnknownGame("chess".to_string()))
+    );
+    assert!(matches!(
+        registry.parse("ataxx", "x5o/7 x").err(),
+        Some(RegistryError::BadPosition("ataxx", _))
+    ));
+}
+
+#[test]
+fn register_games() {
+    let mut registry = Registry::new();
+    assert_eq!(registry.names().count(), 0);
+
+    let game = Game::new::<TicTacToe>("tictactoe", "3/3/3 x");
+    assert!(registry.register(game).is_none());
+
+    // Registering a game under the same name replaces the old one.
+    let game = Game::new::<TicTacToe>("tictactoe", "3/1x1/3 o");
+    let old = registry.register(game).unwrap();
+    assert_eq!(old.startpos(), "3/3/3 x");
+
+    assert_eq!(registry.names().collect::<Vec<_>>(), ["tictactoe"]);
+    assert_eq!(registry.start("tictactoe").unwrap().fen(), "3/1x1/3 o");
+}
@@ -0,0 +1,80 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::interface::{BitBoardType, SquareType};
+
+/// HexSquareType is implemented by the Squares of a hexagonal grid which is
+/// laid out as a rhombus, where each Rank is shifted half a Square to the east
+/// of the Rank below it. Apart from the four neighbours provided by
+/// [`SquareType`], each Square also neighbours the Squares to its north-west
+/// and south-east.
+pub trait HexSquareType: SquareType {
+    /// Returns the square to the north-west of self. If there is no Square to
+    /// the north-west of self, it returns None.
+    #[must_use]
+    fn north_west(self) -> Option<Self> {
+        self.north()?.west()
+    }
+
+    /// Returns the square to the south-east of self. If there is no Square to
+    /// the south-east of self, it returns None.
+    #[must_use]
+    fn south_east(self) -> Option<Self> {
+        self.south()?.east()
+    }
+
+    /// Returns the (up to six) Squares which neighbour self on the hex grid.
+    #[must_use]
+    fn neighbours(self) -> impl Iterator<Item = Self> {
+        [
+            self.north(),
+            self.north_west(),
+            self.west(),
+            self.south(),
+            self.south_east(),
+            self.east(),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+/// HexBitBoardType is implemented by the BitBoards of a hexagonal grid, see
+/// [`HexSquareType`] for the layout of the grid.
+pub trait HexBitBoardType: BitBoardType {
+    /// north_west returns a new Self with all the squares shifted to the
+    /// north-west.
+    #[must_use]
+    fn north_west(self) -> Self {
+        self.north().west()
+    }
+
+    /// south_east returns a new Self with all the squares shifted to the
+    /// south-east.
+    #[must_use]
+    fn south_east(self) -> Self {
+        self.south().east()
+    }
+
+    /// neighbours returns a new Self with all the squares which neighbour any
+    /// of the squares in self on the hex grid.
+    #[must_use]
+    fn neighbours(self) -> Self {
+        self.north()
+            | self.south()
+            | self.east()
+            | self.west()
+            | self.north_west()
+            | self.south_east()
+    }
+}
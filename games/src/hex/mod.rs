@@ -0,0 +1,36 @@
+//! An implementation of hex, a connection game where two players take turns
+//! placing stones on a rhombus-shaped hex grid. Black tries to connect the
+//! first and the last Ranks of the Board with a chain of stones, while White
+//! tries to connect the first and the last Files. The swap rule is supported,
+//! see [`Move::SWAP`] for details.
+//!
+//! The [`Position`] type is generic over the BitBoard of the board it is played
+//! on. The board sizes provided out of the box live in the `board<n>x<n>`
+//! modules, and type aliases are provided for them.
+
+// Make the contents of the non-namespaced
+// modules public, so they can be accessed
+// without their parent namespace.
+pub use self::grid::*;
+pub use self::piece::*;
+pub use self::position::*;
+pub use self::r#move::*;
+
+// Namespaced modules containing the supported boards.
+pub mod board11x11;
+pub mod board7x7;
+
+// Non-namespaced modules.
+mod grid;
+mod r#move;
+mod piece;
+mod position;
+
+#[cfg(test)]
+mod tests;
+
+/// Hex7x7 is hex played on a 7x7 board.
+pub type Hex7x7 = Position<board7x7::BitBoard>;
+
+/// Hex11x11 is hex played on an 11x11 board, the most popular board size.
+pub type Hex11x11 = Position<board11x11::BitBoard>;
@@ -0,0 +1,153 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::string::ToString;
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use thiserror::Error;
+
+use crate::hex::board11x11::MOVE_LIST_CAPACITY;
+use crate::interface::{MoveList, MoveType, SquareType, TypeParseError};
+
+/// Move represents a hex move which can be played on the Board. A Move either
+/// places a stone of the side to move on its target Square, or is the swap
+/// Move. Move is generic over the Square type of the Board it is played on.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Move<S>(u16, PhantomData<S>);
+
+impl<S: SquareType> MoveType for Move<S> {
+    type Repr = u16;
+
+    const NULL: Self = Move(1 << 15, PhantomData);
+    const MAX_IN_GAME: usize = S::N + 1;
+    const MAX_IN_POSITION: usize = S::N + 1;
+
+    type List = MoveList<Self, MOVE_LIST_CAPACITY>;
+}
+
+impl<S> From<u16> for Move<S> {
+    fn from(value: u16) -> Self {
+        Move(value, PhantomData)
+    }
+}
+
+impl<S> From<Move<S>> for u16 {
+    fn from(value: Move<S>) -> Self {
+        value.0
+    }
+}
+
+impl<S: SquareType> Move<S> {
+    /// NULL Move represents an invalid move.
+    pub const NULL: Move<S> = Move(1 << 15, PhantomData);
+    /// SWAP Move represents the second player using the swap rule, where the
+    /// first stone played by Black is replaced by a White stone on the Square
+    /// mirrored along the long diagonal, after which Black is to move again.
+    pub const SWAP: Move<S> = Move(1 << 15 | 1 << 14, PhantomData);
+
+    /// new returns a new Move which places a stone on the given target Square.
+    /// The Square can be recovered with the [`Move::target`] method.
+    /// ```
+    /// use tetka_games::hex::board11x11::Square;
+    /// use tetka_games::hex::Move;
+    ///
+    /// let mov = Move::new(Square::F6);
+    ///
+    /// assert_eq!(mov.target(), Square::F6);
+    /// ```
+    #[inline(always)]
+    pub fn new(target: S) -> Move<S> {
+        Move(target.into() as u16, PhantomData)
+    }
+
+    /// Target returns the target Square of the Move, where a stone is placed.
+    /// The result of this function for [`Move::NULL`] and [`Move::SWAP`] is
+    /// undefined.
+    #[inline(always)]
+    pub fn target(self) -> S {
+        unsafe { S::unsafe_from(self.0) }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum MoveParseError {
+    #[error("bad target square string \"{0}\"")]
+    BadSquare(#[from] TypeParseError),
+}
+
+impl<S: SquareType> FromStr for Move<S> {
+    type Err = MoveParseError;
+
+    /// from_str converts the given string representation of a Move into a
+    /// [Move]. The formats supported are `swap` for a [`Move::SWAP`] and
+    /// `<target>` for a Move which places a stone on the target Square. This
+    /// function can be treated as the inverse of the [`fmt::Display`] trait for
+    /// [Move].
+    /// ```
+    /// use tetka_games::hex::board7x7::Square;
+    /// use tetka_games::hex::Move;
+    /// use core::str::FromStr;
+    ///
+    /// let swap = Move::<Square>::SWAP;
+    /// let mov = Move::new(Square::D4);
+    ///
+    /// assert_eq!(Move::from_str(&swap.to_string()).unwrap(), swap);
+    /// assert_eq!(Move::from_str(&mov.to_string()).unwrap(), mov);
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "swap" {
+            return Ok(Move::SWAP);
+        }
+
+        Ok(Move::new(S::from_str(s).map_err(|_| {
+            TypeParseError::StrError(stringify!(Square).to_string())
+        })?))
+    }
+}
+
+impl<S: SquareType> fmt::Display for Move<S> {
+    /// Display formats the given Move in a human-readable manner. The format
+    /// used for displaying moves is `<target>`, while [`Move::SWAP`] is
+    /// formatted as `swap` and [`Move::NULL`] is formatted as `null`.
+    /// ```
+    /// use tetka_games::hex::board7x7::Square;
+    /// use tetka_games::hex::Move;
+    ///
+    /// assert_eq!(Move::<Square>::NULL.to_string(), "null");
+    /// assert_eq!(Move::<Square>::SWAP.to_string(), "swap");
+    /// assert_eq!(Move::new(Square::C3).to_string(), "c3");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if *self == Move::NULL {
+            write!(f, "null")
+        } else if *self == Move::SWAP {
+            write!(f, "swap")
+        } else {
+            write!(f, "{}", self.target())
+        }
+    }
+}
+
+// Moves are serialized as their move strings.
+crate::interface::serde_string!([S: SquareType] Move<S>);
+
+impl<S: SquareType> fmt::Debug for Move<S> {
+    /// Debug formats the given Move into a human-readable debug string. It uses
+    /// `Move::Display` trait under the hood for formatting the Move.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
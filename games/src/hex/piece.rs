@@ -0,0 +1,65 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::ops;
+use core::str::FromStr;
+
+use crate::interface::representable_type;
+use crate::interface::ColoredPieceType;
+use crate::interface::RepresentableType;
+
+representable_type!(
+    /// Color represents all the possible colors that a hex stone can have,
+    /// specifically, Black and White. Black tries to connect the first and the
+    /// last Ranks, while White tries to connect the first and the last Files.
+    enum Color: u8 { Black "x", White "o", }
+);
+
+impl ops::Not for Color {
+    type Output = Color;
+
+    /// not implements the not unary operator (!) which switches the current
+    /// Color to its opposite, i.e. [`Color::Black`] to [`Color::White`] and
+    /// vice versa.
+    fn not(self) -> Self::Output {
+        unsafe { Color::unsafe_from(self as usize ^ 1) }
+    }
+}
+
+representable_type!(
+    /// Piece represents the types of pieces in hex. Since all the pieces are
+    /// identical stones, there is only a single type of Piece.
+    enum Piece: u8 { Stone "s", }
+);
+
+representable_type!(
+    /// ColoredPiece represents all the possible hex pieces.
+    enum ColoredPiece: u8 { Black "x", White "o", }
+);
+
+impl ColoredPieceType for ColoredPiece {
+    type Piece = Piece;
+    type Color = Color;
+
+    fn piece(self) -> Piece {
+        Piece::Stone
+    }
+
+    fn color(self) -> Option<Color> {
+        Some(match self {
+            ColoredPiece::Black => Color::Black,
+            ColoredPiece::White => Color::White,
+        })
+    }
+}
@@ -0,0 +1,336 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::str::FromStr;
+
+use num_traits::ToPrimitive;
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use crate::interface;
+use crate::interface::{
+    BitBoardType, Hash, MoveStore, PiecePlacementParseError, PositionType,
+    RepresentableType, SquareType, TypeParseError,
+};
+
+use crate::hex::board11x11::MOVE_LIST_CAPACITY;
+use crate::hex::{Color, ColoredPiece, HexBitBoardType, Move, Piece};
+
+type File<B> = <<B as BitBoardType>::Square as SquareType>::File;
+type Rank<B> = <<B as BitBoardType>::Square as SquareType>::Rank;
+
+/// Position represents the snapshot of a hex Board, the state of a hex game at
+/// a single point in time. The size of the Board is decided by the BitBoard
+/// type `B`, which needs to have an equal number of Files and Ranks.
+#[derive(Copy, Clone)]
+pub struct Position<B> {
+    /// bitboards stores BitBoards for the stones of each Color.
+    pub bitboards: [B; ColoredPiece::N],
+    /// checksum stores the semi-unique [struct@Hash] of the current Position.
+    pub checksum: Hash,
+    /// side_to_move stores the piece whose turn to move it currently is.
+    pub side_to_move: Color,
+}
+
+impl<B: HexBitBoardType> PositionType for Position<B> {
+    type BitBoard = B;
+    type ColoredPiece = ColoredPiece;
+    type Move = Move<B::Square>;
+
+    fn insert(&mut self, sq: B::Square, piece: ColoredPiece) {
+        self.bitboards[piece as usize].insert(sq);
+    }
+
+    fn remove(&mut self, sq: B::Square) -> Option<ColoredPiece> {
+        match self.at(sq) {
+            Some(piece) => {
+                self.bitboards[piece as usize].remove(sq);
+                Some(piece)
+            }
+            None => None,
+        }
+    }
+
+    fn at(&self, sq: B::Square) -> Option<ColoredPiece> {
+        ColoredPiece::iter()
+            .find(|piece| self.colored_piece_bb(*piece).contains(sq))
+    }
+
+    fn piece_bb(&self, piece: Piece) -> B {
+        match piece {
+            Piece::Stone => self.bitboards[0] | self.bitboards[1],
+        }
+    }
+
+    fn color_bb(&self, color: Color) -> B {
+        self.bitboards[color as usize]
+    }
+
+    fn colored_piece_bb(&self, piece: ColoredPiece) -> B {
+        self.bitboards[piece as usize]
+    }
+
+    fn hash(&self) -> Hash {
+        self.checksum
+    }
+
+    fn side_to_move(&self) -> Color {
+        self.side_to_move
+    }
+
+    fn fen(&self) -> String {
+        format!(
+            "{} {}",
+            interface::format_piece_placement(self),
+            self.side_to_move
+        )
+    }
+
+    fn winner(&self) -> Option<Color> {
+        let last_rank =
+            B::rank(unsafe { Rank::<B>::unsafe_from(Rank::<B>::N - 1) });
+        let last_file =
+            B::file(unsafe { File::<B>::unsafe_from(File::<B>::N - 1) });
+
+        let black = self.color_bb(Color::Black);
+        let white = self.color_bb(Color::White);
+
+        // Black tries to connect the first and the last Ranks, while White
+        // tries to connect the first and the last Files. Since a hex Board
+        // can't have both sides connected at once, the order of the checks
+        // doesn't matter.
+        if Self::connects(black, B::FIRST_RANK, last_rank) {
+            Some(Color::Black)
+        } else if Self::connects(white, B::FIRST_FILE, last_file) {
+            Some(Color::White)
+        } else {
+            None
+        }
+    }
+
+    fn after_move<const UPDATE_HASH: bool>(&self, m: Self::Move) -> Self {
+        let stm = self.side_to_move;
+
+        let mut bitboards = self.bitboards;
+        if m == Move::SWAP {
+            // Replace Black's stone by a White one mirrored along the long
+            // diagonal, i.e. with the File and the Rank of its Square swapped.
+            let black = bitboards[Color::Black as usize];
+            let stone = black.into_iter().next().unwrap();
+            let mirror = B::Square::new(
+                unsafe { File::<B>::unsafe_from(stone.rank().into()) },
+                unsafe { Rank::<B>::unsafe_from(stone.file().into()) },
+            );
+
+            bitboards[Color::Black as usize] = B::EMPTY;
+            bitboards[Color::White as usize] = B::from(mirror);
+        } else {
+            bitboards[stm as usize].insert(m.target());
+        }
+
+        Position {
+            bitboards,
+            checksum: if UPDATE_HASH {
+                Self::get_hash(bitboards[0], bitboards[1], !stm)
+            } else {
+                Default::default()
+            },
+            side_to_move: !stm,
+        }
+    }
+
+    fn generate_moves_into<
+        const ALLOW_ILLEGAL: bool,
+        const QUIET: bool,
+        const NOISY: bool,
+        T: MoveStore<Self::Move>,
+    >(
+        &self,
+        movelist: &mut T,
+    ) {
+        const {
+            assert!(
+                <Self::Move as interface::MoveType>::MAX_IN_POSITION
+                    <= MOVE_LIST_CAPACITY
+            )
+        };
+
+        if self.is_game_over() {
+            // Game is over, so don't generate any moves.
+            return;
+        }
+
+        for target in !self.piece_bb(Piece::Stone) {
+            movelist.push(Move::new(target));
+        }
+
+        if self.can_swap() {
+            movelist.push(Move::SWAP);
+        }
+    }
+
+    fn count_moves<const QUIET: bool, const NOISY: bool>(&self) -> usize {
+        if self.is_game_over() {
+            // Game is over, so don't generate any moves.
+            return 0;
+        }
+
+        (!self.piece_bb(Piece::Stone)).len() + self.can_swap() as usize
+    }
+}
+
+impl<B: HexBitBoardType> Position<B> {
+    /// can_swap checks if the swap Move can be played in the current Position,
+    /// which is only possible on White's first move.
+    pub fn can_swap(&self) -> bool {
+        self.side_to_move == Color::White
+            && self.color_bb(Color::Black).len() == 1
+            && self.color_bb(Color::White).is_empty()
+    }
+
+    /// connects checks if the given stones form a chain which connects the
+    /// given start and goal edges of the Board. The chain is found by
+    /// repeatedly flood-filling the neighbours of the stones reached from the
+    /// start edge.
+    fn connects(stones: B, start: B, goal: B) -> bool {
+        let mut reached = stones & start;
+        loop {
+            let next = (reached | reached.neighbours()) & stones;
+            if next == reached {
+                return !(reached & goal).is_empty();
+            }
+
+            reached = next;
+        }
+    }
+
+    fn get_hash(black: B, white: B, stm: Color) -> Hash {
+        let hash = Hash::from_bitsets(
+            black.into().to_u128().unwrap(),
+            white.into().to_u128().unwrap(),
+        );
+
+        // The Hash is bitwise complemented if the given side to move is Black.
+        // Therefore, if two Positions only differ in side to move,
+        // `a.Hash == !b.Hash`.
+        if stm == Color::Black {
+            !hash
+        } else {
+            hash
+        }
+    }
+}
+
+impl<B: HexBitBoardType> Default for Position<B> {
+    /// Returns the starting Position of the game, an empty Board with Black to
+    /// move.
+    fn default() -> Self {
+        debug_assert_eq!(File::<B>::N, Rank::<B>::N);
+        Position {
+            bitboards: [B::EMPTY; ColoredPiece::N],
+            checksum: Self::get_hash(B::EMPTY, B::EMPTY, Color::Black),
+            side_to_move: Color::Black,
+        }
+    }
+}
+
+/// PositionParseErr represents an error encountered while parsing
+/// the given FEN position field into a valid Position.
+#[derive(Error, Debug)]
+pub enum PositionParseError {
+    #[error("expected 2 fields, found {0}")]
+    WrongFieldNumber(usize),
+
+    #[error("parsing piece placement: {0}")]
+    BadPiecePlacement(#[from] PiecePlacementParseError),
+
+    #[error("parsing side to move: {0}")]
+    BadSideToMove(#[from] TypeParseError),
+}
+
+// FromStr implements parsing of the position field in a FEN. The FEN for a hex
+// game contains the piece placement and the side to move, for example
+// `7/7/7/3x3/7/7/7 o` after Black has played in the center of a 7x7 Board.
+impl<B: HexBitBoardType> FromStr for Position<B> {
+    type Err = PositionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split(' ').collect::<Vec<&str>>();
+
+        if parts.len() != 2 {
+            return Err(PositionParseError::WrongFieldNumber(parts.len()));
+        }
+
+        let pos = parts[0];
+        let stm = parts[1];
+
+        let mut position: Self = Default::default();
+
+        interface::parse_piece_placement(&mut position, pos)?;
+
+        position.side_to_move = Color::from_str(stm)?;
+
+        // Calculate the Hash value for the Position.
+        position.checksum = Self::get_hash(
+            position.colored_piece_bb(ColoredPiece::Black),
+            position.colored_piece_bb(ColoredPiece::White),
+            position.side_to_move,
+        );
+
+        Ok(position)
+    }
+}
+
+// Display implements displaying a Position using ASCII art. Each Rank is
+// shifted half a Square to the east of the one below it, so that the neighbours
+// of every Square on the hex grid are also its neighbours in the ASCII art.
+impl<B: HexBitBoardType> fmt::Display for Position<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut string_rep = String::new();
+
+        for rank in Rank::<B>::iter().rev() {
+            string_rep += &" ".repeat(rank.into() as usize + 1);
+            for file in File::<B>::iter() {
+                let square = B::Square::new(file, rank);
+                let square_str = match self.at(square) {
+                    Some(piece) => format!("{} ", piece),
+                    None => ". ".to_string(),
+                };
+                string_rep += &square_str;
+            }
+
+            // Append the rank marker.
+            string_rep += &format!(" {}\n", rank);
+        }
+
+        // Append the file markers.
+        string_rep += " ";
+        for file in File::<B>::iter() {
+            string_rep += &format!("{} ", file);
+        }
+
+        writeln!(f, "{}", string_rep.trim_end()).unwrap();
+        writeln!(f, "Side To Move: {}", self.side_to_move)
+    }
+}
+
+// Positions are serialized as their FEN strings.
+crate::interface::serde_string!(
+    [B: HexBitBoardType] Position<B>,
+    crate::interface::PositionType::fen
+);
@@ -0,0 +1,59 @@
+use crate::hex::{Color, Hex11x11, Hex7x7, Move};
+use crate::interface::PositionType;
+use crate::perft;
+use std::str::FromStr;
+
+macro_rules! perft_test {
+    ($name:ident $game:ident $pos:literal $depth:literal $nodes:literal) => {
+        #[test]
+        fn $name() {
+            let position = $game::from_str($pos).unwrap();
+            assert_eq!(perft::<true, true, _>(position, $depth), $nodes)
+        }
+    };
+}
+
+macro_rules! winner_test {
+    ($name:ident $game:ident $pos:literal $winner:expr) => {
+        #[test]
+        fn $name() {
+            let position = $game::from_str($pos).unwrap();
+            let winner: Option<Color> = $winner;
+            assert_eq!(position.winner(), winner);
+            assert_eq!(position.is_game_over(), winner.is_some());
+        }
+    };
+}
+
+perft_test!(hex7x7_01 Hex7x7 "7/7/7/7/7/7/7 x" 3 112896);
+perft_test!(hex7x7_02 Hex7x7 "7/7/7/7/7/7/1x5 o" 2 2304);
+perft_test!(hex7x7_03 Hex7x7
+    "xoxoxo1/oxox3/1oxo3/o1x4/xo5/x1o4/xo5 x" 4 491400);
+perft_test!(hex7x7_04 Hex7x7
+    "3x3/3x3/2xo3/1o1x3/3x1o1/2x4/o1x4 o" 3 50616);
+
+perft_test!(hex11x11_01 Hex11x11
+    "11/11/11/11/11/11/11/11/11/11/11 x" 2 14641);
+
+winner_test!(black_file Hex7x7
+    "3x3/3x3/3x3/3x3/3x3/3x3/3x3 o" Some(Color::Black));
+winner_test!(white_rank Hex7x7 "7/7/7/ooooooo/7/7/7 x" Some(Color::White));
+winner_test!(black_anti_diagonal Hex7x7
+    "x6/1x5/2x4/3x3/4x2/5x1/6x o" Some(Color::Black));
+winner_test!(black_diagonal Hex7x7 "6x/5x1/4x2/3x3/2x4/1x5/x6 o" None);
+winner_test!(white_bent Hex7x7 "7/7/7/2ooooo/ooo4/7/7 x" Some(Color::White));
+winner_test!(white_gap Hex7x7 "7/7/4ooo/3o3/ooo4/7/7 x" None);
+
+#[test]
+fn swap_mirrors_stone() {
+    let position = Hex7x7::from_str("7/7/7/7/7/7/1x5 o").unwrap();
+    assert!(position.can_swap());
+
+    let swapped = position.after_move::<true>(Move::SWAP);
+    let expected = Hex7x7::from_str("7/7/7/7/7/o6/7 x").unwrap();
+
+    assert_eq!(swapped.bitboards, expected.bitboards);
+    assert_eq!(swapped.side_to_move, Color::Black);
+    assert_eq!(swapped.hash(), expected.hash());
+    assert!(!swapped.can_swap());
+}
@@ -1,5 +1,5 @@
+use core::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr, Sub};
 use num_traits::int::PrimInt;
-use std::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr, Sub};
 
 use super::{RepresentableType, SquareType};
 
@@ -11,13 +11,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{fmt, ops};
+use core::{fmt, ops};
 
 /// Hash represents the semi-unique checksum of a Position used to efficiently
 /// check for Position equality. Some properties that a Hash should possess
 /// include determinism, uniform distribution, avalanche effect, and collision
 /// resistance.
 #[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Hash(u64);
 
 impl Hash {
@@ -26,10 +31,47 @@ impl Hash {
     pub fn new(raw: u64) -> Hash {
         Hash(raw)
     }
+
+    /// from_bitsets creates a new Hash from a pair of bitsets, like the
+    /// BitBoards of the two sides in a game. Bitsets of up to 128 bits are
+    /// supported, and the Hash of bitsets which fit in 64 bits is identical to
+    /// the one calculated by the ataxx Position for the same BitBoards.
+    #[must_use]
+    pub fn from_bitsets(a: u128, b: u128) -> Hash {
+        // The 2^-63-almost delta universal hash function is applied separately
+        // to the lower and the upper halves of the bitsets, which are combined
+        // afterwards. The upper halves of 64-bit bitsets hash to zero.
+        let lower = Self::hash_halves(a as u64, b as u64);
+        let upper = Self::hash_halves((a >> 64) as u64, (b >> 64) as u64);
+        Hash(lower ^ upper.rotate_left(32))
+    }
+
+    fn hash_halves(a: u64, b: u64) -> u64 {
+        // An 2^-63-almost delta universal hash function, based on
+        // https://eprint.iacr.org/2011/116.pdf by Long Hoang Nguyen and Andrew
+        // William Roscoe is used to create the Hash.
+
+        // 3 64-bit integer constants used in the hash function.
+        const X: u64 = 6364136223846793005;
+        const Y: u64 = 1442695040888963407;
+        const Z: u64 = 2305843009213693951;
+
+        // xa + yb + floor(ya/2^64) + floor(zb/2^64)
+        // floor(pq/2^64) is essentially getting the top 64 bits of p*q.
+        let part_1 = X.wrapping_mul(a); // xa
+        let part_2 = Y.wrapping_mul(b); // yb
+        let part_3 = (Y as u128 * a as u128) >> 64; // floor(ya/2^64) = ya >> 64
+        let part_4 = (Z as u128 * b as u128) >> 64; // floor(zb/2^64) = zb >> 64
+
+        // add the parts together and return the resultant hash.
+        part_1
+            .wrapping_add(part_2)
+            .wrapping_add(part_3 as u64)
+            .wrapping_add(part_4 as u64)
+    }
 }
 
 impl From<Hash> for u64 {
-    #[must_use]
     fn from(value: Hash) -> Self {
         value.0
     }
@@ -39,7 +81,6 @@ impl ops::Not for Hash {
     type Output = Self;
 
     /// Not operator (!) switches the side to move for the Hash.
-    #[must_use]
     fn not(self) -> Self::Output {
         Hash(!self.0)
     }
@@ -5,8 +5,10 @@
 //! of games. All the details regarding a game can be found as a child of the
 //! [`PositionType`] trait, see its documentation for more information.
 
-use std::fmt::{Debug, Display};
-use std::str::FromStr;
+use alloc::string::{String, ToString};
+
+use core::fmt::{Debug, Display};
+use core::str::FromStr;
 
 use strum::IntoEnumIterator;
 use thiserror::Error;
@@ -35,6 +37,7 @@ pub type Piece<P> = <ColoredPiece<P> as ColoredPieceType>::Piece;
 pub type Color<P> = <ColoredPiece<P> as ColoredPieceType>::Color;
 
 pub type Move<P> = <P as PositionType>::Move;
+pub type MoveListOf<P> = <Move<P> as MoveType>::List;
 
 /// RepresentableType is a basic trait which is implemented by enums with both a
 /// binary and string representation and backed by an integer.
@@ -59,11 +62,35 @@ pub trait RepresentableType<B: Into<usize>>:
     #[must_use]
     unsafe fn unsafe_from<T: Copy + Into<usize>>(number: T) -> Self {
         debug_assert!(number.into() < Self::N);
-        std::mem::transmute_copy(&number)
+        core::mem::transmute_copy(&number)
+    }
+}
+
+/// Wdl represents the result of a game for one of its sides, usually the side
+/// to move in a position.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Wdl {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl core::ops::Not for Wdl {
+    type Output = Wdl;
+
+    /// not implements the not unary operator (!) which returns the result of
+    /// the game for the other side.
+    fn not(self) -> Self::Output {
+        match self {
+            Wdl::Loss => Wdl::Win,
+            Wdl::Draw => Wdl::Draw,
+            Wdl::Win => Wdl::Loss,
+        }
     }
 }
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, PartialEq, Eq)]
 pub enum TypeParseError {
     #[error("invalid {0} identifier string")]
     StrError(String),
@@ -85,7 +112,6 @@ macro_rules! representable_type {
         }
 
         impl From<$type> for $base {
-            #[must_use]
             fn from(value: $type) -> Self {
                 value as $base
             }
@@ -98,7 +124,7 @@ macro_rules! representable_type {
                 if value as usize >= Self::N {
                     Err(
                         $crate::interface::TypeParseError::RangeError(
-                            stringify!($type).to_string()
+                            stringify!($type).into()
                         )
                     )
                 } else {
@@ -115,7 +141,7 @@ macro_rules! representable_type {
                     $($repr => Ok(Self::$variant),)*
                     _ => Err(
                         $crate::interface::TypeParseError::StrError(
-                            stringify!($type).to_string()
+                            stringify!($type).into()
                         )
                     ),
                 }
@@ -123,12 +149,14 @@ macro_rules! representable_type {
         }
 
         impl fmt::Display for $type {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> core::fmt::Result {
                 match *self {
                     $(Self::$variant => write!(f, "{}", $repr),)*
                 }
             }
         }
+
+        $crate::interface::serde_string!($type);
     };
 
     (@__puke_1 $t:tt) => { 1 };
@@ -142,17 +170,33 @@ pub(crate) use representable_type;
 ///
 /// # Examples
 ///
-/// ```
+/// The macro is private to the crate, so a BitBoard is declared like the one
+/// of ataxx from inside a game module:
+/// ```ignore
 /// bitboard_type! {
-///     BitBoardTypeName: u64 {
-///         Square = OurSquareType;
-///         Empty = OurEmptyBitBoard;
-///         Universe = OurUniverseBitBoard;
-///         FirstFile = OurFirstFileBitBoard;
-///         FirstRank = OurFirstRankBitBoard;
+///     struct BitBoard : u64 {
+///         Square = Square;
+///         Empty = Self(0);
+///         Universe = Self(0x1ffffffffffff);
+///         FirstFile = Self(0x0040810204081);
+///         FirstRank = Self(0x000000000007f);
 ///     }
 /// }
 /// ```
+///
+/// The generated BitBoard is a set of squares which implements BitBoardType:
+/// ```
+/// use tetka_games::ataxx::{BitBoard, Square};
+/// use tetka_games::interface::BitBoardType;
+///
+/// let bb = BitBoard::from(Square::A1) | Square::G7;
+/// assert_eq!(bb.len(), 2);
+/// assert!(bb.contains(Square::A1));
+/// assert!(!bb.contains(Square::B2));
+/// assert_eq!(bb - Square::A1, BitBoard::from(Square::G7));
+/// assert_eq!(!BitBoard::EMPTY, BitBoard::UNIVERSE);
+/// assert_eq!(bb.collect::<Vec<_>>(), vec![Square::A1, Square::G7]);
+/// ```
 macro_rules! bitboard_type {
     ($(#[doc = $doc:expr])* struct $name:tt : $typ:tt {
         Square = $sq:tt;
@@ -182,6 +226,39 @@ macro_rules! bitboard_type {
         )]
         pub struct $name(pub $typ);
 
+        // BitBoards are serialized as their base representation, and are
+        // checked to only contain Squares of the board when deserialized.
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $name {
+            fn serialize<Ser: serde::Serializer>(
+                &self,
+                serializer: Ser,
+            ) -> Result<Ser::Ok, Ser::Error> {
+                serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<De: serde::Deserializer<'de>>(
+                deserializer: De,
+            ) -> Result<Self, De::Error> {
+                let bits = <$typ as serde::Deserialize>::deserialize(
+                    deserializer,
+                )?;
+
+                let universe =
+                    <Self as crate::interface::BitBoardType>::UNIVERSE.0;
+                if bits & !universe != 0 {
+                    return Err(<De::Error as serde::de::Error>::custom(
+                        "bitboard has bits outside the board",
+                    ));
+                }
+
+                Ok(Self(bits))
+            }
+        }
+
         impl crate::interface::BitBoardType for $name {
             type Base = $typ;
             type Square = $sq;
@@ -218,41 +295,36 @@ macro_rules! bitboard_type {
             }
         }
 
-        impl std::ops::Sub<usize> for $name {
+        impl core::ops::Sub<usize> for $name {
             type Output = Self;
 
-            #[must_use]
             fn sub(self, rhs: usize) -> Self::Output {
-                Self(self.0 - rhs as u64)
+                Self(self.0 - rhs as $typ)
             }
         }
 
         impl From<$typ> for $name {
-            #[must_use]
             fn from(num: $typ) -> Self {
                 Self(num)
             }
         }
 
         impl From<$name> for $typ {
-            #[must_use]
             fn from(value: $name) -> Self {
                 value.0
             }
         }
 
         impl From<$sq> for $name {
-            #[must_use]
             fn from(square: $sq) -> Self {
                 Self(1 << square as u64)
             }
         }
 
-        impl std::ops::Not for $name {
+        impl core::ops::Not for $name {
             type Output = Self;
 
             /// Returns the complementary BitBoard of `self`.
-            #[must_use]
             fn not(self) -> Self::Output {
                 // ! will set the unused bits so remove them with an &.
                 Self(!self.0)
@@ -261,41 +333,41 @@ macro_rules! bitboard_type {
         }
 
         #[allow(clippy::suspicious_arithmetic_impl)]
-        impl std::ops::Sub for $name {
+        impl core::ops::Sub for $name {
             type Output = Self;
 
             /// Returns the difference of `self` and `rhs` as a new BitBoard.
-            #[must_use]
             fn sub(self, rhs: Self) -> Self::Output {
                 self & !rhs
             }
         }
 
         #[allow(clippy::suspicious_arithmetic_impl)]
-        impl std::ops::BitOr<$sq> for $name {
+        impl core::ops::BitOr<$sq> for $name {
             type Output = Self;
 
             /// Returns the union of `self` and `rhs` as a new BitBoard.
-            #[must_use]
             fn bitor(self, rhs: $sq) -> Self::Output {
                 self | Self::from(rhs)
             }
         }
 
-        impl std::ops::Sub<$sq> for $name {
+        impl core::ops::Sub<$sq> for $name {
             type Output = Self;
 
             /// Returns the BitBoard obtained on removing `rhs` from `self`.
-            #[must_use]
             fn sub(self, rhs: $sq) -> Self::Output {
                 self & !Self::from(rhs)
             }
         }
 
         // Display a bitboard as ASCII art with 0s and 1s.
-        impl std::fmt::Display for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                let mut string_rep = String::from("");
+        impl core::fmt::Display for $name {
+            fn fmt(
+                &self,
+                f: &mut core::fmt::Formatter<'_>,
+            ) -> core::fmt::Result {
+                let mut string_rep = alloc::string::String::new();
                 for rank in
                     <
                         <$sq as crate::interface::SquareType>::Rank
@@ -325,8 +397,11 @@ macro_rules! bitboard_type {
             }
         }
 
-        impl std::fmt::Debug for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl core::fmt::Debug for $name {
+            fn fmt(
+                &self,
+                f: &mut core::fmt::Formatter<'_>,
+            ) -> core::fmt::Result {
                 write!(f, "{}", self)
             }
         }
@@ -335,96 +410,236 @@ macro_rules! bitboard_type {
 
 pub(crate) use bitboard_type;
 
-/// PositionParseErr represents an error encountered while parsing
-/// the given FEN position field into a valid Position.
-#[derive(Error, Debug)]
+/// serde_string implements Serialize and Deserialize for the given type, when
+/// the `serde` feature is enabled, using its string representation. The type
+/// is serialized with the given function, which defaults to `to_string`, and
+/// is deserialized with its FromStr implementation. The generic parameters of
+/// the type, if any, are provided in square brackets before it, and must not
+/// be named `Ser` or `De`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// serde_string!(Move);
+/// serde_string!([B: BitBoardType] Position<B>, PositionType::fen);
+/// ```
+macro_rules! serde_string {
+    (@impl [$($generics:tt)*] $type:ty, $to_string:path) => {
+        #[cfg(feature = "serde")]
+        impl<$($generics)*> serde::Serialize for $type {
+            fn serialize<Ser: serde::Serializer>(
+                &self,
+                serializer: Ser,
+            ) -> Result<Ser::Ok, Ser::Error> {
+                serializer.serialize_str(&$to_string(self))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, $($generics)*> serde::Deserialize<'de> for $type {
+            fn deserialize<De: serde::Deserializer<'de>>(
+                deserializer: De,
+            ) -> Result<Self, De::Error> {
+                let string =
+                    <alloc::string::String as serde::Deserialize>::deserialize(
+                        deserializer,
+                    )?;
+                core::str::FromStr::from_str(&string)
+                    .map_err(<De::Error as serde::de::Error>::custom)
+            }
+        }
+    };
+
+    ([$($generics:tt)*] $type:ty) => {
+        $crate::interface::serde_string!(
+            @impl [$($generics)*] $type, alloc::string::ToString::to_string
+        );
+    };
+
+    ([$($generics:tt)*] $type:ty, $to_string:path) => {
+        $crate::interface::serde_string!(
+            @impl [$($generics)*] $type, $to_string
+        );
+    };
+
+    ($type:ty $(, $to_string:path)?) => {
+        $crate::interface::serde_string!([] $type $(, $to_string)?);
+    };
+}
+
+pub(crate) use serde_string;
+
+/// PiecePlacementParseError represents an error encountered while parsing the
+/// piece placement field of a FEN. Every error also stores the 1-based column
+/// of the field at which the parsing failed, see [`Self::column`].
+#[derive(Error, Debug, PartialEq, Eq)]
 pub enum PiecePlacementParseError {
     #[error("a jump value was too long and overshot")]
-    JumpTooLong,
+    JumpTooLong(usize),
 
     #[error("invalid piece identifier '{0}'")]
-    InvalidPieceIdent(char),
+    InvalidPieceIdent(char, usize),
     #[error("insufficient data to fill the entire {0} file")]
-    FileDataIncomplete(String),
+    FileDataIncomplete(String, usize),
     #[error("expected {0} ranks, found more")]
-    TooManyRanks(usize),
+    TooManyRanks(usize, usize),
+    #[error("expected {0} ranks, found less")]
+    TooFewRanks(usize, usize),
+}
+
+impl PiecePlacementParseError {
+    /// Returns the 1-based column of the piece placement field at which the
+    /// error was encountered.
+    pub fn column(&self) -> usize {
+        match self {
+            Self::JumpTooLong(column)
+            | Self::InvalidPieceIdent(_, column)
+            | Self::FileDataIncomplete(_, column)
+            | Self::TooManyRanks(_, column)
+            | Self::TooFewRanks(_, column) => *column,
+        }
+    }
 }
 
 pub(crate) fn parse_piece_placement<T: PositionType>(
     position: &mut T,
     fen_fragment: &str,
 ) -> Result<(), PiecePlacementParseError> {
-    for sq in <<<T as PositionType>::BitBoard as BitBoardType>::Square
-        as IntoEnumIterator>::iter()
-    {
+    for sq in <Square<T> as IntoEnumIterator>::iter() {
         position.remove(sq);
     }
 
-    // Spilt the position spec by the Ranks which are separated by '/'.
-    let ranks: Vec<&str> = fen_fragment.split('/').collect();
-
     let first_file = File::<T>::iter().next().unwrap();
 
     let mut file = Ok(first_file);
     let mut rank = Ok(Rank::<T>::iter().last().unwrap());
 
-    // Iterate over the Ranks in the string spec.
-    for rank_data in ranks {
-        // Rank pointer ran out, but data carried on.
-        if rank.is_err() {
-            return Err(PiecePlacementParseError::TooManyRanks(
-                <<<T as PositionType>::BitBoard as BitBoardType>::Square
-                    as SquareType>::Rank::iter().len()
-            ));
-        }
+    // Iterate over the Square specs, keeping track of their columns. Ranks
+    // are separated from each other by a '/'.
+    let mut specs = fen_fragment.chars().zip(1..).peekable();
+    while let Some((data, column)) = specs.next() {
+        if data == '/' {
+            // After rank data runs out, file pointer should be
+            // at the last file, i.e, rank is completely filled.
+            if let Ok(file) = file {
+                return Err(PiecePlacementParseError::FileDataIncomplete(
+                    file.to_string(),
+                    column,
+                ));
+            }
+
+            // Switch rank pointer and reset file pointer.
+            rank = <Rank<T>>::try_from((rank.unwrap().into()).wrapping_sub(1));
+            file = Ok(first_file);
 
-        // Iterate over the Square specs in the Rank spec.
-        for data in rank_data.chars() {
-            // Check if a jump was too big and we landed on an invalid File.
-            if file.is_err() {
-                return Err(PiecePlacementParseError::JumpTooLong);
+            // Rank pointer ran out, but data carried on.
+            if rank.is_err() {
+                return Err(PiecePlacementParseError::TooManyRanks(
+                    Rank::<T>::N,
+                    column,
+                ));
             }
 
-            let file_value = *file.as_ref().unwrap();
-            let rank_value = *rank.as_ref().unwrap();
-            let square = <Square<T>>::new(file_value, rank_value);
-            match data {
-                // Numbers represent jump specs to jump over empty squares.
-                '1'..='8' => {
-                    file = <File<T>>::try_from(
-                        file_value.into() + data as u8 - b'1',
-                    );
-                    if file.is_err() {
-                        return Err(PiecePlacementParseError::JumpTooLong);
+            continue;
+        }
+
+        // Check if a jump was too big and we landed on an invalid File.
+        if file.is_err() {
+            return Err(PiecePlacementParseError::JumpTooLong(column));
+        }
+
+        let file_value = *file.as_ref().unwrap();
+        let rank_value = *rank.as_ref().unwrap();
+        let square = <Square<T>>::new(file_value, rank_value);
+        match data {
+            // Numbers represent jump specs to jump over empty squares. Jumps
+            // may span multiple digits on boards with more than 9 files.
+            '1'..='9' => {
+                // A jump is too long as soon as it spans more than a rank,
+                // which also keeps long digit runs from overflowing it.
+                let mut jump = data as usize - '0' as usize;
+                while let Some(digit) =
+                    specs.peek().and_then(|(d, _)| d.to_digit(10))
+                {
+                    jump = jump * 10 + digit as usize;
+                    if jump > File::<T>::N {
+                        return Err(PiecePlacementParseError::JumpTooLong(
+                            column,
+                        ));
                     }
+
+                    specs.next();
                 }
 
-                _ => match <ColoredPiece<T>>::from_str(&data.to_string()) {
-                    Ok(piece) => position.insert(square, piece),
-                    Err(_) => {
-                        return Err(
-                            PiecePlacementParseError::InvalidPieceIdent(data),
-                        )
-                    }
-                },
+                let target = file_value.into() as usize + jump - 1;
+                if target >= File::<T>::N {
+                    return Err(PiecePlacementParseError::JumpTooLong(column));
+                }
+
+                file = <File<T>>::try_from(target as u8);
             }
 
-            // On to the next Square spec in the Rank spec.
-            file = <File<T>>::try_from(file.unwrap().into() + 1);
+            _ => match <ColoredPiece<T>>::from_str(&data.to_string()) {
+                Ok(piece) => position.insert(square, piece),
+                Err(_) => {
+                    return Err(PiecePlacementParseError::InvalidPieceIdent(
+                        data, column,
+                    ))
+                }
+            },
         }
 
-        // After rank data runs out, file pointer should be
-        // at the last file, i.e, rank is completely filled.
-        if let Ok(file) = file {
-            return Err(PiecePlacementParseError::FileDataIncomplete(
-                file.to_string(),
-            ));
-        }
+        // On to the next Square spec in the Rank spec.
+        file = <File<T>>::try_from(file.unwrap().into() + 1);
+    }
+
+    let column = fen_fragment.chars().count() + 1;
+    if let Ok(file) = file {
+        return Err(PiecePlacementParseError::FileDataIncomplete(
+            file.to_string(),
+            column,
+        ));
+    }
 
-        // Switch rank pointer and reset file pointer.
-        rank = <Rank<T>>::try_from((rank.unwrap().into()).wrapping_sub(1));
-        file = Ok(first_file);
+    // All the Ranks should have been filled by the data.
+    if rank.unwrap().into() != 0 {
+        return Err(PiecePlacementParseError::TooFewRanks(
+            Rank::<T>::N,
+            column,
+        ));
     }
 
     Ok(())
 }
+
+/// Formats the piece placement field of a FEN for the given position. This is
+/// the inverse of parsing the field: Ranks are separated by '/', and runs of
+/// empty Squares are represented by the number of Squares in the run.
+pub(crate) fn format_piece_placement<T: PositionType>(position: &T) -> String {
+    let mut fen = String::new();
+
+    for rank in Rank::<T>::iter().rev() {
+        let mut empty = 0;
+        for file in File::<T>::iter() {
+            match position.at(<Square<T>>::new(file, rank)) {
+                Some(piece) => {
+                    if empty > 0 {
+                        fen += &empty.to_string();
+                        empty = 0;
+                    }
+                    fen += &piece.to_string();
+                }
+                None => empty += 1,
+            }
+        }
+
+        if empty > 0 {
+            fen += &empty.to_string();
+        }
+        fen.push('/');
+    }
+
+    // Remove the separator after the last Rank.
+    fen.pop();
+    fen
+}
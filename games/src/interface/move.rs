@@ -1,10 +1,33 @@
-use std::fmt::Display;
-use std::str::FromStr;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::ops::{Deref, DerefMut};
+use core::str::FromStr;
 
 use arrayvec::ArrayVec;
+use num_traits::int::PrimInt;
+use thiserror::Error;
 
 /// The Move trait should be implemented the move representation of a game.
-pub trait MoveType: FromStr + Display + From<u16> + Into<u16> + Copy {
+///
+/// A Move can be converted to and from its binary representation, which is of
+/// the type [`MoveType::Repr`]. Most games can encode their moves in a [`u16`],
+/// while games which need to store more information in a Move, like the path
+/// of a multi-jump capture, can use a wider integer type.
+pub trait MoveType:
+    FromStr<Err: Display>
+    + Display
+    + From<Self::Repr>
+    + Into<Self::Repr>
+    + Copy
+    + Eq
+where
+    Self::Repr: PrimInt,
+{
+    /// The backing [`PrimInt`] type for the binary representation of a Move.
+    type Repr;
+
     /// NULL represents the null or the 'do nothing' move.
     const NULL: Self;
     /// MAX_IN_GAME is a suitably high maximum for the number of move in a game.
@@ -12,6 +35,37 @@ pub trait MoveType: FromStr + Display + From<u16> + Into<u16> + Copy {
     /// MAX_IN_POSITION is a suitably high maximum for the number of move in a
     /// single, possibly unreachable position.
     const MAX_IN_POSITION: usize;
+
+    /// List is the [MoveStore] used to store the moves of a position, like by
+    /// [`generate_moves`](super::PositionType::generate_moves). It should be
+    /// able to store [`MoveType::MAX_IN_POSITION`] moves, which is usually
+    /// done with a [MoveList] of that capacity, or with a [Vec] if the moves
+    /// don't comfortably fit on the stack.
+    type List: MoveStore<Self> + Deref<Target = [Self]> + DerefMut;
+}
+
+/// MoveError represents an error encountered while checking a move against a
+/// position, like in [`parse_move`](super::PositionType::parse_move). The type
+/// `S` is the Square type of the position.
+#[derive(Error, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MoveError<S> {
+    #[error("bad move string: {0}")]
+    BadSyntax(String),
+    #[error("the game is already over")]
+    GameOver,
+    #[error("no piece of the side to move on source square {0}")]
+    NoPieceOnSource(S),
+    #[error("target square {0} is occupied")]
+    OccupiedTarget(S),
+    #[error("target square {1} is too far from source square {0}")]
+    TooFar(S, S),
+    #[error("no piece of the side to move can reach target square {0}")]
+    Unreachable(S),
+    #[error("can't pass when moves are available")]
+    PassWithMovesAvailable,
+    #[error("illegal move {0}")]
+    Illegal(String),
 }
 
 /// MoveStore is a trait implemented by types which are able to store moves
@@ -38,24 +92,54 @@ pub trait MoveStore<M>: Default {
 /// to utilize move-generation methods without having to implement a [MoveStore] by
 /// themselves. It also has utility methods other than the [`MoveStore`] trait.
 ///
-/// MoveList is allocated on the stack and very fast for use. However, due to
-/// current limitations in the Rust type system, the current max capacity is
-/// capped at 256, which can be problematic for games which can have more moves
-/// in a position and might require a custom type.
-pub type MoveList<M> = ArrayVec<M, 256>;
+/// MoveList is allocated on the stack and very fast for use. It can store at
+/// most `N` moves, which should be the [`MoveType::MAX_IN_POSITION`] of the
+/// game it is used for. Games which can have too many moves in a position to
+/// store them on the stack should use a heap-allocated [Vec] instead.
+///
+/// The capacity of a game's MoveList can't depend on its Square type on stable
+/// Rust, so games generic over their Board size it for their largest Board,
+/// and check at compile time that the Board they are played on fits in it.
+/// ```
+/// use tetka_games::interface::{MoveList, MoveStore};
+///
+/// let mut moves: MoveList<u16, 2> = MoveList::new();
+///
+/// MoveStore::push(&mut moves, 1);
+/// MoveStore::push(&mut moves, 2);
+///
+/// assert!(moves.is_full());
+/// assert_eq!(&moves[..], &[1, 2]);
+/// ```
+pub type MoveList<M, const N: usize = 256> = ArrayVec<M, N>;
 
 // MoveStore implementation for MoveList.
-impl<M> MoveStore<M> for MoveList<M> {
+impl<M, const N: usize> MoveStore<M> for MoveList<M, N> {
+    fn push(&mut self, m: M) {
+        self.push(m);
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+// MoveStore implementation for Vec, which can be used to store any number of
+// moves on the heap. It is the fallback for games whose moves don't fit on the
+// stack, or whose maximum number of moves in a position can't be known.
+impl<M> MoveStore<M> for Vec<M> {
     fn push(&mut self, m: M) {
         self.push(m);
     }
 
-    #[must_use]
     fn len(&self) -> usize {
         self.len()
     }
 
-    #[must_use]
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
@@ -1,4 +1,4 @@
-use std::ops::Not;
+use core::ops::Not;
 
 use super::RepresentableType;
 
@@ -15,6 +15,10 @@ where
     type Color;
 
     /// Creates a new ColoredPiece from the given Piece and Color.
+    ///
+    /// By default, the ColoredPieces are assumed to be laid out as every
+    /// Piece of the first Color followed by every Piece of the next Color and
+    /// so on. Games with colorless pieces need to override this function.
     #[must_use]
     fn new(piece: Self::Piece, color: Self::Color) -> Self {
         unsafe {
@@ -27,7 +31,8 @@ where
     /// Returns the Piece of the given ColoredPiece.
     #[must_use]
     fn piece(self) -> Self::Piece;
-    /// Returns the Color of the given ColoredPiece.
+    /// Returns the Color of the given ColoredPiece, or `None` if the piece
+    /// doesn't belong to either side, like the blockers of some games.
     #[must_use]
-    fn color(self) -> Self::Color;
+    fn color(self) -> Option<Self::Color>;
 }
@@ -1,9 +1,11 @@
-use std::fmt::Display;
-use std::str::FromStr;
+use alloc::string::{String, ToString};
+
+use core::fmt::Display;
+use core::str::FromStr;
 
 use super::{
-    BitBoardType, Color, ColoredPieceType, Hash, MoveList, MoveStore, MoveType,
-    Piece, Square,
+    BitBoardType, Color, ColoredPieceType, Hash, MoveError, MoveListOf,
+    MoveStore, MoveType, Piece, Square, Wdl,
 };
 
 /// Position is a generalized interface for board representations of a wide
@@ -53,11 +55,33 @@ where
     #[must_use]
     fn hash(&self) -> Hash;
 
+    /// Returns the Color of the side whose turn it is to move.
+    #[must_use]
+    fn side_to_move(&self) -> Color<Self>;
+
+    /// Returns the FEN of the current Position, which can be parsed back into
+    /// an identical Position with [`FromStr`].
+    #[must_use]
+    fn fen(&self) -> String;
+
     // Game Result functions.
 
     /// Returns the side which has won in the current position, if any.
     #[must_use]
     fn winner(&self) -> Option<Color<Self>>;
+    /// Returns the result of the game for the side to move, if it is over.
+    #[must_use]
+    fn result(&self) -> Option<Wdl> {
+        if !self.is_game_over() {
+            return None;
+        }
+
+        Some(match self.winner() {
+            Some(color) if color == self.side_to_move() => Wdl::Win,
+            Some(_) => Wdl::Loss,
+            None => Wdl::Draw,
+        })
+    }
     /// Returns `true` if the game is over in the current position.
     #[must_use]
     fn is_game_over(&self) -> bool {
@@ -76,6 +100,48 @@ where
         mov: Self::Move,
     ) -> Self;
 
+    // Move legality checking functions for the board representation.
+
+    /// Returns `true` if the given move is legal in the current position.
+    ///
+    /// By default this checks if the move is generated in the position, but
+    /// implementations may check the move directly, which is a lot faster.
+    #[must_use]
+    fn is_legal(&self, mov: Self::Move) -> bool {
+        self.generate_moves::<false, true, true>().contains(&mov)
+    }
+
+    /// Checks if the given move is legal in the current position, and returns
+    /// the reason it is illegal otherwise.
+    ///
+    /// By default, only [`MoveError::GameOver`] and the generic
+    /// [`MoveError::Illegal`] are reported, while implementations may detect
+    /// the more specific reasons.
+    fn check_move(
+        &self,
+        mov: Self::Move,
+    ) -> Result<(), MoveError<Square<Self>>> {
+        if self.is_game_over() {
+            Err(MoveError::GameOver)
+        } else if self.is_legal(mov) {
+            Ok(())
+        } else {
+            Err(MoveError::Illegal(mov.to_string()))
+        }
+    }
+
+    /// Parses the given move string and checks it against the current position,
+    /// so that the returned move is always legal to play with `after_move`.
+    fn parse_move(
+        &self,
+        mov: &str,
+    ) -> Result<Self::Move, MoveError<Square<Self>>> {
+        let mov = Self::Move::from_str(mov)
+            .map_err(|err| MoveError::BadSyntax(err.to_string()))?;
+        self.check_move(mov)?;
+        Ok(mov)
+    }
+
     // Move Generation functions for the board representation.
 
     /// Generates all the moves in the current position and add them into the
@@ -96,7 +162,8 @@ where
         movelist: &mut T,
     );
     /// `generate_moves` is similar to `generate_moves_into`, except that
-    /// instead of taking some storage as input it stores into a [MoveList].
+    /// instead of taking some storage as input it stores into the game's
+    /// [`MoveType::List`], which is usually a [MoveList](super::MoveList).
     #[must_use]
     fn generate_moves<
         const ALLOW_ILLEGAL: bool,
@@ -104,8 +171,8 @@ where
         const NOISY: bool,
     >(
         &self,
-    ) -> MoveList<Self::Move> {
-        let mut movelist: MoveList<Self::Move> = Default::default();
+    ) -> MoveListOf<Self> {
+        let mut movelist: MoveListOf<Self> = Default::default();
         self.generate_moves_into::<ALLOW_ILLEGAL, QUIET, NOISY, _>(
             &mut movelist,
         );
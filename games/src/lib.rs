@@ -1,5 +1,30 @@
+//! Rust implementations of various games, with a common interface to their
+//! positions and move generation.
+//!
+//! The crate only needs `core` and `alloc` with its default `std` feature
+//! disabled, in which case the modules which need the standard library, like
+//! [`selfplay`], are unavailable.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod amazons;
 pub mod ataxx;
+pub mod breakthrough;
+#[cfg(feature = "std")]
+pub mod data;
+pub mod draughts;
+pub mod dynamic;
+pub mod hex;
 pub mod interface;
+pub mod mnk;
+pub mod ordering;
+pub mod pgn;
+pub mod random;
+pub mod render;
+#[cfg(feature = "std")]
+pub mod selfplay;
 
 use interface::PositionType;
 
@@ -7,7 +32,8 @@ use interface::PositionType;
 /// to count all the leaf nodes of a certain depth.
 ///
 /// If `SPLIT` is set to `true`, the perft value contributed by each legal move
-/// in the current position is displayed separately. If `BULK` is set to `true`,
+/// in the current position is displayed separately, which needs the `std`
+/// feature. If `BULK` is set to `true`,
 /// a trick known as bulk-counting is used, which makes it significantly faster.
 ///
 /// In perft, nodes are only counted at the end after the last make-move. Thus
@@ -47,6 +73,7 @@ pub fn perft<const SPLIT: bool, const BULK: bool, T: PositionType>(
         let new_nodes = perft::<false, BULK, T>(new_position, depth - 1);
 
         // If spilt perft is enabled, print the nodes added due to this move.
+        #[cfg(feature = "std")]
         if SPLIT {
             println!("{}: {}", m, new_nodes);
         }
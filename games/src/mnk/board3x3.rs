@@ -0,0 +1,62 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::interface::{
+    bitboard_type, representable_type, BitBoardType, RepresentableType,
+    SquareType,
+};
+
+representable_type!(
+    /// Square represents all the squares present on a 3x3 board.
+    /// The index of each Square is equal to `rank-index * 3 + file-index`.
+    enum Square: u8 {
+        A1 "a1", B1 "b1", C1 "c1",
+        A2 "a2", B2 "b2", C2 "c2",
+        A3 "a3", B3 "b3", C3 "c3",
+    }
+);
+
+impl SquareType for Square {
+    type File = File;
+    type Rank = Rank;
+}
+
+representable_type!(
+    /// File represents a file on a 3x3 board. There are 3 of them in total.
+    enum File: u8 { A "a", B "b", C "c", }
+);
+
+representable_type!(
+    /// Rank represents a rank on a 3x3 board. There are 3 of them in total.
+    enum Rank: u8 { First "1", Second "2", Third "3", }
+);
+
+bitboard_type! {
+    /// A set of Squares implemented as a bitset where the `1 << sq.into()` bit
+    /// represents whether `sq` is in the BitBoard or not.
+    struct BitBoard : u16 {
+        // The BitBoard's Square type.
+        Square = Square;
+
+        // BitBoards representing the null and the universe sets.
+        Empty = Self(0);
+        Universe = Self(0x1ff);
+
+        // BitBoards containing the squares of the first file and the first rank.
+        FirstFile = Self(0x049);
+        FirstRank = Self(0x007);
+    }
+}
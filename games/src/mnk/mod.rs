@@ -0,0 +1,39 @@
+//! Implementations of m,n,k-games, where two players take turns placing stones
+//! on an m×n board, and the first player to get k stones in a row horizontally,
+//! vertically, or diagonally wins. Some m,n,k-games also have gravity, where a
+//! stone can only be placed on the lowest empty square of a file.
+//!
+//! The [`Position`] type is generic over the BitBoard of the board it is played
+//! on, the number of stones in a row `K` needed to win, and gravity. The board
+//! sizes provided out of the box live in the `board<m>x<n>` modules, and type
+//! aliases are provided for some popular m,n,k-games.
+
+// Make the contents of the non-namespaced
+// modules public, so they can be accessed
+// without their parent namespace.
+pub use self::piece::*;
+pub use self::position::*;
+pub use self::r#move::*;
+
+// Namespaced modules containing the supported boards.
+pub mod board11x11;
+pub mod board3x3;
+pub mod board7x6;
+
+// Non-namespaced modules.
+mod r#move;
+mod piece;
+mod position;
+
+#[cfg(test)]
+mod tests;
+
+/// TicTacToe is the 3,3,3-game played on a 3x3 board without gravity.
+pub type TicTacToe = Position<board3x3::BitBoard, 3, false>;
+
+/// ConnectFour is the 7,6,4-game played on a 7x6 board with gravity.
+pub type ConnectFour = Position<board7x6::BitBoard, 4, true>;
+
+/// Gomoku is the 11,11,5-game played on an 11x11 board without gravity. Boards
+/// are limited to 128 squares, so the traditional 15x15 board is unsupported.
+pub type Gomoku = Position<board11x11::BitBoard, 5, false>;
@@ -0,0 +1,63 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use core::ops;
+use core::str::FromStr;
+
+use crate::interface::representable_type;
+use crate::interface::ColoredPieceType;
+use crate::interface::RepresentableType;
+
+representable_type!(
+    /// Color represents all the possible colors that an m,n,k-game stone can
+    /// have, specifically, X (the first player) and O (the second player).
+    enum Color: u8 { X "x", O "o", }
+);
+
+impl ops::Not for Color {
+    type Output = Color;
+
+    /// not implements the not unary operator (!) which switches the current Color
+    /// to its opposite, i.e. [`Color::X`] to [`Color::O`] and vice versa.
+    fn not(self) -> Self::Output {
+        unsafe { Color::unsafe_from(self as usize ^ 1) }
+    }
+}
+
+representable_type!(
+    /// Piece represents the types of pieces in an m,n,k-game. Since all the
+    /// pieces are identical stones, there is only a single type of Piece.
+    enum Piece: u8 { Stone "s", }
+);
+
+representable_type!(
+    /// ColoredPiece represents all the possible m,n,k-game pieces.
+    enum ColoredPiece: u8 { X "x", O "o", }
+);
+
+impl ColoredPieceType for ColoredPiece {
+    type Piece = Piece;
+    type Color = Color;
+
+    fn piece(self) -> Piece {
+        Piece::Stone
+    }
+
+    fn color(self) -> Option<Color> {
+        Some(match self {
+            ColoredPiece::X => Color::X,
+            ColoredPiece::O => Color::O,
+        })
+    }
+}
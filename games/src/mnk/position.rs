@@ -0,0 +1,339 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::str::FromStr;
+
+use num_traits::ToPrimitive;
+use strum::IntoEnumIterator;
+use thiserror::Error;
+
+use crate::interface;
+use crate::interface::{
+    BitBoardType, Hash, MoveStore, PiecePlacementParseError, PositionType,
+    RepresentableType, SquareType, TypeParseError,
+};
+
+use crate::mnk::board11x11::MOVE_LIST_CAPACITY;
+use crate::mnk::{Color, ColoredPiece, Move, Piece};
+
+/// Position represents the snapshot of an m,n,k-game Board, the state of the
+/// game at a single point in time. The size of the Board is decided by the
+/// BitBoard type `B`, while the number of stones in a row needed to win and
+/// whether the stones fall to the lowest empty Square of a File (gravity) are
+/// decided by `K` and `GRAVITY` respectively.
+#[derive(Copy, Clone)]
+pub struct Position<B, const K: usize, const GRAVITY: bool> {
+    /// bitboards stores BitBoards for the stones of each Color.
+    pub bitboards: [B; ColoredPiece::N],
+    /// checksum stores the semi-unique [struct@Hash] of the current Position.
+    pub checksum: Hash,
+    /// side_to_move stores the piece whose turn to move it currently is.
+    pub side_to_move: Color,
+}
+
+impl<B: BitBoardType, const K: usize, const GRAVITY: bool> PositionType
+    for Position<B, K, GRAVITY>
+{
+    type BitBoard = B;
+    type ColoredPiece = ColoredPiece;
+    type Move = Move<B::Square>;
+
+    fn insert(&mut self, sq: B::Square, piece: ColoredPiece) {
+        self.bitboards[piece as usize].insert(sq);
+    }
+
+    fn remove(&mut self, sq: B::Square) -> Option<ColoredPiece> {
+        match self.at(sq) {
+            Some(piece) => {
+                self.bitboards[piece as usize].remove(sq);
+                Some(piece)
+            }
+            None => None,
+        }
+    }
+
+    fn at(&self, sq: B::Square) -> Option<ColoredPiece> {
+        ColoredPiece::iter()
+            .find(|piece| self.colored_piece_bb(*piece).contains(sq))
+    }
+
+    fn piece_bb(&self, piece: Piece) -> B {
+        match piece {
+            Piece::Stone => self.bitboards[0] | self.bitboards[1],
+        }
+    }
+
+    fn color_bb(&self, color: Color) -> B {
+        self.bitboards[color as usize]
+    }
+
+    fn colored_piece_bb(&self, piece: ColoredPiece) -> B {
+        self.bitboards[piece as usize]
+    }
+
+    fn hash(&self) -> Hash {
+        self.checksum
+    }
+
+    fn side_to_move(&self) -> Color {
+        self.side_to_move
+    }
+
+    fn fen(&self) -> String {
+        format!(
+            "{} {}",
+            interface::format_piece_placement(self),
+            self.side_to_move
+        )
+    }
+
+    fn is_game_over(&self) -> bool {
+        self.winner().is_some() ||                       // K stones in a row
+            self.piece_bb(Piece::Stone) == B::UNIVERSE // All squares occupied
+    }
+
+    fn winner(&self) -> Option<Color> {
+        // Only the side which made the last move can have completed a line in a
+        // reachable Position, so check it before the side to move.
+        [!self.side_to_move, self.side_to_move]
+            .into_iter()
+            .find(|&color| Self::has_line(self.color_bb(color)))
+    }
+
+    fn after_move<const UPDATE_HASH: bool>(&self, m: Self::Move) -> Self {
+        let stm = self.side_to_move;
+
+        let mut bitboards = self.bitboards;
+        bitboards[stm as usize].insert(m.target());
+
+        Position {
+            bitboards,
+            checksum: if UPDATE_HASH {
+                Self::get_hash(bitboards[0], bitboards[1], !stm)
+            } else {
+                Default::default()
+            },
+            side_to_move: !stm,
+        }
+    }
+
+    fn generate_moves_into<
+        const ALLOW_ILLEGAL: bool,
+        const QUIET: bool,
+        const NOISY: bool,
+        T: MoveStore<Self::Move>,
+    >(
+        &self,
+        movelist: &mut T,
+    ) {
+        const {
+            assert!(
+                <Self::Move as interface::MoveType>::MAX_IN_POSITION
+                    <= MOVE_LIST_CAPACITY
+            )
+        };
+
+        if self.is_game_over() {
+            // Game is over, so don't generate any moves.
+            return;
+        }
+
+        for target in self.targets() {
+            movelist.push(Move::new(target));
+        }
+    }
+
+    fn count_moves<const QUIET: bool, const NOISY: bool>(&self) -> usize {
+        if self.is_game_over() {
+            // Game is over, so don't generate any moves.
+            return 0;
+        }
+
+        self.targets().len()
+    }
+}
+
+impl<B: BitBoardType, const K: usize, const GRAVITY: bool>
+    Position<B, K, GRAVITY>
+{
+    /// targets returns the Squares where the side to move can place a stone.
+    /// With gravity, only the lowest empty Square of each File is available.
+    fn targets(&self) -> B {
+        let occupied = self.piece_bb(Piece::Stone);
+        if GRAVITY {
+            !occupied & (occupied.north() | B::FIRST_RANK)
+        } else {
+            !occupied
+        }
+    }
+
+    /// has_line checks if the given stones contain K stones in a row, either
+    /// horizontally, vertically, or along one of the two diagonals.
+    fn has_line(stones: B) -> bool {
+        Self::has_line_towards(stones, |bb| bb.east())
+            || Self::has_line_towards(stones, |bb| bb.north())
+            || Self::has_line_towards(stones, |bb| bb.north().east())
+            || Self::has_line_towards(stones, |bb| bb.north().west())
+    }
+
+    /// has_line_towards checks if the given stones contain K stones in a row
+    /// along the direction of the given shift.
+    fn has_line_towards<F: Fn(B) -> B>(stones: B, shift: F) -> bool {
+        // After i iterations, run contains all the Squares which end a run of
+        // i + 1 consecutive stones along the direction of the shift.
+        let mut run = stones;
+        for _ in 1..K {
+            run = shift(run) & stones;
+        }
+
+        !run.is_empty()
+    }
+
+    fn get_hash(x: B, o: B, stm: Color) -> Hash {
+        let hash = Hash::from_bitsets(
+            x.into().to_u128().unwrap(),
+            o.into().to_u128().unwrap(),
+        );
+
+        // The Hash is bitwise complemented if the given side to move is X.
+        // Therefore, if two Positions only differ in side to move,
+        // `a.Hash == !b.Hash`.
+        if stm == Color::X {
+            !hash
+        } else {
+            hash
+        }
+    }
+}
+
+impl<B: BitBoardType, const K: usize, const GRAVITY: bool> Default
+    for Position<B, K, GRAVITY>
+{
+    /// Returns the starting Position of the game, an empty Board with X to move.
+    fn default() -> Self {
+        Position {
+            bitboards: [B::EMPTY; ColoredPiece::N],
+            checksum: Self::get_hash(B::EMPTY, B::EMPTY, Color::X),
+            side_to_move: Color::X,
+        }
+    }
+}
+
+/// PositionParseErr represents an error encountered while parsing
+/// the given FEN position field into a valid Position.
+#[derive(Error, Debug)]
+pub enum PositionParseError {
+    #[error("expected 2 fields, found {0}")]
+    WrongFieldNumber(usize),
+
+    #[error("parsing piece placement: {0}")]
+    BadPiecePlacement(#[from] PiecePlacementParseError),
+    #[error("stone on {0} is not supported by the square below it")]
+    FloatingStone(String),
+
+    #[error("parsing side to move: {0}")]
+    BadSideToMove(#[from] TypeParseError),
+}
+
+// FromStr implements parsing of the position field in a FEN. The FEN for an
+// m,n,k-game contains the piece placement and the side to move, for example
+// `x2/1o1/3 x` for a game of Tic-Tac-Toe.
+impl<B: BitBoardType, const K: usize, const GRAVITY: bool> FromStr
+    for Position<B, K, GRAVITY>
+{
+    type Err = PositionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split(' ').collect::<Vec<&str>>();
+
+        if parts.len() != 2 {
+            return Err(PositionParseError::WrongFieldNumber(parts.len()));
+        }
+
+        let pos = parts[0];
+        let stm = parts[1];
+
+        let mut position: Self = Default::default();
+
+        interface::parse_piece_placement(&mut position, pos)?;
+
+        // With gravity, every stone needs to be supported by another stone
+        // directly below it or the bottom edge of the Board.
+        if GRAVITY {
+            let occupied = position.piece_bb(Piece::Stone);
+            let floating = occupied & !(occupied.north() | B::FIRST_RANK);
+            if let Some(square) = floating.into_iter().next() {
+                return Err(PositionParseError::FloatingStone(
+                    square.to_string(),
+                ));
+            }
+        }
+
+        position.side_to_move = Color::from_str(stm)?;
+
+        // Calculate the Hash value for the Position.
+        position.checksum = Self::get_hash(
+            position.colored_piece_bb(ColoredPiece::X),
+            position.colored_piece_bb(ColoredPiece::O),
+            position.side_to_move,
+        );
+
+        Ok(position)
+    }
+}
+
+// Display implements displaying a Position using ASCII art.
+impl<B: BitBoardType, const K: usize, const GRAVITY: bool> fmt::Display
+    for Position<B, K, GRAVITY>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut string_rep = String::from(" ");
+
+        type File<B> = <<B as BitBoardType>::Square as SquareType>::File;
+        type Rank<B> = <<B as BitBoardType>::Square as SquareType>::Rank;
+
+        for rank in Rank::<B>::iter().rev() {
+            for file in File::<B>::iter() {
+                let square = B::Square::new(file, rank);
+                let square_str = match self.at(square) {
+                    Some(piece) => format!("{} ", piece),
+                    None => ". ".to_string(),
+                };
+                string_rep += &square_str;
+            }
+
+            // Append the rank marker.
+            string_rep += &format!(" {} \n ", rank);
+        }
+
+        // Append the file markers.
+        for file in File::<B>::iter() {
+            string_rep += &format!("{} ", file);
+        }
+
+        writeln!(f, "{}", string_rep.trim_end()).unwrap();
+        writeln!(f, "Side To Move: {}", self.side_to_move)
+    }
+}
+
+// Positions are serialized as their FEN strings.
+crate::interface::serde_string!(
+    [B: BitBoardType, const K: usize, const GRAVITY: bool]
+    Position<B, K, GRAVITY>,
+    crate::interface::PositionType::fen
+);
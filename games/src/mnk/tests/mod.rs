@@ -0,0 +1,72 @@
+use crate::interface::PositionType;
+use crate::mnk::{Color, ConnectFour, Gomoku, TicTacToe};
+use crate::perft;
+use std::str::FromStr;
+
+macro_rules! perft_test {
+    ($name:ident $game:ident $pos:literal $depth:literal $nodes:literal) => {
+        #[test]
+        fn $name() {
+            let position = $game::from_str($pos).unwrap();
+            assert_eq!(perft::<true, true, _>(position, $depth), $nodes)
+        }
+    };
+}
+
+macro_rules! winner_test {
+    ($name:ident $game:ident $pos:literal $winner:expr, $over:literal) => {
+        #[test]
+        fn $name() {
+            let position = $game::from_str($pos).unwrap();
+            assert_eq!(position.winner(), $winner);
+            assert_eq!(position.is_game_over(), $over);
+        }
+    };
+}
+
+perft_test!(tictactoe_01 TicTacToe "3/3/3 x" 5 15120);
+perft_test!(tictactoe_02 TicTacToe "3/3/3 x" 9 127872);
+perft_test!(tictactoe_03 TicTacToe "x2/1o1/3 x" 7 1584);
+
+perft_test!(connect_four_01 ConnectFour "7/7/7/7/7/7 x" 6 117649);
+perft_test!(connect_four_02 ConnectFour "7/7/7/7/7/7 x" 7 823536);
+perft_test!(connect_four_03 ConnectFour "7/7/3o3/3x3/2ox3/1xoxo2 x" 5 16164);
+
+perft_test!(gomoku_01 Gomoku "11/11/11/11/11/11/11/11/11/11/11 x" 3 1727880);
+
+winner_test!(tictactoe_row TicTacToe "3/ooo/xx1 x" Some(Color::O), true);
+winner_test!(tictactoe_diagonal TicTacToe "xo1/ox1/2x o" Some(Color::X), true);
+winner_test!(tictactoe_draw TicTacToe "xox/xoo/oxx x" None, true);
+winner_test!(tictactoe_ongoing TicTacToe "xo1/3/3 x" None, false);
+
+winner_test!(connect_four_column ConnectFour
+    "7/7/x6/x6/xo5/xoo4 o" Some(Color::X), true);
+winner_test!(connect_four_diagonal ConnectFour
+    "7/7/3o3/2ox3/1oxx3/oxxx3 x" Some(Color::O), true);
+winner_test!(connect_four_wrap ConnectFour "7/7/7/7/x6/x3xxx o" None, false);
+
+winner_test!(gomoku_row Gomoku
+    "11/11/11/11/11/3xxxxx3/3oooo4/11/11/11/11 o" Some(Color::X), true);
+
+#[test]
+fn connect_four_floating_stone() {
+    assert!(ConnectFour::from_str("7/7/7/7/3x3/7 o").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use serde_json::{from_str, to_string};
+
+    let position = ConnectFour::from_str("7/7/7/7/7/x6 o").unwrap();
+    let json = to_string(&position).unwrap();
+    assert_eq!(json, "\"7/7/7/7/7/x6 o\"");
+    assert_eq!(
+        from_str::<ConnectFour>(&json).unwrap().fen(),
+        position.fen()
+    );
+
+    let mov = position.parse_move("b1").unwrap();
+    assert_eq!(to_string(&mov).unwrap(), "\"b1\"");
+    assert_eq!(from_str::<Color>("\"o\"").unwrap(), Color::O);
+}
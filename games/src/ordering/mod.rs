@@ -0,0 +1,34 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Utilities for ordering the moves in a search.
+//!
+//! Searches usually look at the moves which are most likely to be the best
+//! first, which is done by scoring the moves with heuristics and picking them
+//! in a best-first order. This module provides a [`ScoredMoveList`] for the
+//! latter, and game-agnostic tables for some of the common move-ordering
+//! heuristics, namely the [`KillerTable`], the [`HistoryTable`] and the
+//! [`CounterMoveTable`].
+
+// Make the contents of the non-namespaced
+// modules public, so they can be accessed
+// without their parent namespace.
+pub use self::scored::*;
+pub use self::tables::*;
+
+// Non-namespaced modules.
+mod scored;
+mod tables;
+
+#[cfg(test)]
+mod tests;
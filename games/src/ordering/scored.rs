@@ -0,0 +1,159 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::vec::Vec;
+
+use core::ops::Deref;
+
+use crate::interface::MoveStore;
+
+/// ScoredMove is a move along with a score, where moves with higher scores are
+/// picked first from a [`ScoredMoveList`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ScoredMove<M> {
+    /// mov is the move which has been scored.
+    pub mov: M,
+    /// score is the score of the move.
+    pub score: i32,
+}
+
+/// ScoredMoveList is a [`MoveStore`] which stores a score along with every
+/// move, and allows picking the moves in best-first order. The moves are stored
+/// on the heap, so it can store all the moves of a position of any game, even
+/// ones with as many moves as the Game of the Amazons. A ScoredMoveList can be
+/// [cleared](Self::clear) and reused to avoid allocating for every position.
+///
+/// Moves are picked using an incremental selection sort, which only orders as
+/// many moves as are picked. This is faster than sorting all the moves in
+/// searches where a cutoff happens after the first few moves. Moves can still
+/// be pushed after picking has started, which allows staged move generation,
+/// like picking the noisy moves before generating the quiet ones.
+/// ```
+/// use tetka_games::interface::MoveStore;
+/// use tetka_games::ordering::ScoredMoveList;
+///
+/// let mut moves: ScoredMoveList<char> = ScoredMoveList::new();
+/// moves.push_scored('a', 10);
+/// moves.push_scored('b', 30);
+/// moves.push('c'); // Scored as 0.
+///
+/// assert_eq!(moves.pick_best().unwrap().mov, 'b');
+///
+/// // Moves pushed later are picked according to their scores.
+/// moves.push_scored('d', 20);
+///
+/// assert_eq!(moves.pick_best().unwrap().mov, 'd');
+/// assert_eq!(moves.pick_best().unwrap().mov, 'a');
+/// assert_eq!(moves.pick_best().unwrap().mov, 'c');
+/// assert_eq!(moves.pick_best(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScoredMoveList<M> {
+    moves: Vec<ScoredMove<M>>,
+    picked: usize,
+}
+
+impl<M: Copy> ScoredMoveList<M> {
+    /// new creates a new empty ScoredMoveList.
+    #[must_use]
+    pub fn new() -> Self {
+        ScoredMoveList {
+            moves: Vec::new(),
+            picked: 0,
+        }
+    }
+
+    /// push_scored appends the given move with the given score to the back of
+    /// the ScoredMoveList.
+    pub fn push_scored(&mut self, mov: M, score: i32) {
+        self.moves.push(ScoredMove { mov, score });
+    }
+
+    /// score_with replaces the scores of all the moves which haven't been
+    /// picked yet with the scores returned by the given function.
+    pub fn score_with<F: FnMut(M) -> i32>(&mut self, mut scorer: F) {
+        for scored in &mut self.moves[self.picked..] {
+            scored.score = scorer(scored.mov);
+        }
+    }
+
+    /// pick_best returns the move with the highest score out of the moves
+    /// which haven't been picked yet, or None if all of them have been picked.
+    /// If multiple moves have the highest score, the earliest pushed one is
+    /// picked.
+    pub fn pick_best(&mut self) -> Option<ScoredMove<M>> {
+        let remaining = &mut self.moves[self.picked..];
+
+        let mut best = 0;
+        for i in 1..remaining.len() {
+            if remaining[i].score > remaining[best].score {
+                best = i;
+            }
+        }
+
+        // Move the best move to the front of the remaining moves, so that the
+        // picked moves are stored in the order they were picked.
+        let scored = *remaining.get(best)?;
+        remaining[..=best].rotate_right(1);
+        self.picked += 1;
+
+        Some(scored)
+    }
+
+    /// picked returns the moves which have already been picked, in the order
+    /// they were picked.
+    pub fn picked(&self) -> &[ScoredMove<M>] {
+        &self.moves[..self.picked]
+    }
+
+    /// remaining returns the number of moves which haven't been picked yet.
+    pub fn remaining(&self) -> usize {
+        self.moves.len() - self.picked
+    }
+
+    /// reset allows all the moves in the ScoredMoveList to be picked again.
+    pub fn reset(&mut self) {
+        self.picked = 0;
+    }
+
+    /// clear removes all the moves from the ScoredMoveList.
+    pub fn clear(&mut self) {
+        self.moves.clear();
+        self.picked = 0;
+    }
+}
+
+impl<M: Copy> Default for ScoredMoveList<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> Deref for ScoredMoveList<M> {
+    type Target = [ScoredMove<M>];
+
+    fn deref(&self) -> &[ScoredMove<M>] {
+        &self.moves
+    }
+}
+
+// MoveStore implementation for ScoredMoveList, which scores every move as 0.
+impl<M: Copy> MoveStore<M> for ScoredMoveList<M> {
+    fn push(&mut self, m: M) {
+        self.push_scored(m, 0);
+    }
+
+    fn len(&self) -> usize {
+        self.moves.len()
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::interface::{
+    Color, Move, MoveType, PositionType, RepresentableType, Square,
+};
+
+/// KillerTable stores the killer moves for every ply of a search. Killer moves
+/// are quiet moves which caused a beta-cutoff in a sibling node, and are thus
+/// likely to cause one again. The `K` most recent killer moves are stored for
+/// every ply, with the most recent one first, so `K` must be at least one.
+/// ```
+/// use tetka_games::ataxx::{Move, Position, Square};
+/// use tetka_games::ordering::KillerTable;
+///
+/// let mut killers = KillerTable::<Position>::new(64);
+/// let mov = Move::new_single(Square::B2);
+///
+/// killers.insert(3, mov);
+///
+/// assert!(killers.contains(3, mov));
+/// assert!(!killers.contains(4, mov));
+/// ```
+#[derive(Clone)]
+pub struct KillerTable<P: PositionType, const K: usize = 2> {
+    table: Vec<[Move<P>; K]>,
+}
+
+impl<P: PositionType, const K: usize> KillerTable<P, K>
+where
+    Move<P>: PartialEq,
+{
+    /// new creates a new empty KillerTable for searches of at most the given
+    /// number of plies.
+    #[must_use]
+    pub fn new(max_ply: usize) -> Self {
+        const { assert!(K > 0, "a KillerTable must store at least one move") };
+
+        KillerTable {
+            table: vec![[Move::<P>::NULL; K]; max_ply],
+        }
+    }
+
+    /// insert adds the given move as the most recent killer move of the given
+    /// ply, forgetting the oldest one. A move which is already a killer move
+    /// of the ply is only moved to the front.
+    pub fn insert(&mut self, ply: usize, mov: Move<P>) {
+        let killers = &mut self.table[ply];
+        let end = killers.iter().position(|&m| m == mov).unwrap_or(K - 1);
+        killers[..=end].rotate_right(1);
+        killers[0] = mov;
+    }
+
+    /// get returns the killer moves of the given ply, with the most recent one
+    /// first. Empty slots are filled with the NULL move.
+    pub fn get(&self, ply: usize) -> &[Move<P>; K] {
+        &self.table[ply]
+    }
+
+    /// contains checks if the given move is a killer move of the given ply.
+    pub fn contains(&self, ply: usize, mov: Move<P>) -> bool {
+        mov != Move::<P>::NULL && self.table[ply].contains(&mov)
+    }
+
+    /// clear_ply removes all the killer moves of the given ply, which is
+    /// usually done for the children of a node before searching them.
+    pub fn clear_ply(&mut self, ply: usize) {
+        self.table[ply] = [Move::<P>::NULL; K];
+    }
+
+    /// clear removes all the killer moves from the KillerTable.
+    pub fn clear(&mut self) {
+        self.table.fill([Move::<P>::NULL; K]);
+    }
+}
+
+/// HistoryTable stores the history heuristic scores of the quiet moves of each
+/// side, indexed by the source and the target Squares of the moves. Games with
+/// moves that only have a target Square, like placement games, can use the
+/// target Square as the source Square too.
+///
+/// The scores are updated with a gravity formula, which keeps them in the range
+/// `-HistoryTable::MAX..=HistoryTable::MAX` and makes recent updates more
+/// important than older ones.
+/// ```
+/// use tetka_games::ataxx::{Color, Position, Square};
+/// use tetka_games::ordering::HistoryTable;
+///
+/// let mut history = HistoryTable::<Position>::new();
+///
+/// history.update(Color::Black, Square::A1, Square::C3, 400);
+/// history.update(Color::Black, Square::A1, Square::B2, -400);
+///
+/// assert!(history.get(Color::Black, Square::A1, Square::C3) > 0);
+/// assert!(history.get(Color::Black, Square::A1, Square::B2) < 0);
+/// assert_eq!(history.get(Color::White, Square::A1, Square::C3), 0);
+/// ```
+#[derive(Clone)]
+pub struct HistoryTable<P: PositionType> {
+    table: Vec<i32>,
+    _position: core::marker::PhantomData<P>,
+}
+
+impl<P: PositionType> HistoryTable<P> {
+    /// MAX is the maximum absolute value of a history score.
+    pub const MAX: i32 = 16384;
+
+    /// new creates a new HistoryTable with all the scores set to zero.
+    #[must_use]
+    pub fn new() -> Self {
+        HistoryTable {
+            table: vec![0; Color::<P>::N * Square::<P>::N * Square::<P>::N],
+            _position: Default::default(),
+        }
+    }
+
+    /// get returns the history score of the move of the given side from the
+    /// given source Square to the given target Square.
+    pub fn get(
+        &self,
+        color: Color<P>,
+        source: Square<P>,
+        target: Square<P>,
+    ) -> i32 {
+        self.table[Self::index(color, source, target)]
+    }
+
+    /// update adds the given bonus, which is usually positive for moves which
+    /// caused a beta-cutoff and negative for the moves searched before them,
+    /// to the history score of the given move. The bonus is clamped to
+    /// `-HistoryTable::MAX..=HistoryTable::MAX`.
+    pub fn update(
+        &mut self,
+        color: Color<P>,
+        source: Square<P>,
+        target: Square<P>,
+        bonus: i32,
+    ) {
+        let bonus = bonus.clamp(-Self::MAX, Self::MAX);
+        let entry = &mut self.table[Self::index(color, source, target)];
+        *entry += bonus - *entry * bonus.abs() / Self::MAX;
+    }
+
+    /// age halves all the history scores, which is usually done between
+    /// searches so that the old scores matter less than the new ones.
+    pub fn age(&mut self) {
+        self.table.iter_mut().for_each(|entry| *entry /= 2);
+    }
+
+    /// clear sets all the history scores to zero.
+    pub fn clear(&mut self) {
+        self.table.fill(0);
+    }
+
+    fn index(color: Color<P>, source: Square<P>, target: Square<P>) -> usize {
+        let color = color.into() as usize;
+        let source = source.into() as usize;
+        let target = target.into() as usize;
+        (color * Square::<P>::N + source) * Square::<P>::N + target
+    }
+}
+
+impl<P: PositionType> Default for HistoryTable<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// CounterMoveTable stores the counter moves to the moves of the opponent,
+/// indexed by the source and the target Squares of the opponent's move. A
+/// counter move is a quiet move which caused a beta-cutoff as a reply to the
+/// opponent's move, and is thus likely to be a good reply to it again.
+/// ```
+/// use tetka_games::ataxx::{Move, Position, Square};
+/// use tetka_games::interface::MoveType;
+/// use tetka_games::ordering::CounterMoveTable;
+///
+/// let mut counters = CounterMoveTable::<Position>::new();
+/// let reply = Move::new(Square::G7, Square::E5);
+///
+/// counters.insert(Square::A1, Square::C3, reply);
+///
+/// assert_eq!(counters.get(Square::A1, Square::C3), reply);
+/// assert_eq!(counters.get(Square::A1, Square::B2), Move::NULL);
+/// ```
+#[derive(Clone)]
+pub struct CounterMoveTable<P: PositionType> {
+    table: Vec<Move<P>>,
+}
+
+impl<P: PositionType> CounterMoveTable<P> {
+    /// new creates a new CounterMoveTable with all the counter moves set to
+    /// the NULL move.
+    #[must_use]
+    pub fn new() -> Self {
+        CounterMoveTable {
+            table: vec![Move::<P>::NULL; Square::<P>::N * Square::<P>::N],
+        }
+    }
+
+    /// get returns the counter move to the opponent's move from the given
+    /// source Square to the given target Square, or the NULL move if there is
+    /// none.
+    pub fn get(&self, source: Square<P>, target: Square<P>) -> Move<P> {
+        self.table[Self::index(source, target)]
+    }
+
+    /// insert sets the given move as the counter move to the opponent's move
+    /// from the given source Square to the given target Square.
+    pub fn insert(
+        &mut self,
+        source: Square<P>,
+        target: Square<P>,
+        mov: Move<P>,
+    ) {
+        self.table[Self::index(source, target)] = mov;
+    }
+
+    /// clear sets all the counter moves to the NULL move.
+    pub fn clear(&mut self) {
+        self.table.fill(Move::<P>::NULL);
+    }
+
+    fn index(source: Square<P>, target: Square<P>) -> usize {
+        source.into() as usize * Square::<P>::N + target.into() as usize
+    }
+}
+
+impl<P: PositionType> Default for CounterMoveTable<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
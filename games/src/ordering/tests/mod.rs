@@ -0,0 +1,161 @@
+use std::str::FromStr;
+
+use crate::amazons;
+use crate::ataxx;
+use crate::breakthrough;
+use crate::draughts;
+use crate::interface::{MoveStore, PositionType};
+use crate::ordering::{
+    CounterMoveTable, HistoryTable, KillerTable, ScoredMoveList,
+};
+
+#[test]
+fn scored_move_list_picks_best_first() {
+    let position =
+        ataxx::Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+
+    let mut moves: ScoredMoveList<ataxx::Move> = ScoredMoveList::new();
+    position.generate_moves_into::<false, true, true, _>(&mut moves);
+    moves.score_with(|m| u16::from(m) as i32 % 7);
+
+    let mut picked = Vec::new();
+    while let Some(scored) = moves.pick_best() {
+        picked.push(scored);
+    }
+
+    assert_eq!(picked.len(), position.count_moves::<true, true>());
+    assert!(picked.windows(2).all(|w| w[0].score >= w[1].score));
+    assert_eq!(moves.picked(), &picked[..]);
+    assert_eq!(moves.remaining(), 0);
+
+    // After a reset, the moves are picked in the same order again.
+    moves.reset();
+    assert_eq!(moves.pick_best(), Some(picked[0]));
+}
+
+#[test]
+fn scored_move_list_stores_any_number_of_moves() {
+    // The Game of the Amazons has thousands of moves in its start position.
+    let position =
+        amazons::Position::from_str("3b2b3/10/10/b8b/10/10/w8w/10/10/3w2w3 w")
+            .unwrap();
+
+    let mut moves: ScoredMoveList<amazons::Move> = ScoredMoveList::new();
+    position.generate_moves_into::<false, true, true, _>(&mut moves);
+    moves.score_with(|m| u32::from(m) as i32 % 101);
+    assert_eq!(moves.len(), 2176);
+
+    let mut picked = 0;
+    let mut last = i32::MAX;
+    while let Some(scored) = moves.pick_best() {
+        assert!(scored.score <= last);
+        last = scored.score;
+        picked += 1;
+    }
+
+    assert_eq!(picked, 2176);
+}
+
+#[test]
+fn scored_move_list_staged_picking() {
+    let position = breakthrough::Breakthrough::from_str(
+        "1pp1pp1p/p1p2p2/3pP3/1P6/2p1p1P1/p4p2/PP1PPPP1/P1P4P b",
+    )
+    .unwrap();
+
+    // Pick the captures first, and then generate and pick the quiet moves.
+    let mut moves: ScoredMoveList<_> = ScoredMoveList::new();
+    position.generate_moves_into::<false, false, true, _>(&mut moves);
+    let noisy = moves.len();
+    while moves.pick_best().is_some() {}
+
+    position.generate_moves_into::<false, true, false, _>(&mut moves);
+    let quiet = moves.len() - noisy;
+    assert_eq!(moves.remaining(), quiet);
+
+    let mut picked = 0;
+    while moves.pick_best().is_some() {
+        picked += 1;
+    }
+
+    assert_eq!(picked, quiet);
+    assert_eq!(noisy, position.count_moves::<false, true>());
+    assert_eq!(quiet, position.count_moves::<true, false>());
+}
+
+#[test]
+fn killer_table() {
+    let a = ataxx::Move::new_single(ataxx::Square::B2);
+    let b = ataxx::Move::new_single(ataxx::Square::C3);
+    let c = ataxx::Move::new_single(ataxx::Square::D4);
+
+    let mut killers = KillerTable::<ataxx::Position>::new(8);
+    assert!(!killers.contains(0, ataxx::Move::NULL));
+
+    killers.insert(0, a);
+    killers.insert(0, b);
+    assert_eq!(killers.get(0), &[b, a]);
+
+    // Re-inserting a killer move doesn't duplicate it.
+    killers.insert(0, a);
+    assert_eq!(killers.get(0), &[a, b]);
+
+    // The oldest killer move is forgotten.
+    killers.insert(0, c);
+    assert_eq!(killers.get(0), &[c, a]);
+    assert!(!killers.contains(0, b));
+
+    killers.clear_ply(0);
+    assert_eq!(killers.get(0), &[ataxx::Move::NULL; 2]);
+}
+
+#[test]
+fn history_table_gravity() {
+    let mut history = HistoryTable::<ataxx::Position>::new();
+    let (source, target) = (ataxx::Square::A1, ataxx::Square::C3);
+
+    for _ in 0..1000 {
+        history.update(ataxx::Color::White, source, target, 100_000);
+    }
+    assert_eq!(
+        history.get(ataxx::Color::White, source, target),
+        HistoryTable::<ataxx::Position>::MAX
+    );
+
+    for _ in 0..1000 {
+        history.update(ataxx::Color::White, source, target, -300);
+    }
+    let score = history.get(ataxx::Color::White, source, target);
+    assert!((-HistoryTable::<ataxx::Position>::MAX..0).contains(&score));
+
+    history.age();
+    assert_eq!(history.get(ataxx::Color::White, source, target), score / 2);
+
+    history.clear();
+    assert_eq!(history.get(ataxx::Color::White, source, target), 0);
+}
+
+#[test]
+fn counter_move_table() {
+    // The tables work with any game, like draughts with its 64-bit moves.
+    let position = draughts::Position::default();
+    let reply = position.generate_moves::<false, true, true>()[0];
+
+    let mut counters = CounterMoveTable::<draughts::Position>::new();
+    counters.insert(draughts::Square::C3, draughts::Square::D4, reply);
+
+    assert_eq!(
+        counters.get(draughts::Square::C3, draughts::Square::D4),
+        reply
+    );
+    assert_eq!(
+        counters.get(draughts::Square::D4, draughts::Square::C3),
+        draughts::Move::NULL
+    );
+
+    counters.clear();
+    assert_eq!(
+        counters.get(draughts::Square::C3, draughts::Square::D4),
+        draughts::Move::NULL
+    );
+}
@@ -0,0 +1,49 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A PGN-like format for recording complete games.
+//!
+//! A [`GameRecord`] contains the tag pairs of a game, its starting position,
+//! and the moves played from it, along with comments, engine evaluations and
+//! variations. Records are written in a format closely following the Portable
+//! Game Notation used for chess, with moves in the notation of the game:
+//! ```text
+//! [Event "Example"]
+//! [White "Alice"]
+//! [Black "Bob"]
+//! [Result "0-1"]
+//! [FEN "x5o/7/7/7/7/7/o5x x 0 1"]
+//! [TimeControl "10+0.1"]
+//!
+//! 1. g2 {[%eval 0.35]} a1c3 (1... b1 2. f1) 2. f2 {book exit} 0-1
+//! ```
+//!
+//! Since the PGN tags are named after chess, the `White` tag names the player
+//! of the first Color of the game, which moves first from the usual starting
+//! positions, and the `Black` tag names the player of the second Color. So,
+//! the result `1-0` is a win for the first Color of the game, even for games
+//! like ataxx where it is called black.
+
+// Make the contents of the non-namespaced
+// modules public, so they can be accessed
+// without their parent namespace.
+pub use self::parse::*;
+pub use self::record::*;
+
+// Non-namespaced modules.
+mod parse;
+mod record;
+mod write;
+
+#[cfg(test)]
+mod tests;
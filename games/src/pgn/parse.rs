@@ -0,0 +1,450 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use core::fmt::Display;
+use core::iter::Peekable;
+use core::str::{CharIndices, FromStr};
+
+use thiserror::Error;
+
+use super::{Eval, GameRecord, GameResult, MoveNode};
+use crate::interface::PositionType;
+
+/// MAX_VARIATION_DEPTH is the deepest that variations may be nested in a
+/// parsed game. Parsing, writing and dropping a record all recurse into its
+/// variations, so records built by hand should stay within it too.
+pub const MAX_VARIATION_DEPTH: usize = 256;
+
+/// PgnError represents an error encountered while parsing a [`GameRecord`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum PgnError {
+    #[error("invalid tag pair: {0}")]
+    BadTag(String),
+    #[error("invalid FEN tag: {0}")]
+    BadFen(String),
+    #[error("invalid eval in comment: {0}")]
+    BadEval(String),
+    #[error("illegal move {0} at ply {1}: {2}")]
+    IllegalMove(String, usize, String),
+    #[error("unexpected {0}")]
+    UnexpectedToken(String),
+    #[error("unclosed comment")]
+    UnclosedComment,
+    #[error("unclosed variation")]
+    UnclosedVariation,
+    #[error("variations nested deeper than {} levels", MAX_VARIATION_DEPTH)]
+    TooDeep,
+    #[error("result {0} doesn't match the result of the game {1}")]
+    ResultMismatch(GameResult, GameResult),
+    #[error("no game found")]
+    NoGame,
+}
+
+impl<P: PositionType + Clone> GameRecord<P>
+where
+    <P as FromStr>::Err: Display,
+{
+    /// parse parses the single game record in the given string. The moves of
+    /// the game are played from its FEN tag, or from the given starting
+    /// position if there is no FEN tag, and every move of the game, including
+    /// the ones in variations, is checked to be legal.
+    pub fn parse(pgn: &str, startpos: &P) -> Result<Self, PgnError> {
+        let mut games = Self::parse_all(pgn, startpos)?;
+        match games.len() {
+            0 => Err(PgnError::NoGame),
+            1 => Ok(games.pop().unwrap()),
+            _ => Err(PgnError::UnexpectedToken("second game".to_string())),
+        }
+    }
+
+    /// parse_all parses all the game records in the given string, like
+    /// [`GameRecord::parse`] does for a single one.
+    pub fn parse_all(pgn: &str, startpos: &P) -> Result<Vec<Self>, PgnError> {
+        let mut parser = Parser {
+            tokens: Tokenizer::new(pgn).peekable(),
+        };
+
+        let mut games = vec![];
+        while parser.tokens.peek().is_some() {
+            games.push(parser.game(startpos)?);
+        }
+
+        Ok(games)
+    }
+}
+
+/// Token is a single lexical token of a game record.
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    Tag(String, String),
+    Comment(String),
+    Move(String),
+    Result(GameResult),
+    OpenVariation,
+    CloseVariation,
+}
+
+/// Tokenizer splits a game record into its Tokens, ignoring move numbers and
+/// numeric annotation glyphs.
+struct Tokenizer<'a> {
+    source: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(source: &'a str) -> Self {
+        Tokenizer {
+            source,
+            chars: source.char_indices().peekable(),
+        }
+    }
+
+    /// tag parses a tag pair, with the opening bracket already consumed.
+    fn tag(&mut self) -> Result<Token, PgnError> {
+        let start = self.chars.peek().map_or(self.source.len(), |c| c.0);
+        let error = |tokenizer: &mut Self| {
+            let end = tokenizer
+                .chars
+                .peek()
+                .map_or(tokenizer.source.len(), |c| c.0);
+            PgnError::BadTag(tokenizer.source[start..end].to_string())
+        };
+
+        self.skip_whitespace();
+        let mut name = String::new();
+        while let Some(&(_, c)) = self.chars.peek() {
+            if !(c.is_alphanumeric() || c == '_') {
+                break;
+            }
+
+            name.push(c);
+            self.chars.next();
+        }
+
+        self.skip_whitespace();
+        if name.is_empty() || self.chars.next_if(|c| c.1 == '"').is_none() {
+            return Err(error(self));
+        }
+
+        let mut value = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => break,
+                Some((_, '\\')) => match self.chars.next() {
+                    Some((_, c)) => value.push(c),
+                    None => return Err(error(self)),
+                },
+                Some((_, c)) => value.push(c),
+                None => return Err(error(self)),
+            }
+        }
+
+        self.skip_whitespace();
+        if self.chars.next_if(|c| c.1 == ']').is_none() {
+            return Err(error(self));
+        }
+
+        Ok(Token::Tag(name, value))
+    }
+
+    /// word returns the word starting at the given index, which ends at a
+    /// whitespace or at the start of another token.
+    fn word(&mut self, start: usize) -> &'a str {
+        let mut end = self.source.len();
+        while let Some(&(index, c)) = self.chars.peek() {
+            if c.is_whitespace() || "{}()[];".contains(c) {
+                end = index;
+                break;
+            }
+
+            self.chars.next();
+        }
+
+        &self.source[start..end]
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.1.is_whitespace()).is_some() {}
+    }
+}
+
+impl Iterator for Tokenizer<'_> {
+    type Item = Result<Token, PgnError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.skip_whitespace();
+            let (start, c) = self.chars.next()?;
+            return Some(match c {
+                '[' => self.tag(),
+                '(' => Ok(Token::OpenVariation),
+                ')' => Ok(Token::CloseVariation),
+                '{' => {
+                    let mut comment = String::new();
+                    loop {
+                        match self.chars.next() {
+                            Some((_, '}')) => {
+                                break Ok(Token::Comment(comment))
+                            }
+                            Some((_, c)) => comment.push(c),
+                            None => break Err(PgnError::UnclosedComment),
+                        }
+                    }
+                }
+                ';' => {
+                    let mut comment = String::new();
+                    while let Some((_, c)) = self.chars.next_if(|c| c.1 != '\n')
+                    {
+                        comment.push(c);
+                    }
+
+                    Ok(Token::Comment(comment))
+                }
+                '}' | ']' => Err(PgnError::UnexpectedToken(format!("'{}'", c))),
+                _ => {
+                    let word = self.word(start);
+
+                    // Numeric annotation glyphs aren't recorded.
+                    if word.starts_with('$') {
+                        continue;
+                    }
+
+                    if let Ok(result) = GameResult::from_str(word) {
+                        return Some(Ok(Token::Result(result)));
+                    }
+
+                    // Strip any move number before the move, which may be
+                    // written without a space like "1.g2".
+                    let number =
+                        word.trim_start_matches(|c: char| c.is_ascii_digit());
+                    let mov = match number.trim_start_matches('.') {
+                        mov if mov.len() < number.len() => mov,
+                        _ => word,
+                    };
+
+                    // Move suffix annotations like "!?" aren't recorded.
+                    let mov = mov.trim_end_matches(['!', '?']);
+                    if mov.is_empty() {
+                        continue;
+                    }
+
+                    Ok(Token::Move(mov.to_string()))
+                }
+            });
+        }
+    }
+}
+
+/// Parser builds GameRecords from a stream of Tokens.
+struct Parser<'a> {
+    tokens: Peekable<Tokenizer<'a>>,
+}
+
+impl Parser<'_> {
+    /// next returns the next Token, if any.
+    fn next(&mut self) -> Result<Option<Token>, PgnError> {
+        self.tokens.next().transpose()
+    }
+
+    /// game parses the next game, which ends at its result or at the start of
+    /// the tags of the next game.
+    fn game<P: PositionType + Clone>(
+        &mut self,
+        startpos: &P,
+    ) -> Result<GameRecord<P>, PgnError>
+    where
+        <P as FromStr>::Err: Display,
+    {
+        let mut record = GameRecord::new(startpos.clone());
+        let mut tag_result = None;
+
+        while let Some(Ok(Token::Tag(..))) = self.tokens.peek() {
+            let Some(Token::Tag(name, value)) = self.next()? else {
+                unreachable!()
+            };
+
+            match name.as_str() {
+                "FEN" => {
+                    record.root = P::from_str(&value)
+                        .map_err(|err| PgnError::BadFen(err.to_string()))?;
+                }
+                "Result" => {
+                    tag_result = Some(
+                        GameResult::from_str(&value)
+                            .map_err(|_| PgnError::BadTag(value.clone()))?,
+                    );
+                }
+                _ => record.set_tag(&name, &value),
+            }
+        }
+
+        let mut comment: Option<String> = None;
+        while let Some(Ok(Token::Comment(_))) = self.tokens.peek() {
+            let Some(Token::Comment(text)) = self.next()? else {
+                unreachable!()
+            };
+
+            append_comment(&mut comment, &text);
+        }
+
+        record.comment = comment;
+
+        let root = record.root.clone();
+        let movetext_result = self.line(&root, 0, 0, &mut record.moves)?;
+        record.last = None;
+
+        record.result = match (tag_result, movetext_result) {
+            (Some(tag), Some(movetext)) if tag != movetext => {
+                return Err(PgnError::ResultMismatch(movetext, tag));
+            }
+            (tag, movetext) => movetext.or(tag).unwrap_or(GameResult::Unknown),
+        };
+
+        // A finished game can't have been decided in any other way.
+        let actual = GameResult::from_position(&record.final_position());
+        if actual != GameResult::Unknown
+            && record.result != GameResult::Unknown
+            && record.result != actual
+        {
+            return Err(PgnError::ResultMismatch(record.result, actual));
+        }
+
+        Ok(record)
+    }
+
+    /// line parses a line of moves played from the given position, whose
+    /// first move is at the given ply, into the given list, along with their
+    /// annotations and variations. The main line of a game, at depth zero,
+    /// ends at its result, which is returned, while variations, which are
+    /// nested one level deeper than the line they are in, end at their
+    /// closing parenthesis.
+    fn line<P: PositionType + Clone>(
+        &mut self,
+        position: &P,
+        ply: usize,
+        depth: usize,
+        moves: &mut Vec<MoveNode<P>>,
+    ) -> Result<Option<GameResult>, PgnError> {
+        let is_variation = depth > 0;
+        let mut previous = position.clone();
+        let mut position = position.clone();
+
+        loop {
+            // The tags of the next game may end this game.
+            if !is_variation {
+                if let Some(Ok(Token::Tag(..))) = self.tokens.peek() {
+                    return Ok(None);
+                }
+            }
+
+            match self.next()? {
+                Some(Token::Move(mov)) => {
+                    let ply = ply + moves.len();
+                    let parsed = position.parse_move(&mov).map_err(|err| {
+                        PgnError::IllegalMove(mov, ply, err.to_string())
+                    })?;
+
+                    previous = position;
+                    position = previous.after_move::<true>(parsed);
+                    moves.push(MoveNode::new(parsed));
+                }
+                Some(Token::Comment(text)) => {
+                    let Some(node) = moves.last_mut() else {
+                        return Err(PgnError::UnexpectedToken(
+                            "comment before the first move of a variation"
+                                .to_string(),
+                        ));
+                    };
+
+                    let (eval, text) = extract_eval(&text)?;
+                    if eval.is_some() {
+                        node.eval = eval;
+                    }
+
+                    if !text.is_empty() {
+                        append_comment(&mut node.comment, &text);
+                    }
+                }
+                Some(Token::OpenVariation) => {
+                    let ply = ply + moves.len();
+                    let Some(node) = moves.last_mut() else {
+                        return Err(PgnError::UnexpectedToken(
+                            "variation before the first move".to_string(),
+                        ));
+                    };
+
+                    if depth == MAX_VARIATION_DEPTH {
+                        return Err(PgnError::TooDeep);
+                    }
+
+                    let mut variation = vec![];
+                    self.line(&previous, ply - 1, depth + 1, &mut variation)?;
+                    node.variations.push(variation);
+                }
+                Some(Token::CloseVariation) if is_variation => {
+                    return Ok(None);
+                }
+                Some(Token::CloseVariation) => {
+                    return Err(PgnError::UnexpectedToken("')'".to_string()));
+                }
+                Some(Token::Result(result)) if !is_variation => {
+                    return Ok(Some(result));
+                }
+                None if !is_variation => return Ok(None),
+                // The tags of the next game were peeked at in the main line.
+                Some(Token::Result(_)) | Some(Token::Tag(..)) | None => {
+                    return Err(PgnError::UnclosedVariation);
+                }
+            }
+        }
+    }
+}
+
+/// append_comment appends the given comment text to the given comment,
+/// separating them by a space.
+fn append_comment(comment: &mut Option<String>, text: &str) {
+    let text = text.trim();
+    match comment {
+        Some(comment) => {
+            comment.push(' ');
+            comment.push_str(text);
+        }
+        None => *comment = Some(text.to_string()),
+    }
+}
+
+/// extract_eval removes the `[%eval <score>]` command from the given comment
+/// text, and returns the parsed eval along with the rest of the comment.
+fn extract_eval(text: &str) -> Result<(Option<Eval>, String), PgnError> {
+    let Some(start) = text.find("[%eval") else {
+        return Ok((None, text.trim().to_string()));
+    };
+
+    let Some(length) = text[start..].find(']') else {
+        return Err(PgnError::BadEval(text[start..].to_string()));
+    };
+
+    let command = &text[start..start + length];
+    let eval = command["[%eval".len()..]
+        .trim()
+        .parse()
+        .map_err(|_| PgnError::BadEval(command.to_string() + "]"))?;
+
+    let rest = [text[..start].trim(), text[start + length + 1..].trim()];
+    Ok((Some(eval), rest.join(" ").trim().to_string()))
+}
@@ -0,0 +1,332 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::interface::{Color, Move, MoveError, PositionType, Square};
+
+/// GameResult represents the result of a recorded game.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GameResult {
+    /// FirstWin is a win for the first Color of the game, written `1-0`.
+    FirstWin,
+    /// SecondWin is a win for the second Color of the game, written `0-1`.
+    SecondWin,
+    /// Draw is a drawn game, written `1/2-1/2`.
+    Draw,
+    /// Unknown is an unfinished game or an unknown result, written `*`.
+    Unknown,
+}
+
+impl GameResult {
+    /// from_position returns the result of the game in the given position,
+    /// which is [`GameResult::Unknown`] if the game isn't over.
+    pub fn from_position<P: PositionType>(position: &P) -> Self {
+        if !position.is_game_over() {
+            return GameResult::Unknown;
+        }
+
+        Self::from_winner::<P>(position.winner())
+    }
+
+    /// from_winner returns the result of a finished game with the given
+    /// winner, which is a draw if there is no winner.
+    pub fn from_winner<P: PositionType>(winner: Option<Color<P>>) -> Self {
+        match winner {
+            Some(winner) if Into::<u8>::into(winner) == 0 => {
+                GameResult::FirstWin
+            }
+            Some(_) => GameResult::SecondWin,
+            None => GameResult::Draw,
+        }
+    }
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                GameResult::FirstWin => "1-0",
+                GameResult::SecondWin => "0-1",
+                GameResult::Draw => "1/2-1/2",
+                GameResult::Unknown => "*",
+            }
+        )
+    }
+}
+
+impl FromStr for GameResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1-0" => Ok(GameResult::FirstWin),
+            "0-1" => Ok(GameResult::SecondWin),
+            "1/2-1/2" => Ok(GameResult::Draw),
+            "*" => Ok(GameResult::Unknown),
+            _ => Err(format!("invalid game result \"{}\"", s)),
+        }
+    }
+}
+
+// GameResults are serialized as their PGN strings.
+crate::interface::serde_string!(GameResult);
+
+/// Eval is an engine evaluation of a move, recorded in its comment as
+/// `[%eval <score>]`, where the score is in hundredths written as a decimal,
+/// like `[%eval -0.35]`, or the number of moves to a mate, like `[%eval #3]`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Eval {
+    /// Score is an evaluation in hundredths, like centipawns in chess.
+    Score(i32),
+    /// Mate is a forced win in the given number of moves if it is positive,
+    /// or a forced loss if it is negative.
+    Mate(i32),
+}
+
+impl fmt::Display for Eval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Eval::Score(score) => {
+                let sign = if score < 0 { "-" } else { "" };
+                let score = score.unsigned_abs();
+                write!(f, "{}{}.{:02}", sign, score / 100, score % 100)
+            }
+            Eval::Mate(moves) => write!(f, "#{}", moves),
+        }
+    }
+}
+
+impl FromStr for Eval {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || format!("invalid eval \"{}\"", s);
+        if let Some(moves) = s.strip_prefix('#') {
+            return moves.parse().map(Eval::Mate).map_err(|_| error());
+        }
+
+        let score = s.parse::<f64>().map_err(|_| error())?;
+        if !score.is_finite() {
+            return Err(error());
+        }
+
+        // Round half away from zero, since f64::round needs std.
+        let score = score * 100.0;
+        let half = if score < 0.0 { -0.5 } else { 0.5 };
+        Ok(Eval::Score((score + half) as i32))
+    }
+}
+
+// Evals are serialized as they are written in comments.
+crate::interface::serde_string!(Eval);
+
+/// MoveNode is a move in a GameRecord, along with its annotations and the
+/// variations which were considered instead of it.
+pub struct MoveNode<P: PositionType> {
+    /// mov is the move played.
+    pub mov: Move<P>,
+    /// eval is the evaluation of the position after the move, if any.
+    pub eval: Option<Eval>,
+    /// comment is the comment on the move, without its eval.
+    pub comment: Option<String>,
+    /// variations are the alternative lines to this move, each of which
+    /// starts from the position before it.
+    pub variations: Vec<Vec<MoveNode<P>>>,
+}
+
+impl<P: PositionType> MoveNode<P> {
+    /// new creates a new MoveNode of the given move, without any annotations.
+    pub fn new(mov: Move<P>) -> Self {
+        MoveNode {
+            mov,
+            eval: None,
+            comment: None,
+            variations: vec![],
+        }
+    }
+}
+
+// The traits are implemented manually since deriving them would require the
+// Position to implement them too, instead of only its Move type.
+
+impl<P: PositionType> Clone for MoveNode<P> {
+    fn clone(&self) -> Self {
+        MoveNode {
+            mov: self.mov,
+            eval: self.eval,
+            comment: self.comment.clone(),
+            variations: self.variations.clone(),
+        }
+    }
+}
+
+impl<P: PositionType> PartialEq for MoveNode<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.mov == other.mov
+            && self.eval == other.eval
+            && self.comment == other.comment
+            && self.variations == other.variations
+    }
+}
+
+impl<P: PositionType> Eq for MoveNode<P> {}
+
+impl<P: PositionType> fmt::Debug for MoveNode<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MoveNode")
+            .field("mov", &format_args!("{}", self.mov))
+            .field("eval", &self.eval)
+            .field("comment", &self.comment)
+            .field("variations", &self.variations)
+            .finish()
+    }
+}
+
+/// GameRecord is the record of a game, containing its tag pairs, its starting
+/// position, and the moves played from it. The result and the starting
+/// position are stored separately from the other tags, and are written as the
+/// `Result` and `FEN` tags.
+/// ```
+/// use tetka_games::ataxx::Position;
+/// use tetka_games::interface::PositionType;
+/// use tetka_games::pgn::*;
+/// use core::str::FromStr;
+///
+/// let startpos = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+/// let mut record = GameRecord::new(startpos);
+/// record.set_tag("White", "Alice");
+///
+/// for mov in ["g2", "a1c3"] {
+///     let mov = record.final_position().parse_move(mov).unwrap();
+///     record.push(mov).unwrap().eval = Some(Eval::Score(35));
+/// }
+///
+/// let pgn = record.to_string();
+/// let parsed = GameRecord::parse(&pgn, &startpos).unwrap();
+/// assert_eq!(parsed.tag("White"), Some("Alice"));
+/// assert_eq!(parsed.moves(), record.moves());
+/// ```
+#[derive(Clone, Debug)]
+pub struct GameRecord<P: PositionType> {
+    /// tags are the tag pairs of the game other than Result and FEN, in the
+    /// order they were added in.
+    pub tags: Vec<(String, String)>,
+    /// root is the starting position of the game.
+    pub(super) root: P,
+    /// comment is the comment before the first move of the game, if any.
+    pub comment: Option<String>,
+    /// moves are the moves of the main line of the game.
+    pub(super) moves: Vec<MoveNode<P>>,
+    /// result is the result of the game.
+    pub result: GameResult,
+    /// last caches the position at the end of the main line, so that pushing
+    /// a move doesn't replay the whole line. It is None if the moves may have
+    /// been changed since it was cached.
+    pub(super) last: Option<P>,
+}
+
+impl<P: PositionType + Clone> GameRecord<P> {
+    /// new creates a new GameRecord which starts from the given position,
+    /// without any tags or moves and with an unknown result.
+    pub fn new(root: P) -> Self {
+        GameRecord {
+            tags: vec![],
+            last: Some(root.clone()),
+            root,
+            comment: None,
+            moves: vec![],
+            result: GameResult::Unknown,
+        }
+    }
+
+    /// root returns the starting position of the game.
+    pub fn root(&self) -> &P {
+        &self.root
+    }
+
+    /// moves returns the moves of the main line of the game.
+    pub fn moves(&self) -> &[MoveNode<P>] {
+        &self.moves
+    }
+
+    /// moves_mut returns the moves of the main line of the game, so that they
+    /// can be annotated or changed. Any moves added through it aren't checked
+    /// for legality, unlike the ones added by push.
+    pub fn moves_mut(&mut self) -> &mut Vec<MoveNode<P>> {
+        self.last = None;
+        &mut self.moves
+    }
+
+    /// tag returns the value of the tag with the given name, if any.
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// set_tag sets the value of the tag with the given name, replacing its
+    /// old value if it is already set. The Result and FEN tags should be set
+    /// through the result and root fields instead.
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        match self.tags.iter_mut().find(|(tag, _)| tag == name) {
+            Some((_, old)) => value.clone_into(old),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    /// final_position returns the position at the end of the main line.
+    pub fn final_position(&self) -> P {
+        match &self.last {
+            Some(last) => last.clone(),
+            None => self.positions().last().unwrap(),
+        }
+    }
+
+    /// positions returns an iterator over the positions of the main line,
+    /// starting with the root position.
+    pub fn positions(&self) -> impl Iterator<Item = P> + '_ {
+        let mut position = Some(self.root.clone());
+        let mut moves = self.moves.iter();
+        core::iter::from_fn(move || {
+            let current = position.take()?;
+            position = moves
+                .next()
+                .map(|node| current.after_move::<true>(node.mov));
+            Some(current)
+        })
+    }
+
+    /// push adds the given move to the end of the main line, if it is legal,
+    /// and returns its MoveNode so that it can be annotated.
+    pub fn push(
+        &mut self,
+        mov: Move<P>,
+    ) -> Result<&mut MoveNode<P>, MoveError<Square<P>>> {
+        let position = self.final_position();
+        position.check_move(mov)?;
+        self.last = Some(position.after_move::<true>(mov));
+        self.moves.push(MoveNode::new(mov));
+        Ok(self.moves.last_mut().unwrap())
+    }
+}
@@ -0,0 +1,289 @@
+use std::str::FromStr;
+
+use crate::ataxx::Position;
+use crate::interface::{MoveError, PositionType};
+use crate::mnk::TicTacToe;
+use crate::pgn::*;
+
+const STARTPOS: &str = "x5o/7/7/7/7/7/o5x x 0 1";
+
+fn startpos() -> Position {
+    Position::from_str(STARTPOS).unwrap()
+}
+
+fn play<'a>(
+    record: &'a mut GameRecord<Position>,
+    mov: &str,
+) -> &'a mut MoveNode<Position> {
+    let mov = record.final_position().parse_move(mov).unwrap();
+    record.push(mov).unwrap()
+}
+
+#[test]
+fn write() {
+    let mut record = GameRecord::new(startpos());
+    record.set_tag("Event", "Test");
+    record.set_tag("Black", "B");
+    record.set_tag("TimeControl", "10+0.1");
+    record.set_tag("White", "A \"Quoted\"");
+
+    play(&mut record, "g2").eval = Some(Eval::Score(35));
+    let mut variation =
+        MoveNode::new(record.final_position().parse_move("b1").unwrap());
+    variation.comment = Some("also good".to_string());
+    play(&mut record, "a2").variations.push(vec![variation]);
+    play(&mut record, "f1");
+
+    assert_eq!(
+        record.to_string(),
+        concat!(
+            "[Event \"Test\"]\n",
+            "[White \"A \\\"Quoted\\\"\"]\n",
+            "[Black \"B\"]\n",
+            "[Result \"*\"]\n",
+            "[FEN \"x5o/7/7/7/7/7/o5x x 0 1\"]\n",
+            "[TimeControl \"10+0.1\"]\n",
+            "\n",
+            "1. g2 {[%eval 0.35]} 1... a2 (1... b1 {also good}) 2. f1 *\n",
+        )
+    );
+
+    let parsed = GameRecord::parse(&record.to_string(), &startpos()).unwrap();
+    assert_eq!(parsed.tag("White"), Some("A \"Quoted\""));
+    assert_eq!(parsed.tag("TimeControl"), Some("10+0.1"));
+    assert_eq!(parsed.moves(), record.moves());
+    assert_eq!(parsed.to_string(), record.to_string());
+}
+
+#[test]
+fn parse() {
+    let pgn = r#"
+[Event "Example"]
+[Result "1/2-1/2"]
+
+{Opening comment} 1.g2 $1 {[%eval -0.35] clone} a2 ; line comment
+2. f1 (2. g1e1?! (2. f2) {[%eval #3]} 2... b1) 2... b2 1/2-1/2
+"#;
+
+    let record = GameRecord::parse(pgn, &startpos()).unwrap();
+    assert_eq!(record.tag("Event"), Some("Example"));
+    assert_eq!(record.tag("Result"), None);
+    assert_eq!(record.result, GameResult::Draw);
+    assert_eq!(record.comment.as_deref(), Some("Opening comment"));
+
+    let moves: Vec<_> =
+        record.moves().iter().map(|n| n.mov.to_string()).collect();
+    assert_eq!(moves, ["g2", "a2", "f1", "b2"]);
+
+    assert_eq!(record.moves()[0].eval, Some(Eval::Score(-35)));
+    assert_eq!(record.moves()[0].comment.as_deref(), Some("clone"));
+    assert_eq!(record.moves()[1].comment.as_deref(), Some("line comment"));
+
+    let variation = &record.moves()[2].variations[0];
+    assert_eq!(variation.len(), 2);
+    assert_eq!(variation[0].mov.to_string(), "g1e1");
+    assert_eq!(variation[0].eval, Some(Eval::Mate(3)));
+    assert_eq!(variation[0].variations[0][0].mov.to_string(), "f2");
+    assert_eq!(variation[1].mov.to_string(), "b1");
+}
+
+#[test]
+fn parse_fen_tag() {
+    let pgn = "[FEN \"x5o/7/7/7/7/7/o5x o 0 1\"]\n\n1... a2 2. g2 *";
+    let record = GameRecord::parse(pgn, &startpos()).unwrap();
+    assert_eq!(record.root().fen(), "x5o/7/7/7/7/7/o5x o 0 1");
+    assert_eq!(record.moves().len(), 2);
+    assert!(record.to_string().ends_with("\n1... a2 2. g2 *\n"));
+}
+
+#[test]
+fn parse_all() {
+    let pgn = "[Event \"1\"]\n1. g2 *\n\n[Event \"2\"]\n1. f1 a2\n";
+    let records = GameRecord::parse_all(pgn, &startpos()).unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].tag("Event"), Some("1"));
+    assert_eq!(records[1].tag("Event"), Some("2"));
+    assert_eq!(records[1].moves().len(), 2);
+    assert_eq!(records[1].result, GameResult::Unknown);
+
+    assert_eq!(
+        GameRecord::parse(pgn, &startpos()).err(),
+        Some(PgnError::UnexpectedToken("second game".to_string()))
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let parse = |pgn: &str| GameRecord::parse(pgn, &startpos()).err().unwrap();
+
+    assert_eq!(
+        parse("1. g2 a7"),
+        PgnError::IllegalMove(
+            "a7".to_string(),
+            1,
+            MoveError::<crate::ataxx::Square>::OccupiedTarget(
+                crate::ataxx::Square::A7
+            )
+            .to_string()
+        )
+    );
+    assert!(matches!(
+        parse("1. g2 (1. g7) *"),
+        PgnError::IllegalMove(_, 0, _)
+    ));
+    assert!(matches!(
+        parse("1. g2 a2 d4"),
+        PgnError::IllegalMove(_, 2, _)
+    ));
+    assert!(matches!(parse("[Event Example]"), PgnError::BadTag(_)));
+    assert!(matches!(parse("[FEN \"x\"]"), PgnError::BadFen(_)));
+    assert!(matches!(parse("1. g2 {[%eval x]}"), PgnError::BadEval(_)));
+    assert_eq!(parse("1. g2 {comment"), PgnError::UnclosedComment);
+    assert_eq!(parse("1. g2 (1. f1 *"), PgnError::UnclosedVariation);
+    assert_eq!(parse(""), PgnError::NoGame);
+    assert!(matches!(parse("1. g2 )"), PgnError::UnexpectedToken(_)));
+    assert!(matches!(parse("(1. g2)"), PgnError::UnexpectedToken(_)));
+    assert_eq!(
+        parse("[Result \"1-0\"] 1. g2 0-1"),
+        PgnError::ResultMismatch(GameResult::SecondWin, GameResult::FirstWin)
+    );
+}
+
+#[test]
+fn eval() {
+    for (eval, string) in [
+        (Eval::Score(35), "0.35"),
+        (Eval::Score(-5), "-0.05"),
+        (Eval::Score(1250), "12.50"),
+        (Eval::Mate(3), "#3"),
+        (Eval::Mate(-2), "#-2"),
+    ] {
+        assert_eq!(eval.to_string(), string);
+        assert_eq!(Eval::from_str(string), Ok(eval));
+    }
+
+    assert_eq!(Eval::from_str("+1.5"), Ok(Eval::Score(150)));
+    assert!(Eval::from_str("NaN").is_err());
+    assert!(Eval::from_str("#").is_err());
+}
+
+#[test]
+fn tictactoe() {
+    let startpos = TicTacToe::from_str("3/3/3 x").unwrap();
+    let mut record = GameRecord::new(startpos);
+    for mov in ["b2", "a1", "a3", "c3", "c1"] {
+        let mov = record.final_position().parse_move(mov).unwrap();
+        record.push(mov).unwrap();
+    }
+
+    let mov = record.final_position().parse_move("c2");
+    assert_eq!(mov.err(), Some(MoveError::GameOver));
+
+    record.result = GameResult::from_position(&record.final_position());
+    assert_eq!(record.result, GameResult::FirstWin);
+
+    let pgn = record.to_string();
+    assert!(pgn.ends_with("\n1. b2 a1 2. a3 c3 3. c1 1-0\n"));
+
+    let parsed = GameRecord::parse(&pgn, &startpos).unwrap();
+    assert_eq!(parsed.moves(), record.moves());
+    assert_eq!(parsed.result, GameResult::FirstWin);
+
+    // A finished game's result must match the actual result of the game.
+    let pgn = pgn.replace("1-0", "0-1");
+    assert_eq!(
+        GameRecord::parse(&pgn, &startpos).err(),
+        Some(PgnError::ResultMismatch(
+            GameResult::SecondWin,
+            GameResult::FirstWin
+        ))
+    );
+}
+
+#[test]
+fn line_wrapping() {
+    let mut record = GameRecord::new(startpos());
+    let mut rng = crate::random::Rng::new(0x967);
+    while record.moves().len() < 60 && !record.final_position().is_game_over() {
+        let moves = record
+            .final_position()
+            .generate_moves::<false, true, true>();
+        let mov = moves[rng.below(moves.len())];
+        record.push(mov).unwrap();
+    }
+
+    let pgn = record.to_string();
+    assert!(pgn.lines().all(|line| line.len() <= 80));
+
+    let parsed = GameRecord::parse(&pgn, &startpos()).unwrap();
+    assert_eq!(parsed.moves(), record.moves());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use serde_json::{from_str, to_string};
+
+    assert_eq!(to_string(&GameResult::Draw).unwrap(), "\"1/2-1/2\"");
+    assert_eq!(to_string(&Eval::Mate(-3)).unwrap(), "\"#-3\"");
+    assert_eq!(from_str::<Eval>("\"0.35\"").unwrap(), Eval::Score(35));
+    assert!(from_str::<GameResult>("\"2-0\"").is_err());
+}
+
+#[test]
+fn closing_brace_in_comment() {
+    let mut record = GameRecord::new(startpos());
+    record.comment = Some("opening {book}".to_string());
+    play(&mut record, "g2").comment = Some("a } b".to_string());
+    play(&mut record, "a2").eval = Some(Eval::Score(10));
+    record.moves_mut()[1].comment = Some("}".to_string());
+    play(&mut record, "f1");
+
+    let pgn = record.to_string();
+    assert!(pgn.ends_with(
+        "{opening {book)} 1. g2 {a ) b} 1... a2 {[%eval 0.10] )} 2. f1 *\n"
+    ));
+
+    let parsed = GameRecord::parse(&pgn, &startpos()).unwrap();
+    assert_eq!(parsed.comment.as_deref(), Some("opening {book)"));
+    assert_eq!(parsed.moves().len(), 3);
+    assert_eq!(parsed.moves()[0].comment.as_deref(), Some("a ) b"));
+    assert_eq!(parsed.moves()[1].comment.as_deref(), Some(")"));
+    assert_eq!(parsed.moves()[1].eval, Some(Eval::Score(10)));
+}
+
+#[test]
+fn final_position() {
+    let mut record = GameRecord::new(startpos());
+    play(&mut record, "g2");
+    play(&mut record, "a1c3");
+    assert_eq!(record.final_position().fen(), "x5o/7/7/7/2o4/6x/6x x 1 2");
+
+    // Changing the moves directly is reflected in the final position.
+    record.moves_mut().pop();
+    assert_eq!(record.final_position().fen(), "x5o/7/7/7/7/6x/o5x o 0 1");
+    play(&mut record, "b1");
+    assert_eq!(
+        record.final_position().fen(),
+        record.positions().last().unwrap().fen()
+    );
+}
+
+#[test]
+fn variation_depth() {
+    let nested = |depth: usize| {
+        format!("1. g2 {}{} *", "(1. b6 ".repeat(depth), ")".repeat(depth))
+    };
+
+    let record =
+        GameRecord::parse(&nested(MAX_VARIATION_DEPTH), &startpos()).unwrap();
+    let parsed = GameRecord::parse(&record.to_string(), &startpos()).unwrap();
+    assert_eq!(parsed.moves(), record.moves());
+
+    for depth in [MAX_VARIATION_DEPTH + 1, 20000] {
+        assert_eq!(
+            GameRecord::parse(&nested(depth), &startpos()).err(),
+            Some(PgnError::TooDeep)
+        );
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use core::fmt;
+
+use super::{GameRecord, MoveNode};
+use crate::interface::PositionType;
+
+/// MAX_LINE_LENGTH is the maximum length of a line of movetext, which is only
+/// exceeded by single tokens longer than it, like long comments.
+const MAX_LINE_LENGTH: usize = 80;
+
+/// ROSTER are the tags which are always written first, in this order, even
+/// if they aren't set in a record.
+const ROSTER: [&str; 3] = ["Event", "White", "Black"];
+
+impl<P: PositionType + Clone> fmt::Display for GameRecord<P> {
+    /// Display writes the GameRecord in its PGN-like format, with its Event,
+    /// White, Black, Result and FEN tags first, followed by its other tags and
+    /// its movetext. Since comments end at the first `}`, any `}` in them is
+    /// written as `)` instead.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for name in ROSTER {
+            write_tag(f, name, self.tag(name).unwrap_or("?"))?;
+        }
+
+        write_tag(f, "Result", &self.result.to_string())?;
+        write_tag(f, "FEN", &self.root.fen())?;
+
+        for (name, value) in &self.tags {
+            if !ROSTER.contains(&name.as_str()) {
+                write_tag(f, name, value)?;
+            }
+        }
+
+        writeln!(f)?;
+
+        let mut tokens = vec![];
+        if let Some(comment) = &self.comment {
+            tokens.push(write_comment(comment));
+        }
+
+        // Games starting with the second Color to move have their first move
+        // numbered as the second half of the first move.
+        let ply = Into::<u8>::into(self.root.side_to_move()) as usize;
+        write_line(&mut tokens, &self.moves, ply);
+        tokens.push(self.result.to_string());
+
+        let mut length = 0;
+        for token in tokens {
+            if length > 0 && length + token.len() + 1 > MAX_LINE_LENGTH {
+                writeln!(f)?;
+                length = 0;
+            }
+
+            if length > 0 && !token.starts_with(')') {
+                write!(f, " ")?;
+                length += 1;
+            }
+
+            write!(f, "{}", token)?;
+            length += token.len();
+        }
+
+        writeln!(f)
+    }
+}
+
+/// write_tag writes the given tag pair, escaping its value.
+fn write_tag(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    value: &str,
+) -> fmt::Result {
+    let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+    writeln!(f, "[{} \"{}\"]", name, value)
+}
+
+/// write_comment returns the given comment text as a comment token, with any
+/// `}` in it replaced by `)` so that it doesn't end the comment early.
+fn write_comment(text: &str) -> String {
+    format!("{{{}}}", text.replace('}', ")"))
+}
+
+/// write_line adds the tokens of the given line of moves, whose first move is
+/// at the given ply, to the given token list. The opening parenthesis of a
+/// variation is attached to its first token, so that it isn't separated from
+/// it when wrapping lines.
+fn write_line<P: PositionType>(
+    tokens: &mut Vec<String>,
+    moves: &[MoveNode<P>],
+    ply: usize,
+) {
+    // The number of a move by the second Color is only written if something
+    // comes between it and the previous move, like a comment or a variation.
+    let mut needs_number = true;
+    for (offset, node) in moves.iter().enumerate() {
+        let ply = ply + offset;
+        let mut token = if ply.is_multiple_of(2) {
+            format!("{}. ", ply / 2 + 1)
+        } else if needs_number {
+            format!("{}... ", ply / 2 + 1)
+        } else {
+            String::new()
+        };
+
+        token.push_str(&node.mov.to_string());
+        tokens.push(token);
+        needs_number = false;
+
+        let comment = match (node.eval, &node.comment) {
+            (Some(eval), Some(comment)) => {
+                Some(write_comment(&format!("[%eval {}] {}", eval, comment)))
+            }
+            (Some(eval), None) => Some(format!("{{[%eval {}]}}", eval)),
+            (None, Some(comment)) => Some(write_comment(comment)),
+            (None, None) => None,
+        };
+
+        if let Some(comment) = comment {
+            tokens.push(comment);
+            needs_number = true;
+        }
+
+        for variation in node.variations.iter().filter(|v| !v.is_empty()) {
+            let start = tokens.len();
+            write_line(tokens, variation, ply);
+            tokens[start].insert(0, '(');
+            tokens.push(")".to_string());
+            needs_number = true;
+        }
+    }
+}
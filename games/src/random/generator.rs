@@ -0,0 +1,171 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use core::ops::RangeInclusive;
+
+use crate::interface::PositionType;
+use crate::random::Rng;
+
+/// Filter is a custom filter for the positions generated by RandomPositions.
+type Filter<P> = Box<dyn Fn(&P) -> bool>;
+
+/// RandomPositions generates random positions by playing random legal moves
+/// from a root position. The number of moves played is picked uniformly from a
+/// range of plies, and the generated positions can be filtered, for example to
+/// only keep positions with at least a certain number of legal moves.
+///
+/// RandomPositions is also an [`Iterator`] over the generated positions, which
+/// ends if no position satisfying the filters is found after the configured
+/// number of attempts.
+/// ```
+/// use tetka_games::ataxx::Position;
+/// use tetka_games::interface::PositionType;
+/// use tetka_games::random::RandomPositions;
+/// use core::str::FromStr;
+///
+/// let root = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+/// let positions = RandomPositions::new(root, 0xDEADBEEF)
+///     .plies(8..=16)
+///     .not_game_over()
+///     .min_moves(10);
+///
+/// for position in positions.take(10) {
+///     assert!(!position.is_game_over());
+///     assert!(position.count_moves::<true, true>() >= 10);
+/// }
+/// ```
+pub struct RandomPositions<P: PositionType + Clone> {
+    root: P,
+    rng: Rng,
+    plies: RangeInclusive<usize>,
+    not_game_over: bool,
+    min_moves: usize,
+    filters: Vec<Filter<P>>,
+    max_attempts: usize,
+}
+
+impl<P: PositionType + Clone> RandomPositions<P> {
+    /// new creates a new RandomPositions which plays out from the given root
+    /// position, with its random moves decided by the given seed. By default
+    /// between 0 and 20 plies are played, and positions aren't filtered.
+    #[must_use]
+    pub fn new(root: P, seed: u64) -> Self {
+        RandomPositions {
+            root,
+            rng: Rng::new(seed),
+            plies: 0..=20,
+            not_game_over: false,
+            min_moves: 0,
+            filters: vec![],
+            max_attempts: 1000,
+        }
+    }
+
+    /// plies sets the range of the number of plies played from the root
+    /// position to reach a generated position.
+    #[must_use]
+    pub fn plies(mut self, plies: RangeInclusive<usize>) -> Self {
+        self.plies = plies;
+        self
+    }
+
+    /// not_game_over filters out positions where the game is over.
+    #[must_use]
+    pub fn not_game_over(mut self) -> Self {
+        self.not_game_over = true;
+        self
+    }
+
+    /// min_moves filters out positions with less than the given number of
+    /// legal moves.
+    #[must_use]
+    pub fn min_moves(mut self, moves: usize) -> Self {
+        self.min_moves = moves;
+        self
+    }
+
+    /// filter adds a custom filter, which only keeps the positions for which
+    /// the given function returns `true`.
+    #[must_use]
+    pub fn filter(mut self, filter: impl Fn(&P) -> bool + 'static) -> Self {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
+    /// max_attempts sets the number of playouts tried by [`Self::generate`]
+    /// before giving up on finding a position which passes the filters.
+    #[must_use]
+    pub fn max_attempts(mut self, attempts: usize) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// generate returns a new random position which passes all the filters, or
+    /// `None` if no such position was found in the allowed number of attempts.
+    pub fn generate(&mut self) -> Option<P> {
+        for _ in 0..self.max_attempts {
+            if let Some(position) = self.playout() {
+                if self.accepts(&position) {
+                    return Some(position);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// playout plays a random number of random moves from the root position,
+    /// and returns `None` if the game ended before all of them were played.
+    fn playout(&mut self) -> Option<P> {
+        let plies = self.rng.range(self.plies.clone());
+
+        let mut position = self.root.clone();
+        for _ in 0..plies {
+            let moves = position.generate_moves::<false, true, true>();
+            if moves.is_empty() {
+                return None;
+            }
+
+            let mov = moves[self.rng.below(moves.len())];
+            position = position.after_move::<true>(mov);
+        }
+
+        Some(position)
+    }
+
+    fn accepts(&self, position: &P) -> bool {
+        if self.not_game_over && position.is_game_over() {
+            return false;
+        }
+
+        if self.min_moves > 0
+            && position.count_moves::<true, true>() < self.min_moves
+        {
+            return false;
+        }
+
+        self.filters.iter().all(|filter| filter(position))
+    }
+}
+
+impl<P: PositionType + Clone> Iterator for RandomPositions<P> {
+    type Item = P;
+
+    fn next(&mut self) -> Option<P> {
+        self.generate()
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Seeded generation of random positions for testing and fuzzing.
+//!
+//! Random positions are generated by playing random legal moves from a root
+//! position, so every generated position is reachable in an actual game. The
+//! [`RandomPositions`] generator is seeded with a [`Rng`], which makes the
+//! generated positions reproducible from the seed alone.
+
+// Make the contents of the non-namespaced
+// modules public, so they can be accessed
+// without their parent namespace.
+pub use self::generator::*;
+pub use self::rng::*;
+
+// Non-namespaced modules.
+mod generator;
+mod rng;
+
+#[cfg(test)]
+mod tests;
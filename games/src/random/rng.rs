@@ -0,0 +1,82 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::ops::RangeInclusive;
+
+/// Rng is a small and fast pseudo-random number generator, based on the
+/// xorshift64* algorithm. It is not cryptographically secure, but the numbers
+/// generated from a seed stay the same across platforms and versions, which is
+/// what makes a fuzzing failure reproducible.
+/// ```
+/// use tetka_games::random::Rng;
+///
+/// let mut a = Rng::new(42);
+/// let mut b = Rng::new(42);
+///
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// assert!(a.below(10) < 10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// new creates a new Rng from the given seed. Any seed, including zero, is
+    /// valid, and similar seeds still produce unrelated sequences.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        // Scramble the seed with a round of splitmix64, since xorshift needs a
+        // non-zero state and takes a while to recover from sparse ones.
+        let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^= z >> 31;
+
+        Rng {
+            state: if z == 0 { 0x9E3779B97F4A7C15 } else { z },
+        }
+    }
+
+    /// next_u64 returns the next random 64-bit number from the Rng.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    /// below returns a random number in the range `0..n`. It panics if `n` is
+    /// zero, since the range is empty.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "Rng::below() called with an empty range");
+
+        // Take the high bits of the product, which avoids the bias of the
+        // low bits that a simple modulo would introduce.
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// range returns a random number in the given inclusive range. It panics
+    /// if the range is empty.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Rng::range() called with an empty range");
+
+        // The length of the range only overflows for the range of all usizes,
+        // which every random number is a part of.
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.below(len),
+            None => self.next_u64() as usize,
+        }
+    }
+}
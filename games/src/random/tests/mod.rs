@@ -0,0 +1,95 @@
+use crate::ataxx::Position;
+use crate::interface::PositionType;
+use crate::random::{RandomPositions, Rng};
+use std::str::FromStr;
+
+const STARTPOS: &str = "x5o/7/7/7/7/7/o5x x 0 1";
+
+#[test]
+fn rng_is_deterministic() {
+    let mut a = Rng::new(7);
+    let mut b = Rng::new(7);
+    let mut c = Rng::new(8);
+
+    let a = (0..100).map(|_| a.next_u64()).collect::<Vec<_>>();
+    let b = (0..100).map(|_| b.next_u64()).collect::<Vec<_>>();
+    let c = (0..100).map(|_| c.next_u64()).collect::<Vec<_>>();
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
+
+#[test]
+fn rng_ranges() {
+    let mut rng = Rng::new(0);
+    let mut seen = [false; 10];
+    for _ in 0..1000 {
+        let n = rng.below(10);
+        assert!(n < 10);
+        seen[n] = true;
+
+        let n = rng.range(5..=7);
+        assert!((5..=7).contains(&n));
+    }
+
+    // Every number in the range should be generated eventually.
+    assert!(seen.iter().all(|&seen| seen));
+}
+
+#[test]
+fn rng_full_range() {
+    let mut rng = Rng::new(0);
+    let numbers = (0..100).map(|_| rng.range(0..=usize::MAX));
+    assert!(numbers.collect::<Vec<_>>().windows(2).any(|w| w[0] != w[1]));
+
+    assert_eq!(rng.range(usize::MAX..=usize::MAX), usize::MAX);
+    assert!(rng.range(1..=usize::MAX) >= 1);
+}
+
+#[test]
+fn positions_are_reproducible() {
+    let root = Position::from_str(STARTPOS).unwrap();
+    let a = RandomPositions::new(root, 42).take(20).map(|p| p.fen());
+    let b = RandomPositions::new(root, 42).take(20).map(|p| p.fen());
+
+    assert!(a.eq(b));
+}
+
+#[test]
+fn positions_respect_plies() {
+    let root = Position::from_str(STARTPOS).unwrap();
+    for position in RandomPositions::new(root, 1).plies(5..=9).take(100) {
+        assert!((5..=9).contains(&(position.ply_count as usize)));
+    }
+}
+
+#[test]
+fn positions_respect_filters() {
+    let root = Position::from_str(STARTPOS).unwrap();
+    let positions = RandomPositions::new(root, 2)
+        .plies(30..=60)
+        .not_game_over()
+        .min_moves(20)
+        .filter(|p| p.half_move_clock > 0);
+
+    let mut count = 0;
+    for position in positions.take(100) {
+        count += 1;
+        assert!(!position.is_game_over());
+        assert!(position.count_moves::<true, true>() >= 20);
+        assert!(position.half_move_clock > 0);
+    }
+
+    assert_eq!(count, 100);
+}
+
+#[test]
+fn impossible_filters_end_generation() {
+    let root = Position::from_str(STARTPOS).unwrap();
+    let mut positions = RandomPositions::new(root, 3)
+        .max_attempts(10)
+        .filter(|_| false);
+
+    assert!(positions.generate().is_none());
+    assert!(positions.next().is_none());
+}
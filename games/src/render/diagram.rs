@@ -0,0 +1,165 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::vec::Vec;
+
+use strum::IntoEnumIterator;
+
+use crate::interface::{
+    ColoredPiece, File, PositionType, Rank, Square, SquareType,
+};
+
+/// Diagram is a diagram of a position, with optional highlights for the last
+/// move played, the squares captured by it, and arrows between squares.
+/// Diagrams are built with the highlight methods, and are then rendered with
+/// [`Diagram::svg`], [`Diagram::unicode`] or [`Diagram::ansi`].
+///
+/// Since moves are represented differently by every game, highlights are
+/// specified with squares, which can be taken from the game's move type.
+pub struct Diagram<'a, P: PositionType> {
+    pub(super) position: &'a P,
+    pub(super) source: Option<Square<P>>,
+    pub(super) target: Option<Square<P>>,
+    pub(super) captured: Vec<Square<P>>,
+    pub(super) arrows: Vec<(Square<P>, Square<P>)>,
+    pub(super) theme: Theme,
+}
+
+impl<'a, P: PositionType> Diagram<'a, P> {
+    /// new creates a new Diagram of the given position without highlights,
+    /// which is drawn with the default [`Theme`].
+    #[must_use]
+    pub fn new(position: &'a P) -> Self {
+        Diagram {
+            position,
+            source: None,
+            target: None,
+            captured: Vec::new(),
+            arrows: Vec::new(),
+            theme: Theme::default(),
+        }
+    }
+
+    /// last_move highlights the source and target squares of the last move
+    /// played. The source should be `None` for moves without one, like the
+    /// placements of m,n,k-games and hex or the single moves of ataxx.
+    #[must_use]
+    pub fn last_move(
+        mut self,
+        source: Option<Square<P>>,
+        target: Square<P>,
+    ) -> Self {
+        self.source = source;
+        self.target = Some(target);
+        self
+    }
+
+    /// captured highlights the given squares as captured, which can also be
+    /// a BitBoard of the captured squares.
+    #[must_use]
+    pub fn captured(
+        mut self,
+        squares: impl IntoIterator<Item = Square<P>>,
+    ) -> Self {
+        self.captured.extend(squares);
+        self
+    }
+
+    /// arrow draws an arrow from the first to the second of the given squares,
+    /// for example to show a threat or a suggested move. Arrows are only drawn
+    /// in SVG diagrams.
+    #[must_use]
+    pub fn arrow(mut self, from: Square<P>, to: Square<P>) -> Self {
+        self.arrows.push((from, to));
+        self
+    }
+
+    /// theme sets the colors used to draw the diagram.
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// squares returns the squares of the board rank by rank from the top rank
+    /// of the diagram, along with their file and rank indices.
+    pub(super) fn squares(
+        &self,
+    ) -> impl Iterator<Item = (Square<P>, usize, usize)> {
+        Rank::<P>::iter().rev().flat_map(|rank| {
+            File::<P>::iter().map(move |file| {
+                let square = Square::<P>::new(file, rank);
+                (square, file.into() as usize, rank.into() as usize)
+            })
+        })
+    }
+
+    /// highlight returns the highlight of the given square, if any. Captured
+    /// squares take precedence over the squares of the last move.
+    pub(super) fn highlight(&self, square: Square<P>) -> Option<Highlight> {
+        if self.captured.contains(&square) {
+            Some(Highlight::Captured)
+        } else if self.source == Some(square) || self.target == Some(square) {
+            Some(Highlight::LastMove)
+        } else {
+            None
+        }
+    }
+
+    /// piece returns the piece on the given square, if any.
+    pub(super) fn piece(&self, square: Square<P>) -> Option<ColoredPiece<P>> {
+        self.position.at(square)
+    }
+}
+
+/// Highlight represents the ways in which a square can be highlighted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum Highlight {
+    LastMove,
+    Captured,
+}
+
+/// Theme contains the colors used to draw an SVG diagram, which can be any
+/// color understood by SVG, like `#f0d9b5` or `red`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    /// The color of the light squares.
+    pub light: &'static str,
+    /// The color of the dark squares, which include the bottom-left square.
+    pub dark: &'static str,
+    /// The colors of the pieces of each side, in the order of the sides in
+    /// the game's color type, followed by the color of colorless pieces.
+    pub pieces: [&'static str; 3],
+    /// The color of the outlines of pieces and the coordinates.
+    pub outline: &'static str,
+    /// The color of the squares of the last move.
+    pub last_move: &'static str,
+    /// The color of the captured squares.
+    pub captured: &'static str,
+    /// The color of the arrows.
+    pub arrow: &'static str,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            light: "#f0d9b5",
+            dark: "#b58863",
+            pieces: ["#222222", "#eeeeee", "#555555"],
+            outline: "#000000",
+            last_move: "#cdd26a",
+            captured: "#e06666",
+            arrow: "#15781b",
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rendering of positions into diagrams for reports, documentation and
+//! debugging.
+//!
+//! A [`Diagram`] of any [`PositionType`](crate::interface::PositionType) is
+//! created from a reference to the position, and is then decorated with
+//! highlights for the last move, captured squares and arrows before being
+//! rendered as an SVG image with [`Diagram::svg`], or as text for terminals
+//! with [`Diagram::unicode`] and [`Diagram::ansi`].
+//! ```
+//! use tetka_games::ataxx::{Position, Square};
+//! use tetka_games::render::Diagram;
+//! use core::str::FromStr;
+//!
+//! let position = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+//! let diagram = Diagram::new(&position)
+//!     .last_move(Some(Square::A7), Square::C5)
+//!     .arrow(Square::G1, Square::F2);
+//!
+//! let svg = diagram.svg();
+//! assert!(svg.starts_with("<svg"));
+//! println!("{}", diagram.ansi());
+//! ```
+
+// Make the contents of the non-namespaced
+// modules public, so they can be accessed
+// without their parent namespace.
+pub use self::diagram::*;
+
+// Non-namespaced modules.
+mod diagram;
+mod svg;
+mod terminal;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,185 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::format;
+use alloc::string::String;
+
+use core::fmt::Write;
+
+use super::{Diagram, Highlight};
+use crate::interface::{
+    ColoredPieceType, File, PositionType, Rank, RepresentableType, Square,
+    SquareType,
+};
+
+/// SQUARE is the side length of a square in an SVG diagram. The board is
+/// surrounded by a margin of half a square, which holds the coordinates.
+const SQUARE: usize = 50;
+
+impl<P: PositionType> Diagram<'_, P> {
+    /// svg renders the diagram as a standalone SVG image, with the coordinates
+    /// of the board drawn on its left and bottom edges.
+    #[must_use]
+    pub fn svg(&self) -> String {
+        let width = (File::<P>::N + 1) * SQUARE;
+        let height = (Rank::<P>::N + 1) * SQUARE;
+        let theme = &self.theme;
+
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {width} {height}" width="{width}" height="{height}">"#
+        );
+
+        // Write errors are ignored since writing to a String never fails.
+        let _ = write!(
+            svg,
+            r#"<defs><marker id="arrowhead" viewBox="0 0 4 4" refX="2" refY="2" markerWidth="4" markerHeight="4" orient="auto"><path d="M0,0 L4,2 L0,4 z" fill="{}"/></marker></defs>"#,
+            theme.arrow
+        );
+
+        for (square, file, rank) in self.squares() {
+            let (x, y) = self.corner(file, rank);
+            let fill = if (file + rank).is_multiple_of(2) {
+                theme.dark
+            } else {
+                theme.light
+            };
+
+            let _ = write!(
+                svg,
+                r#"<rect x="{x}" y="{y}" width="{SQUARE}" height="{SQUARE}" fill="{fill}"/>"#
+            );
+
+            if let Some(highlight) = self.highlight(square) {
+                let fill = match highlight {
+                    Highlight::LastMove => theme.last_move,
+                    Highlight::Captured => theme.captured,
+                };
+
+                let _ = write!(
+                    svg,
+                    r#"<rect x="{x}" y="{y}" width="{SQUARE}" height="{SQUARE}" fill="{fill}" fill-opacity="0.8"/>"#
+                );
+            }
+
+            if let Some(piece) = self.piece(square) {
+                self.svg_piece(&mut svg, piece, x, y);
+            }
+
+            // Label the files below the board and the ranks left of it.
+            let (cx, cy) = (x + SQUARE / 2, y + SQUARE / 2);
+            if rank == 0 {
+                let _ = write!(
+                    svg,
+                    r#"<text x="{cx}" y="{}" font-family="sans-serif" font-size="16" text-anchor="middle" fill="{}">{}</text>"#,
+                    height - SQUARE / 6,
+                    theme.outline,
+                    square.file()
+                );
+            }
+
+            if file == 0 {
+                let _ = write!(
+                    svg,
+                    r#"<text x="{}" y="{}" font-family="sans-serif" font-size="16" text-anchor="middle" fill="{}">{}</text>"#,
+                    SQUARE / 4,
+                    cy + 6,
+                    theme.outline,
+                    square.rank()
+                );
+            }
+        }
+
+        for &(from, to) in &self.arrows {
+            self.svg_arrow(&mut svg, from, to);
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+
+    /// corner returns the coordinates of the top-left corner of the square
+    /// with the given file and rank indices.
+    fn corner(&self, file: usize, rank: usize) -> (usize, usize) {
+        (
+            SQUARE / 2 + file * SQUARE,
+            SQUARE / 2 + (Rank::<P>::N - 1 - rank) * SQUARE,
+        )
+    }
+
+    /// center returns the coordinates of the center of the given square.
+    fn center(&self, square: Square<P>) -> (usize, usize) {
+        let (file, rank) = (square.file().into(), square.rank().into());
+        let (x, y) = self.corner(file as usize, rank as usize);
+        (x + SQUARE / 2, y + SQUARE / 2)
+    }
+
+    /// svg_piece draws the given piece on the square with the given top-left
+    /// corner. Pieces of a side are drawn as discs in the side's color, which
+    /// are labelled with the piece unless it is the first piece of the game,
+    /// while colorless pieces like blockers are drawn as squares.
+    fn svg_piece(
+        &self,
+        svg: &mut String,
+        piece: P::ColoredPiece,
+        x: usize,
+        y: usize,
+    ) {
+        let theme = &self.theme;
+        let color = match piece.color() {
+            Some(color) => color.into() as usize,
+            None => {
+                let (inset, side) = (SQUARE / 10, SQUARE - SQUARE / 5);
+                let _ = write!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{side}" height="{side}" fill="{}" stroke="{}"/>"#,
+                    x + inset,
+                    y + inset,
+                    theme.pieces[2],
+                    theme.outline
+                );
+                return;
+            }
+        };
+
+        let (cx, cy) = (x + SQUARE / 2, y + SQUARE / 2);
+        let _ = write!(
+            svg,
+            r#"<circle cx="{cx}" cy="{cy}" r="{}" fill="{}" stroke="{}" stroke-width="2"/>"#,
+            SQUARE * 2 / 5,
+            theme.pieces[color % 2],
+            theme.outline
+        );
+
+        if piece.piece().into() != 0 {
+            let _ = write!(
+                svg,
+                r#"<text x="{cx}" y="{}" font-family="sans-serif" font-size="20" font-weight="bold" text-anchor="middle" fill="{}">{}</text>"#,
+                cy + 7,
+                theme.pieces[(color + 1) % 2],
+                piece.piece()
+            );
+        }
+    }
+
+    /// svg_arrow draws an arrow between the centers of the given squares.
+    fn svg_arrow(&self, svg: &mut String, from: Square<P>, to: Square<P>) {
+        let (x1, y1) = self.center(from);
+        let (x2, y2) = self.center(to);
+        let _ = write!(
+            svg,
+            r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke="{}" stroke-width="{}" stroke-opacity="0.8" marker-end="url(#arrowhead)"/>"#,
+            self.theme.arrow,
+            SQUARE / 8
+        );
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use core::fmt::Write;
+
+use strum::IntoEnumIterator;
+
+use super::{Diagram, Highlight};
+use crate::interface::{
+    ColoredPieceType, File, PositionType, Rank, RepresentableType, SquareType,
+};
+
+// ANSI escape codes for the 256-color backgrounds and foregrounds used by
+// Diagram::ansi, which correspond to the colors of the default Theme.
+const ANSI_LIGHT: &str = "\x1b[48;5;180m";
+const ANSI_DARK: &str = "\x1b[48;5;137m";
+const ANSI_LAST_MOVE: &str = "\x1b[48;5;143m";
+const ANSI_CAPTURED: &str = "\x1b[48;5;167m";
+const ANSI_PIECES: [&str; 3] =
+    ["\x1b[1;38;5;16m", "\x1b[1;38;5;231m", "\x1b[1;38;5;238m"];
+const ANSI_RESET: &str = "\x1b[0m";
+
+impl<P: PositionType> Diagram<'_, P> {
+    /// unicode renders the diagram as plain text framed with Unicode box
+    /// drawing characters, with empty squares drawn as dots. Highlights are
+    /// not shown, so the output can be used where colors aren't available.
+    /// ```
+    /// use tetka_games::ataxx::Position;
+    /// use tetka_games::render::Diagram;
+    /// use core::str::FromStr;
+    ///
+    /// let position = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+    /// assert_eq!(
+    ///     Diagram::new(&position).unicode(),
+    ///     "  ┌───────────────┐\n\
+    ///      7 │ x · · · · · o │\n\
+    ///      6 │ · · · · · · · │\n\
+    ///      5 │ · · · · · · · │\n\
+    ///      4 │ · · · · · · · │\n\
+    ///      3 │ · · · · · · · │\n\
+    ///      2 │ · · · · · · · │\n\
+    ///      1 │ o · · · · · x │\n  \
+    ///        └───────────────┘\n    \
+    ///          a b c d e f g\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn unicode(&self) -> String {
+        let (margin, cell) = (self.rank_label_width(), self.file_label_width());
+        let border = "─".repeat(File::<P>::N * (cell + 1) + 1);
+
+        let mut text = format!("{:margin$} ┌{border}┐\n", "");
+        for (square, file, _) in self.squares() {
+            if file == 0 {
+                let _ =
+                    write!(text, "{:>margin$} │", square.rank().to_string());
+            }
+
+            let _ = match self.piece(square) {
+                Some(piece) => write!(text, " {:^cell$}", piece.to_string()),
+                None => write!(text, " {:^cell$}", "·"),
+            };
+
+            if file == File::<P>::N - 1 {
+                text.push_str(" │\n");
+            }
+        }
+
+        let _ = writeln!(text, "{:margin$} └{border}┘", "");
+        let _ = write!(text, "{:margin$}  ", "");
+        for file in File::<P>::iter() {
+            let _ = write!(text, " {:^cell$}", file.to_string());
+        }
+
+        text.push('\n');
+        text
+    }
+
+    /// ansi renders the diagram as text colored with ANSI escape codes for
+    /// terminals, showing the squares of the last move and the captured
+    /// squares with colored backgrounds.
+    #[must_use]
+    pub fn ansi(&self) -> String {
+        let (margin, cell) = (self.rank_label_width(), self.file_label_width());
+
+        let mut text = String::new();
+        for (square, file, rank) in self.squares() {
+            if file == 0 {
+                let _ = write!(text, "{:>margin$} ", square.rank().to_string());
+            }
+
+            let background = match self.highlight(square) {
+                Some(Highlight::LastMove) => ANSI_LAST_MOVE,
+                Some(Highlight::Captured) => ANSI_CAPTURED,
+                None if (file + rank).is_multiple_of(2) => ANSI_DARK,
+                None => ANSI_LIGHT,
+            };
+
+            let _ = match self.piece(square) {
+                Some(piece) => {
+                    let foreground = match piece.color() {
+                        Some(color) => ANSI_PIECES[color.into() as usize % 2],
+                        None => ANSI_PIECES[2],
+                    };
+
+                    let piece = piece.to_string();
+                    write!(text, "{background}{foreground} {piece:^cell$} ")
+                }
+                None => write!(text, "{background} {:cell$} ", ""),
+            };
+
+            if file == File::<P>::N - 1 {
+                let _ = writeln!(text, "{ANSI_RESET}");
+            }
+        }
+
+        let _ = write!(text, "{:margin$} ", "");
+        for file in File::<P>::iter() {
+            let _ = write!(text, " {:^cell$} ", file.to_string());
+        }
+
+        text.push('\n');
+        text
+    }
+
+    /// rank_label_width returns the width of the widest rank label.
+    fn rank_label_width(&self) -> usize {
+        Rank::<P>::iter()
+            .map(|rank| rank.to_string().chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// file_label_width returns the width of the widest file label, which is
+    /// also the width of the squares, since the labels are placed below them.
+    fn file_label_width(&self) -> usize {
+        File::<P>::iter()
+            .map(|file| file.to_string().chars().count())
+            .max()
+            .unwrap_or(1)
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::str::FromStr;
+
+use crate::ataxx::{self, BitBoard, Square};
+use crate::draughts;
+use crate::mnk::{board3x3, Gomoku, TicTacToe};
+use crate::render::{Diagram, Theme};
+
+const FEN: &str = "x5o/7/3-3/7/7/7/o5x x 0 1";
+
+/// strip_ansi removes the ANSI escape codes from the given text.
+fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut chars = text.chars();
+    while let Some(char) = chars.next() {
+        if char == '\x1b' {
+            chars.by_ref().find(|&char| char == 'm');
+        } else {
+            stripped.push(char);
+        }
+    }
+
+    stripped
+}
+
+#[test]
+fn svg() {
+    let position = ataxx::Position::from_str(FEN).unwrap();
+    let svg = Diagram::new(&position).svg();
+    let theme = Theme::default();
+
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.ends_with("</svg>"));
+    assert!(svg.contains(r#"width="400" height="400""#));
+
+    // 4 stones, 1 block and the 7 file and 7 rank labels.
+    assert_eq!(svg.matches("<circle").count(), 4);
+    assert_eq!(svg.matches(theme.pieces[2]).count(), 1);
+    assert_eq!(svg.matches("<text").count(), 14);
+    assert_eq!(svg.matches(theme.dark).count(), 25);
+    assert_eq!(svg.matches(theme.light).count(), 24);
+
+    assert_eq!(svg.matches(theme.last_move).count(), 0);
+    assert_eq!(svg.matches(theme.captured).count(), 0);
+    assert_eq!(svg.matches("<line").count(), 0);
+}
+
+#[test]
+fn svg_highlights() {
+    let position = ataxx::Position::from_str(FEN).unwrap();
+    let theme = Theme::default();
+
+    let svg = Diagram::new(&position)
+        .last_move(Some(Square::A7), Square::C5)
+        .svg();
+    assert_eq!(svg.matches(theme.last_move).count(), 2);
+
+    let svg = Diagram::new(&position).last_move(None, Square::B6).svg();
+    assert_eq!(svg.matches(theme.last_move).count(), 1);
+
+    // Captured squares take precedence over the squares of the last move.
+    let svg = Diagram::new(&position)
+        .last_move(Some(Square::E3), Square::G1)
+        .captured(BitBoard::from(Square::G1) | Square::F1)
+        .captured([Square::F2])
+        .svg();
+    assert_eq!(svg.matches(theme.last_move).count(), 1);
+    assert_eq!(svg.matches(theme.captured).count(), 3);
+
+    let svg = Diagram::new(&position)
+        .arrow(Square::A7, Square::C5)
+        .arrow(Square::G1, Square::G2)
+        .svg();
+    assert_eq!(svg.matches("<line").count(), 2);
+    assert!(svg.contains(r#"x1="50" y1="50" x2="150" y2="150""#));
+}
+
+#[test]
+fn svg_theme() {
+    let position = ataxx::Position::from_str(FEN).unwrap();
+    let theme = Theme {
+        light: "white",
+        dark: "gray",
+        pieces: ["red", "blue", "green"],
+        ..Theme::default()
+    };
+
+    let svg = Diagram::new(&position).theme(theme).svg();
+    assert_eq!(svg.matches(r#"fill="red""#).count(), 2);
+    assert_eq!(svg.matches(r#"fill="blue""#).count(), 2);
+    assert_eq!(svg.matches(r#"fill="green""#).count(), 1);
+    assert_eq!(svg.matches(r#"fill="gray""#).count(), 25);
+}
+
+#[test]
+fn svg_piece_labels() {
+    // Kings are labelled to tell them apart from men.
+    let position = draughts::Position::from_str("W:WK29,30:B1,K2").unwrap();
+    let svg = Diagram::new(&position).svg();
+
+    assert_eq!(svg.matches("<circle").count(), 4);
+    assert_eq!(svg.matches(">k</text>").count(), 2);
+}
+
+#[test]
+fn unicode() {
+    let position = TicTacToe::from_str("x2/1o1/2x o").unwrap();
+    assert_eq!(
+        Diagram::new(&position).unicode(),
+        "  ┌───────┐\n\
+         3 │ x · · │\n\
+         2 │ · o · │\n\
+         1 │ · · x │\n  \
+           └───────┘\n    \
+             a b c\n"
+    );
+
+    // Highlights are ignored by the Unicode renderer.
+    let highlighted = Diagram::new(&position)
+        .last_move(None, board3x3::Square::C1)
+        .unicode();
+    assert_eq!(highlighted, Diagram::new(&position).unicode());
+
+    // Rank labels are aligned to the widest label.
+    let position =
+        Gomoku::from_str("11/11/11/11/11/11/11/11/11/11/x10 o").unwrap();
+    let unicode = Diagram::new(&position).unicode();
+    let lines = unicode.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 14);
+    assert!(lines[1].starts_with("11 │ · "));
+    assert!(lines[11].starts_with(" 1 │ x "));
+    assert!(lines.iter().take(13).all(|line| line.chars().count() == 28));
+}
+
+#[test]
+fn unicode_wide_files() {
+    // The files of the compressed draughts board are named after two files.
+    let position = draughts::Position::from_str("W:W29:B1").unwrap();
+    let unicode = Diagram::new(&position).unicode();
+    let lines = unicode.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[1], "8 │ b  ·  ·  ·  │");
+    assert_eq!(lines[8], "1 │ w  ·  ·  ·  │");
+    assert_eq!(lines[10], "    ab cd ef gh");
+}
+
+#[test]
+fn ansi() {
+    let position = ataxx::Position::from_str(FEN).unwrap();
+    let plain = Diagram::new(&position).ansi();
+    let highlighted = Diagram::new(&position)
+        .last_move(Some(Square::A7), Square::C5)
+        .captured([Square::G1])
+        .ansi();
+
+    // Every rank is reset at its end.
+    assert_eq!(plain.matches("\x1b[0m").count(), 7);
+    assert_eq!(strip_ansi(&plain), strip_ansi(&highlighted));
+    assert_eq!(
+        strip_ansi(&plain),
+        "7  x                 o \n\
+         6                      \n\
+         5           -          \n\
+         4                      \n\
+         3                      \n\
+         2                      \n\
+         1  o                 x \n   \
+            a  b  c  d  e  f  g \n"
+    );
+
+    assert!(!plain.contains("\x1b[48;5;143m"));
+    assert!(!plain.contains("\x1b[48;5;167m"));
+    assert_eq!(highlighted.matches("\x1b[48;5;143m").count(), 2);
+    assert_eq!(highlighted.matches("\x1b[48;5;167m").count(), 1);
+}
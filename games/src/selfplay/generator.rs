@@ -0,0 +1,351 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::io;
+use std::ops::Not;
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::data::{RecordSink, SelfPlayRecord};
+use crate::interface::{Color, PositionType, Wdl};
+use crate::random::{RandomPositions, Rng};
+use crate::selfplay::{Limits, Searcher};
+
+/// Openings are the positions self-play games are started from.
+#[derive(Clone, Debug)]
+pub enum Openings<P> {
+    /// Random openings are reached by playing a random number of random moves
+    /// in the given range from the root position.
+    Random {
+        root: P,
+        plies: RangeInclusive<usize>,
+    },
+    /// List openings are picked randomly from the given positions, which are
+    /// usually the lines of an opening book.
+    List(Vec<P>),
+}
+
+/// Progress reports the progress of a [`SelfPlay`] run.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    /// games is the number of games finished.
+    pub games: usize,
+    /// failed is the number of games which weren't played since no opening
+    /// could be found for them, like when every random opening is over.
+    pub failed: usize,
+    /// decisive is the number of finished games which weren't drawn.
+    pub decisive: usize,
+    /// positions is the number of positions written to the sink.
+    pub positions: usize,
+    /// duplicates is the number of positions skipped for having the same
+    /// Hash as a position which was already written.
+    pub duplicates: usize,
+    /// elapsed is the time since the start of the run.
+    pub elapsed: Duration,
+}
+
+/// SelfPlay generates training data by playing games with a searcher against
+/// itself. Positions are deduplicated by their Hash over the whole run, and
+/// the positions where the game is over aren't recorded.
+/// ```
+/// use tetka_games::ataxx::Position;
+/// use tetka_games::interface::{BitBoardType, PositionType};
+/// use tetka_games::data::SelfPlayRecord;
+/// use tetka_games::selfplay::*;
+/// use std::str::FromStr;
+///
+/// let root = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+/// let material = |p: &Position| {
+///     let stm = p.color_bb(p.side_to_move).len() as i32;
+///     let xtm = p.color_bb(!p.side_to_move).len() as i32;
+///     100 * (stm - xtm)
+/// };
+///
+/// let mut records: Vec<SelfPlayRecord<Position>> = vec![];
+/// let progress = SelfPlay::new(Openings::Random { root, plies: 2..=4 })
+///     .games(4)
+///     .threads(2)
+///     .limits(Limits::depth(1))
+///     .max_plies(40)
+///     .run(|| AlphaBeta::new(material), &mut records, |_| {})
+///     .unwrap();
+///
+/// assert_eq!(progress.games, 4);
+/// assert_eq!(progress.positions, records.len());
+/// ```
+#[derive(Clone, Debug)]
+pub struct SelfPlay<P> {
+    openings: Openings<P>,
+    games: usize,
+    threads: usize,
+    limits: Limits,
+    seed: u64,
+    max_plies: usize,
+    adjudication: Option<(i32, usize)>,
+}
+
+impl<P> SelfPlay<P>
+where
+    P: PositionType + Clone + Send + Sync,
+    Color<P>: Not<Output = Color<P>>,
+{
+    /// new creates a new SelfPlay which starts its games from the given
+    /// openings. By default, a single game is played on a single thread with a
+    /// depth 1 search, and games are drawn after 400 plies.
+    #[must_use]
+    pub fn new(openings: Openings<P>) -> Self {
+        SelfPlay {
+            openings,
+            games: 1,
+            threads: 1,
+            limits: Limits::depth(1),
+            seed: 0,
+            max_plies: 400,
+            adjudication: None,
+        }
+    }
+
+    /// games sets the number of games to play.
+    #[must_use]
+    pub fn games(mut self, games: usize) -> Self {
+        self.games = games;
+        self
+    }
+
+    /// threads sets the number of threads to play the games on.
+    #[must_use]
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// limits sets the limits of the search for every move.
+    #[must_use]
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// seed sets the seed for the random openings. The opening of every game
+    /// only depends on the seed and the game's number.
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// max_plies sets the number of plies after which a game is drawn.
+    #[must_use]
+    pub fn max_plies(mut self, plies: usize) -> Self {
+        self.max_plies = plies;
+        self
+    }
+
+    /// win_adjudication adjudicates a game as won once the absolute score of
+    /// the search is at least `score` for `moves` consecutive moves, in favour
+    /// of the same side.
+    #[must_use]
+    pub fn win_adjudication(mut self, score: i32, moves: usize) -> Self {
+        self.adjudication = Some((score, moves));
+        self
+    }
+
+    /// run plays all the games, with a new searcher created by `searcher` for
+    /// every thread, and writes the records into `sink`. The `progress`
+    /// function is called after every finished or failed game. The first
+    /// error from the sink stops the run and is returned.
+    pub fn run<S: Searcher<P>>(
+        &self,
+        searcher: impl Fn() -> S + Sync,
+        sink: &mut impl RecordSink<P>,
+        progress: impl Fn(&Progress) + Sync,
+    ) -> io::Result<Progress> {
+        let start = Instant::now();
+        let next_game = AtomicUsize::new(0);
+        let stop = AtomicBool::new(false);
+        let shared = Mutex::new(Shared {
+            sink,
+            seen: HashSet::new(),
+            progress: Progress::default(),
+            error: None,
+        });
+
+        thread::scope(|scope| {
+            for _ in 0..self.threads {
+                scope.spawn(|| {
+                    let mut searcher = searcher();
+                    while !stop.load(Ordering::Relaxed) {
+                        let game = next_game.fetch_add(1, Ordering::Relaxed);
+                        if game >= self.games {
+                            break;
+                        }
+
+                        // Games without an opening are counted as failed, so
+                        // that the number of games requested is accounted for.
+                        let game = self.opening(game).map(|opening| {
+                            searcher.new_game();
+                            self.play(&mut searcher, opening)
+                        });
+
+                        let mut shared = shared.lock().unwrap();
+                        match game {
+                            Some((history, winner)) => {
+                                shared.record(history, winner)
+                            }
+                            None => shared.progress.failed += 1,
+                        }
+
+                        shared.progress.elapsed = start.elapsed();
+                        if shared.error.is_some() {
+                            stop.store(true, Ordering::Relaxed);
+                        } else {
+                            progress(&shared.progress);
+                        }
+                    }
+                });
+            }
+        });
+
+        let shared = shared.into_inner().unwrap();
+        match shared.error {
+            Some(err) => Err(err),
+            None => Ok(shared.progress),
+        }
+    }
+
+    /// opening returns the opening of the given game.
+    fn opening(&self, game: usize) -> Option<P> {
+        let seed = self.seed ^ (game as u64).wrapping_mul(0x9E3779B97F4A7C15);
+        match &self.openings {
+            Openings::Random { root, plies } => {
+                RandomPositions::new(root.clone(), seed)
+                    .plies(plies.clone())
+                    .not_game_over()
+                    .generate()
+            }
+            Openings::List(positions) if !positions.is_empty() => {
+                let mut rng = Rng::new(seed);
+                Some(positions[rng.below(positions.len())].clone())
+            }
+            Openings::List(_) => None,
+        }
+    }
+
+    /// play plays a game from the given opening, and returns the scored
+    /// positions of the game along with its winner.
+    fn play<S: Searcher<P>>(
+        &self,
+        searcher: &mut S,
+        opening: P,
+    ) -> (Vec<(P, i32)>, Option<Color<P>>) {
+        let mut history = vec![];
+        let mut position = opening;
+        let mut streak: Option<(Color<P>, usize)> = None;
+
+        loop {
+            if position.is_game_over() {
+                return (history, position.winner());
+            }
+
+            if history.len() >= self.max_plies {
+                return (history, None);
+            }
+
+            let result = searcher.search(&position, self.limits);
+            history.push((position.clone(), result.score));
+
+            if let Some((threshold, moves)) = self.adjudication {
+                let stm = position.side_to_move();
+                streak = if result.score >= threshold {
+                    Self::extend(streak, stm)
+                } else if result.score <= -threshold {
+                    Self::extend(streak, !stm)
+                } else {
+                    None
+                };
+
+                if let Some((winner, count)) = streak {
+                    if count >= moves {
+                        return (history, Some(winner));
+                    }
+                }
+            }
+
+            position = position.after_move::<true>(result.best_move);
+        }
+    }
+
+    fn extend(
+        streak: Option<(Color<P>, usize)>,
+        winner: Color<P>,
+    ) -> Option<(Color<P>, usize)> {
+        match streak {
+            Some((color, count)) if color == winner => Some((color, count + 1)),
+            _ => Some((winner, 1)),
+        }
+    }
+}
+
+/// Shared is the state of a SelfPlay run which is shared by its threads.
+struct Shared<'a, K> {
+    sink: &'a mut K,
+    seen: HashSet<u64>,
+    progress: Progress,
+    error: Option<io::Error>,
+}
+
+impl<K> Shared<'_, K> {
+    fn record<P: PositionType>(
+        &mut self,
+        history: Vec<(P, i32)>,
+        winner: Option<Color<P>>,
+    ) where
+        K: RecordSink<P>,
+    {
+        self.progress.games += 1;
+        if winner.is_some() {
+            self.progress.decisive += 1;
+        }
+
+        for (position, score) in history {
+            if self.error.is_some() {
+                return;
+            }
+
+            if !self.seen.insert(u64::from(position.hash())) {
+                self.progress.duplicates += 1;
+                continue;
+            }
+
+            let result = match winner {
+                Some(color) if color == position.side_to_move() => Wdl::Win,
+                Some(_) => Wdl::Loss,
+                None => Wdl::Draw,
+            };
+
+            let record = SelfPlayRecord {
+                position,
+                score,
+                result,
+            };
+            match self.sink.write_record(&record) {
+                Ok(()) => self.progress.positions += 1,
+                Err(err) => self.error = Some(err),
+            }
+        }
+    }
+}
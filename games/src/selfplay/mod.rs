@@ -0,0 +1,36 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Self-play data generation.
+//!
+//! Evaluation networks are usually trained on positions from games an engine
+//! plays against itself. [`SelfPlay`] plays such games over multiple threads,
+//! starting from random or given openings, with any [`Searcher`] picking the
+//! moves, and writes the scored positions along with the final results of
+//! their games into a [`RecordSink`](crate::data::RecordSink).
+//!
+//! A simple depth- and node-limited [`AlphaBeta`] searcher, which only needs
+//! an evaluation function, is also provided for games without an engine.
+
+// Make the contents of the non-namespaced
+// modules public, so they can be accessed
+// without their parent namespace.
+pub use self::generator::*;
+pub use self::search::*;
+
+// Non-namespaced modules.
+mod generator;
+mod search;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,221 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::marker::PhantomData;
+
+use crate::interface::{Move, MoveType, PositionType, Wdl};
+
+/// MATE is the score of a position where the side to move has won the game.
+/// Wins which take more plies are scored lower, as `MATE - ply`.
+pub const MATE: i32 = 30000;
+
+/// Limits are the limits of a search. A search stops after either reaching
+/// the maximum depth or searching the maximum number of nodes, but always
+/// completes at least a depth 1 search.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// depth is the maximum depth to search to.
+    pub depth: Option<u8>,
+    /// nodes is the maximum number of nodes to search.
+    pub nodes: Option<u64>,
+}
+
+impl Limits {
+    /// depth creates new Limits which search to the given depth.
+    #[must_use]
+    pub fn depth(depth: u8) -> Self {
+        Limits {
+            depth: Some(depth),
+            nodes: None,
+        }
+    }
+
+    /// nodes creates new Limits which search the given number of nodes.
+    #[must_use]
+    pub fn nodes(nodes: u64) -> Self {
+        Limits {
+            depth: None,
+            nodes: Some(nodes),
+        }
+    }
+}
+
+/// SearchResult is the result of a search.
+#[derive(Copy, Clone, Debug)]
+pub struct SearchResult<M> {
+    /// best_move is the best move found by the search.
+    pub best_move: M,
+    /// score is the score of the position for the side to move.
+    pub score: i32,
+    /// depth is the depth of the last completed iteration of the search.
+    pub depth: u8,
+    /// nodes is the number of nodes searched.
+    pub nodes: u64,
+}
+
+/// Searcher is the interface of the searches used to play games.
+pub trait Searcher<P: PositionType> {
+    /// search searches the given position within the given limits. It should
+    /// only be called on positions where the game isn't over.
+    fn search(&mut self, position: &P, limits: Limits)
+        -> SearchResult<Move<P>>;
+
+    /// new_game is called before every new game, so that the Searcher can
+    /// clear any state which shouldn't carry over to it.
+    fn new_game(&mut self) {}
+}
+
+/// AlphaBeta is a basic iterative deepening alpha-beta searcher, which scores
+/// the leaves of the search with the given evaluation function. Evaluations
+/// are from the point of view of the side to move, and should stay well within
+/// the range of `-MATE..MATE`.
+/// ```
+/// use tetka_games::mnk::TicTacToe;
+/// use tetka_games::selfplay::{AlphaBeta, Limits, Searcher};
+/// use std::str::FromStr;
+///
+/// let position = TicTacToe::from_str("3/3/3 x").unwrap();
+/// let mut searcher = AlphaBeta::new(|_: &TicTacToe| 0);
+///
+/// // Tic-tac-toe is a draw with perfect play.
+/// assert_eq!(searcher.search(&position, Limits::depth(9)).score, 0);
+/// ```
+pub struct AlphaBeta<P, E> {
+    eval: E,
+    nodes: u64,
+    max_nodes: u64,
+    stopped: bool,
+    phantom: PhantomData<fn(&P)>,
+}
+
+impl<P: PositionType, E: Fn(&P) -> i32> AlphaBeta<P, E> {
+    /// new creates a new AlphaBeta searcher with the given evaluation.
+    pub fn new(eval: E) -> Self {
+        AlphaBeta {
+            eval,
+            nodes: 0,
+            max_nodes: u64::MAX,
+            stopped: false,
+            phantom: PhantomData,
+        }
+    }
+
+    fn negamax(
+        &mut self,
+        position: &P,
+        depth: u8,
+        ply: i32,
+        mut alpha: i32,
+        beta: i32,
+    ) -> i32 {
+        self.nodes += 1;
+        if self.nodes >= self.max_nodes {
+            self.stopped = true;
+            return 0;
+        }
+
+        match position.result() {
+            Some(Wdl::Win) => return MATE - ply,
+            Some(Wdl::Loss) => return -(MATE - ply),
+            Some(Wdl::Draw) => return 0,
+            None => {}
+        }
+
+        if depth == 0 {
+            return (self.eval)(position);
+        }
+
+        let mut best = -MATE;
+        for &mov in position.generate_moves::<false, true, true>().iter() {
+            let child = position.after_move::<false>(mov);
+            let score =
+                -self.negamax(&child, depth - 1, ply + 1, -beta, -alpha);
+            if self.stopped {
+                return 0;
+            }
+
+            best = best.max(score);
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        best
+    }
+}
+
+impl<P: PositionType, E: Fn(&P) -> i32> Searcher<P> for AlphaBeta<P, E> {
+    fn search(
+        &mut self,
+        position: &P,
+        limits: Limits,
+    ) -> SearchResult<Move<P>> {
+        let max_depth = match (limits.depth, limits.nodes) {
+            (Some(depth), _) => depth.max(1),
+            (None, Some(_)) => u8::MAX,
+            (None, None) => 1,
+        };
+
+        self.nodes = 0;
+        self.stopped = false;
+
+        let moves = position.generate_moves::<false, true, true>();
+        let mut result = SearchResult {
+            best_move: moves.first().copied().unwrap_or(Move::<P>::NULL),
+            score: 0,
+            depth: 0,
+            nodes: 0,
+        };
+
+        for depth in 1..=max_depth {
+            // The first iteration is always completed, so that there is a
+            // best move to return.
+            self.max_nodes = match limits.nodes {
+                Some(nodes) if depth > 1 => nodes,
+                _ => u64::MAX,
+            };
+
+            let mut alpha = -MATE;
+            let mut best_move = result.best_move;
+            for &mov in moves.iter() {
+                let child = position.after_move::<false>(mov);
+                let score = -self.negamax(&child, depth - 1, 1, -MATE, -alpha);
+                if self.stopped {
+                    break;
+                }
+
+                if score > alpha {
+                    alpha = score;
+                    best_move = mov;
+                }
+            }
+
+            if self.stopped {
+                break;
+            }
+
+            result.best_move = best_move;
+            result.score = alpha;
+            result.depth = depth;
+
+            // Stop searching once a forced result has been found.
+            if alpha.abs() >= MATE - depth as i32 {
+                break;
+            }
+        }
+
+        result.nodes = self.nodes;
+        result
+    }
+}
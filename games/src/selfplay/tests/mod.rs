@@ -0,0 +1,180 @@
+use crate::ataxx::training::{RecordWriter, TrainingRecord};
+use crate::ataxx::Position;
+use crate::data::SelfPlayRecord;
+use crate::interface::{BitBoardType, PositionType, Wdl};
+use crate::selfplay::*;
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const STARTPOS: &str = "x5o/7/7/7/7/7/o5x x 0 1";
+
+fn material(position: &Position) -> i32 {
+    let stm = position.color_bb(position.side_to_move).len() as i32;
+    let xtm = position.color_bb(!position.side_to_move).len() as i32;
+    100 * (stm - xtm)
+}
+
+fn random_openings() -> Openings<Position> {
+    Openings::Random {
+        root: Position::from_str(STARTPOS).unwrap(),
+        plies: 2..=6,
+    }
+}
+
+#[test]
+fn alpha_beta_finds_wins() {
+    // Black wins by filling the last empty square of the 2x2 board.
+    let position = Position::from_str(
+        "xo-----/o1-----/-------/-------/-------/-------/------- x 0 1",
+    )
+    .unwrap();
+    let mut searcher = AlphaBeta::new(material);
+    let result = searcher.search(&position, Limits::depth(4));
+
+    assert_eq!(result.score, MATE - 1);
+    assert!(position.after_move::<true>(result.best_move).is_game_over());
+}
+
+#[test]
+fn alpha_beta_respects_node_limit() {
+    let position = Position::from_str(STARTPOS).unwrap();
+    let mut searcher = AlphaBeta::new(material);
+
+    let limited = searcher.search(&position, Limits::nodes(5_000));
+    let deeper = searcher.search(&position, Limits::depth(limited.depth + 1));
+
+    assert!(limited.depth >= 1);
+    assert!(deeper.nodes > limited.nodes);
+    assert!(position.is_legal(limited.best_move));
+}
+
+#[test]
+fn self_play_runs_all_games() {
+    let mut records: Vec<SelfPlayRecord<Position>> = vec![];
+    let reports = AtomicUsize::new(0);
+    let progress = SelfPlay::new(random_openings())
+        .games(12)
+        .threads(4)
+        .seed(42)
+        .max_plies(60)
+        .run(
+            || AlphaBeta::new(material),
+            &mut records,
+            |_| {
+                reports.fetch_add(1, Ordering::Relaxed);
+            },
+        )
+        .unwrap();
+
+    assert_eq!(progress.games, 12);
+    assert_eq!(reports.load(Ordering::Relaxed), 12);
+    assert_eq!(progress.positions, records.len());
+
+    // Every recorded position should be unique and not game over.
+    let mut seen = HashSet::new();
+    for record in &records {
+        assert!(seen.insert(u64::from(record.position.hash())));
+        assert!(!record.position.is_game_over());
+    }
+}
+
+#[test]
+fn self_play_max_plies_draws() {
+    let openings = vec![Position::from_str(STARTPOS).unwrap()];
+    let mut records: Vec<SelfPlayRecord<Position>> = vec![];
+    let progress = SelfPlay::new(Openings::List(openings))
+        .games(3)
+        .max_plies(4)
+        .run(|| AlphaBeta::new(material), &mut records, |_| {})
+        .unwrap();
+
+    assert_eq!(progress.decisive, 0);
+    assert_eq!(records.len(), 4);
+    assert!(records.iter().all(|record| record.result == Wdl::Draw));
+}
+
+#[test]
+fn self_play_adjudication() {
+    let mut records: Vec<SelfPlayRecord<Position>> = vec![];
+    let progress = SelfPlay::new(random_openings())
+        .games(4)
+        .win_adjudication(0, 1)
+        .run(|| AlphaBeta::new(material), &mut records, |_| {})
+        .unwrap();
+
+    // Every score passes the threshold for one of the sides, so games are
+    // adjudicated after a single move, in favour of the side it prefers.
+    assert_eq!(progress.decisive, 4);
+    assert_eq!(records.len() + progress.duplicates, 4);
+    for record in &records {
+        let expected = if record.score >= 0 {
+            Wdl::Win
+        } else {
+            Wdl::Loss
+        };
+        assert_eq!(record.result, expected);
+    }
+}
+
+#[test]
+fn self_play_book_openings() {
+    let openings = vec![Position::from_str(STARTPOS).unwrap()];
+    let mut records: Vec<SelfPlayRecord<Position>> = vec![];
+    let progress = SelfPlay::new(Openings::List(openings))
+        .games(3)
+        .max_plies(10)
+        .run(|| AlphaBeta::new(material), &mut records, |_| {})
+        .unwrap();
+
+    // The searcher is deterministic, so all games after the first are
+    // duplicates of it.
+    assert_eq!(progress.games, 3);
+    assert_eq!(progress.positions, 10);
+    assert_eq!(progress.duplicates, 20);
+}
+
+#[test]
+fn self_play_failed_openings() {
+    // Random openings can't be found from a position where the game is over.
+    let root = Position::from_str("7/7/7/7/7/7/x6 o 0 1").unwrap();
+    let called = AtomicUsize::new(0);
+    let mut records: Vec<SelfPlayRecord<Position>> = vec![];
+    let progress = SelfPlay::new(Openings::Random { root, plies: 0..=2 })
+        .games(3)
+        .threads(2)
+        .run(
+            || AlphaBeta::new(material),
+            &mut records,
+            |_| {
+                called.fetch_add(1, Ordering::Relaxed);
+            },
+        )
+        .unwrap();
+
+    assert_eq!(progress.games, 0);
+    assert_eq!(progress.failed, 3);
+    assert_eq!(called.load(Ordering::Relaxed), 3);
+    assert!(records.is_empty());
+
+    let progress = SelfPlay::new(Openings::List(vec![]))
+        .games(2)
+        .run(|| AlphaBeta::new(material), &mut records, |_| {})
+        .unwrap();
+
+    assert_eq!((progress.games, progress.failed), (0, 2));
+}
+
+#[test]
+fn self_play_training_records() {
+    let mut writer = RecordWriter::new(vec![]);
+    let progress = SelfPlay::new(random_openings())
+        .games(2)
+        .max_plies(20)
+        .run(|| AlphaBeta::new(material), &mut writer, |_| {})
+        .unwrap();
+
+    assert_eq!(writer.written(), progress.positions);
+    let bytes = writer.into_inner().unwrap();
+    assert_eq!(bytes.len(), progress.positions * TrainingRecord::SIZE);
+}
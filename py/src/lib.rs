@@ -0,0 +1,379 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Python bindings for the ataxx implementation of tetka-games.
+//!
+//! The bindings are built into the `tetka` Python module with maturin:
+//! ```python
+//! import tetka
+//!
+//! position = tetka.Position()
+//! for mov in position.legal_moves():
+//!     print(mov, position.after_move(mov).perft(2))
+//! ```
+//! Moves can be passed to every function as either a `Move` or a move string,
+//! and invalid FENs, moves and colors raise a `ValueError`.
+
+use std::str::FromStr;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use tetka_games::ataxx::training::{self, FEATURES};
+use tetka_games::ataxx::{Color, Move, Position};
+use tetka_games::interface::{PositionType, Wdl};
+
+/// STARTPOS is the FEN of the standard ataxx starting position.
+const STARTPOS: &str = "x5o/7/7/7/7/7/o5x x 0 1";
+
+/// value_error converts the given error into a Python ValueError.
+fn value_error(err: impl ToString) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+/// An ataxx move, which is created from its move string like `Move("a1c3")`.
+#[pyclass(name = "Move", module = "tetka", frozen, eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct PyMove(Move);
+
+#[pymethods]
+impl PyMove {
+    #[new]
+    fn new(mov: &str) -> PyResult<Self> {
+        Move::from_str(mov).map(PyMove).map_err(value_error)
+    }
+
+    /// The move which passes the turn to the other side.
+    #[classattr]
+    #[allow(non_snake_case)]
+    fn PASS() -> Self {
+        PyMove(Move::PASS)
+    }
+
+    /// The source square of a jump, or None for single moves and passes.
+    #[getter]
+    fn source(&self) -> Option<String> {
+        (self.0 != Move::PASS && !self.0.is_single()).then(|| self.0.source().to_string())
+    }
+
+    /// The target square of the move, or None for passes.
+    #[getter]
+    fn target(&self) -> Option<String> {
+        (self.0 != Move::PASS).then(|| self.0.target().to_string())
+    }
+
+    /// Returns True if the move passes the turn to the other side.
+    fn is_pass(&self) -> bool {
+        self.0 == Move::PASS
+    }
+
+    /// Returns True if the move clones a piece to an adjacent square.
+    fn is_single(&self) -> bool {
+        self.0 != Move::PASS && self.0.is_single()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Move('{}')", self.0)
+    }
+
+    fn __hash__(&self) -> u64 {
+        u16::from(self.0).into()
+    }
+}
+
+/// MoveArg is a move passed from Python, as a Move or as a move string.
+#[derive(FromPyObject)]
+enum MoveArg {
+    Move(PyMove),
+    String(String),
+}
+
+/// An ataxx position, which is created from its FEN, or from the starting
+/// position if no FEN is given.
+#[pyclass(name = "Position", module = "tetka")]
+#[derive(Clone)]
+struct PyPosition(Position);
+
+impl PyPosition {
+    /// parse_move parses the given move and checks that it is legal.
+    fn parse_move(&self, mov: MoveArg) -> PyResult<Move> {
+        match mov {
+            MoveArg::Move(mov) => {
+                self.0.check_move(mov.0).map_err(value_error)?;
+                Ok(mov.0)
+            }
+            MoveArg::String(mov) => self.0.parse_move(&mov).map_err(value_error),
+        }
+    }
+}
+
+#[pymethods]
+impl PyPosition {
+    #[new]
+    #[pyo3(signature = (fen = STARTPOS))]
+    fn new(fen: &str) -> PyResult<Self> {
+        Position::from_str(fen).map(PyPosition).map_err(value_error)
+    }
+
+    /// Returns the FEN of the position.
+    fn fen(&self) -> String {
+        self.0.fen()
+    }
+
+    /// The side to move, "x" for black or "o" for white.
+    #[getter]
+    fn side_to_move(&self) -> String {
+        self.0.side_to_move().to_string()
+    }
+
+    /// Returns the hash of the position.
+    fn hash(&self) -> u64 {
+        self.0.hash().into()
+    }
+
+    /// Returns a list of the legal moves in the position.
+    fn legal_moves(&self) -> Vec<PyMove> {
+        let moves = self.0.generate_moves::<false, true, true>();
+        moves.iter().map(|&mov| PyMove(mov)).collect()
+    }
+
+    /// Returns True if the given move is legal in the position.
+    fn is_legal(&self, mov: MoveArg) -> bool {
+        self.parse_move(mov).is_ok()
+    }
+
+    /// Plays the given move on the position, raising a ValueError without
+    /// changing the position if the move is illegal.
+    fn play(&mut self, mov: MoveArg) -> PyResult<()> {
+        let mov = self.parse_move(mov)?;
+        self.0 = self.0.after_move::<true>(mov);
+        Ok(())
+    }
+
+    /// Returns the position reached after playing the given move, raising a
+    /// ValueError if the move is illegal.
+    fn after_move(&self, mov: MoveArg) -> PyResult<Self> {
+        let mov = self.parse_move(mov)?;
+        Ok(PyPosition(self.0.after_move::<true>(mov)))
+    }
+
+    /// Returns the number of leaf nodes in the move generation tree of the
+    /// given depth from the position.
+    fn perft(&self, py: Python<'_>, depth: u8) -> u64 {
+        let position = self.0;
+        py.allow_threads(|| tetka_games::perft::<false, true, _>(position, depth))
+    }
+
+    /// Returns True if the game is over in the position.
+    fn is_game_over(&self) -> bool {
+        self.0.is_game_over()
+    }
+
+    /// Returns the side which has won the game, "x" or "o", or None if the
+    /// game is drawn or isn't over.
+    fn winner(&self) -> Option<String> {
+        // The winner of an ataxx position is only defined once it is over.
+        if !self.0.is_game_over() {
+            return None;
+        }
+
+        self.0.winner().map(|color| color.to_string())
+    }
+
+    /// Returns the result of the game for the side to move, "win", "draw" or
+    /// "loss", or None if the game isn't over.
+    fn result(&self) -> Option<&'static str> {
+        self.0.result().map(wdl_to_str)
+    }
+
+    fn copy(&self) -> Self {
+        self.clone()
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0.fen() == other.0.fen()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Position('{}')", self.0.fen())
+    }
+}
+
+/// A position labelled with its search score and the final result of the game
+/// it was played in, which is encoded into bytes for training networks.
+#[pyclass(name = "TrainingRecord", module = "tetka", get_all, set_all)]
+#[derive(Clone)]
+struct PyTrainingRecord {
+    /// The labelled position.
+    position: PyPosition,
+    /// The search score of the position for the side to move.
+    score: i16,
+    /// The final result of the game for the side to move.
+    result: String,
+}
+
+impl PyTrainingRecord {
+    fn to_record(&self) -> PyResult<training::TrainingRecord> {
+        Ok(training::TrainingRecord {
+            position: self.position.0,
+            score: self.score,
+            result: str_to_wdl(&self.result)?,
+        })
+    }
+
+    fn from_record(record: training::TrainingRecord) -> Self {
+        PyTrainingRecord {
+            position: PyPosition(record.position),
+            score: record.score,
+            result: wdl_to_str(record.result).to_string(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyTrainingRecord {
+    #[new]
+    fn new(position: PyPosition, score: i16, result: String) -> PyResult<Self> {
+        str_to_wdl(&result)?;
+        Ok(PyTrainingRecord {
+            position,
+            score,
+            result,
+        })
+    }
+
+    /// The number of bytes taken by an encoded record.
+    #[classattr]
+    const SIZE: usize = training::TrainingRecord::SIZE;
+
+    /// Returns the encoded bytes of the record.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        Ok(PyBytes::new(py, &self.to_record()?.to_bytes()))
+    }
+
+    /// Decodes a record from its encoded bytes.
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        let bytes = bytes.try_into().map_err(|_| {
+            value_error(format!(
+                "expected {} bytes, found {}",
+                training::TrainingRecord::SIZE,
+                bytes.len()
+            ))
+        })?;
+
+        training::TrainingRecord::from_bytes(bytes)
+            .map(Self::from_record)
+            .map_err(value_error)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TrainingRecord(Position('{}'), {}, '{}')",
+            self.position.0.fen(),
+            self.score,
+            self.result
+        )
+    }
+}
+
+/// Encodes the given records into the bytes of a training data file.
+#[pyfunction]
+fn encode_records<'py>(
+    py: Python<'py>,
+    records: Vec<PyRef<'py, PyTrainingRecord>>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let mut bytes = Vec::with_capacity(records.len() * training::TrainingRecord::SIZE);
+    for record in records {
+        bytes.extend_from_slice(&record.to_record()?.to_bytes());
+    }
+
+    Ok(PyBytes::new(py, &bytes))
+}
+
+/// Decodes the records in the given bytes of a training data file.
+#[pyfunction]
+fn decode_records(bytes: &[u8]) -> PyResult<Vec<PyTrainingRecord>> {
+    let size = training::TrainingRecord::SIZE;
+    if !bytes.len().is_multiple_of(size) {
+        return Err(value_error(format!(
+            "record ends after {} of {} bytes",
+            bytes.len() % size,
+            size
+        )));
+    }
+
+    bytes
+        .chunks_exact(size)
+        .map(PyTrainingRecord::from_bytes)
+        .collect()
+}
+
+/// Returns the network input feature indices of the given position from the
+/// perspective of the given side, "x" or "o", which defaults to the side to
+/// move. Every index is less than FEATURES.
+#[pyfunction]
+#[pyo3(signature = (position, perspective = None))]
+fn feature_indices(position: &PyPosition, perspective: Option<&str>) -> PyResult<Vec<usize>> {
+    let perspective = match perspective {
+        Some(color) => Color::from_str(color).map_err(value_error)?,
+        None => position.0.side_to_move(),
+    };
+
+    Ok(training::feature_indices(&position.0, perspective))
+}
+
+fn wdl_to_str(wdl: Wdl) -> &'static str {
+    match wdl {
+        Wdl::Win => "win",
+        Wdl::Draw => "draw",
+        Wdl::Loss => "loss",
+    }
+}
+
+fn str_to_wdl(wdl: &str) -> PyResult<Wdl> {
+    match wdl {
+        "win" => Ok(Wdl::Win),
+        "draw" => Ok(Wdl::Draw),
+        "loss" => Ok(Wdl::Loss),
+        _ => Err(value_error(format!("invalid result '{}'", wdl))),
+    }
+}
+
+/// The tetka Python module.
+#[pymodule]
+#[pyo3(name = "tetka")]
+fn tetka_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("STARTPOS", STARTPOS)?;
+    m.add("FEATURES", FEATURES)?;
+    m.add_class::<PyMove>()?;
+    m.add_class::<PyPosition>()?;
+    m.add_class::<PyTrainingRecord>()?;
+    m.add_function(wrap_pyfunction!(feature_indices, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_records, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_records, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,166 @@
+# Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+# http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+import pytest
+
+import tetka
+
+
+# The perft numbers are the same ones the Rust tests check against, which were
+# taken from libataxx.
+@pytest.mark.parametrize(
+    "fen, depth, nodes",
+    [
+        ("x5o/7/7/7/7/7/o5x x 0 1", 1, 16),
+        ("x5o/7/7/7/7/7/o5x x 0 1", 2, 256),
+        ("x5o/7/7/7/7/7/o5x x 0 1", 3, 6460),
+        ("x5o/7/7/7/7/7/o5x x 0 1", 4, 155888),
+        ("7/7/7/7/7/7/7 x 0 1", 4, 0),
+        ("x5o/7/2-1-2/7/2-1-2/7/o5x x 0 1", 5, 2266352),
+        ("7/7/7/7/ooooooo/ooooooo/xxxxxxx o 0 1", 4, 452980),
+        ("7/7/7/7/xxxxxxx/xxxxxxx/ooooooo x 0 1", 4, 452980),
+        ("x5o/7/7/7/7/7/o5x x 100 1", 5, 0),
+        ("7/7/7/7/-------/-------/x5o x 0 1", 6, 175),
+    ],
+)
+def test_perft(fen, depth, nodes):
+    assert tetka.Position(fen).perft(depth) == nodes
+
+
+def test_fen():
+    position = tetka.Position()
+    assert position.fen() == tetka.STARTPOS
+    assert repr(position) == f"Position('{tetka.STARTPOS}')"
+
+    fen = "x5o/7/2-1-2/7/2-1-2/7/o5x o 12 34"
+    assert tetka.Position(fen).fen() == fen
+    assert tetka.Position(fen) == tetka.Position(fen)
+    assert tetka.Position(fen) != position
+
+    for fen in ["", "x5o/7/7 x 0 1", "x5o/7/7/7/7/7/o5x y 0 1"]:
+        with pytest.raises(ValueError):
+            tetka.Position(fen)
+
+
+def test_moves():
+    mov = tetka.Move("a1c3")
+    assert str(mov) == "a1c3"
+    assert repr(mov) == "Move('a1c3')"
+    assert (mov.source, mov.target) == ("a1", "c3")
+    assert not mov.is_single()
+
+    single = tetka.Move("g2")
+    assert (single.source, single.target) == (None, "g2")
+    assert single.is_single()
+
+    assert tetka.Move("0000") == tetka.Move.PASS
+    assert tetka.Move.PASS.is_pass()
+    assert tetka.Move.PASS.target is None
+
+    assert len({tetka.Move("g2"), tetka.Move("g2"), mov}) == 2
+
+    with pytest.raises(ValueError):
+        tetka.Move("z9")
+
+
+def test_legal_moves():
+    position = tetka.Position()
+    moves = position.legal_moves()
+    assert len(moves) == 16
+    assert tetka.Move("g2") in moves
+    assert tetka.Move("a7c5") in moves
+
+    assert position.is_legal("g2")
+    assert position.is_legal(tetka.Move("g1e3"))
+    assert not position.is_legal("a7")
+    assert not position.is_legal("0000")
+
+
+def test_play():
+    position = tetka.Position()
+    hash = position.hash()
+    assert position.side_to_move == "x"
+
+    after = position.after_move("g2")
+    assert after.side_to_move == "o"
+    assert position.side_to_move == "x"
+
+    position.play(tetka.Move("g2"))
+    assert position == after
+    assert position.hash() == after.hash() != hash
+
+    # Illegal moves don't change the position.
+    for mov in ["a7", "d4", "0000", "z9"]:
+        with pytest.raises(ValueError):
+            position.play(mov)
+    assert position == after
+
+    copy = position.copy()
+    copy.play("a2")
+    assert copy != position
+
+
+def test_outcome():
+    position = tetka.Position()
+    assert not position.is_game_over()
+    assert position.winner() is None
+    assert position.result() is None
+
+    position = tetka.Position("xx5/7/7/7/7/7/7 o 0 1")
+    assert position.is_game_over()
+    assert position.winner() == "x"
+    assert position.result() == "loss"
+    assert position.legal_moves() == []
+
+    position = tetka.Position("x5o/7/7/7/7/7/o5x x 100 1")
+    assert position.is_game_over()
+    assert position.winner() is None
+    assert position.result() == "draw"
+
+
+def test_training_records():
+    position = tetka.Position("x5o/7/2-1-2/7/2-1-2/7/o5x o 0 1")
+    record = tetka.TrainingRecord(position, -123, "win")
+    data = record.to_bytes()
+    assert len(data) == tetka.TrainingRecord.SIZE
+
+    decoded = tetka.TrainingRecord.from_bytes(data)
+    assert decoded.position == position
+    assert (decoded.score, decoded.result) == (-123, "win")
+
+    records = [record, tetka.TrainingRecord(tetka.Position(), 50, "draw")]
+    data = tetka.encode_records(records)
+    assert len(data) == 2 * tetka.TrainingRecord.SIZE
+    decoded = tetka.decode_records(data)
+    assert [r.result for r in decoded] == ["win", "draw"]
+    assert decoded[1].position == tetka.Position()
+
+    with pytest.raises(ValueError):
+        tetka.TrainingRecord(position, 0, "lost")
+    with pytest.raises(ValueError):
+        tetka.decode_records(data[:-1])
+    with pytest.raises(ValueError):
+        tetka.TrainingRecord.from_bytes(bytes(tetka.TrainingRecord.SIZE - 1))
+
+
+def test_feature_indices():
+    position = tetka.Position("x5o/7/7/7/7/6x/o5x x 0 1")
+    features = tetka.feature_indices(position)
+    assert len(features) == 5
+    assert all(0 <= feature < tetka.FEATURES for feature in features)
+
+    assert sorted(tetka.feature_indices(position, "x")) == sorted(features)
+    assert sorted(tetka.feature_indices(position, "o")) != sorted(features)
+
+    with pytest.raises(ValueError):
+        tetka.feature_indices(position, "y")
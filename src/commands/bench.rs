@@ -0,0 +1,63 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::process::ExitCode;
+use std::time::Instant;
+
+use tetka::games::dynamic::Registry;
+
+use super::perft::nps;
+use super::{BenchArgs, Result};
+use crate::game::bench_depth;
+
+/// bench measures the speed of the move generators of the given games, by
+/// running a bulk-counted perft on their starting positions.
+pub fn bench(args: &BenchArgs) -> Result<ExitCode> {
+    let registry = Registry::default();
+    let games = if args.games.is_empty() {
+        registry.names().map(String::from).collect()
+    } else {
+        args.games.clone()
+    };
+
+    let (mut total_nodes, mut total_seconds) = (0, 0.0);
+    for game in &games {
+        let depth = args.depth.unwrap_or(bench_depth(game));
+        let position = registry.start(game)?;
+
+        let start = Instant::now();
+        let nodes = position.perft(depth);
+        let seconds = start.elapsed().as_secs_f64();
+
+        println!(
+            "{:<18} depth {:>2} {:>12} nodes {:>8} ms {:>12} nps",
+            game,
+            depth,
+            nodes,
+            (seconds * 1000.0) as u64,
+            nps(nodes, seconds)
+        );
+
+        total_nodes += nodes;
+        total_seconds += seconds;
+    }
+
+    println!();
+    println!(
+        "{} nodes {} nps",
+        total_nodes,
+        nps(total_nodes, total_seconds)
+    );
+
+    Ok(ExitCode::SUCCESS)
+}
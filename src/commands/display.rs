@@ -0,0 +1,46 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::process::ExitCode;
+
+use tetka::games::dynamic::DynPosition;
+
+use super::{PositionArgs, Result};
+use crate::game::parse_position;
+
+/// display prints the position selected by the given arguments, along with
+/// some information about it.
+pub fn display(args: &PositionArgs) -> Result<ExitCode> {
+    let position = parse_position(&args.game, args.fen.as_deref())?;
+
+    println!("{}", position);
+    print_info(&*position);
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// print_info prints information about the given position, which is shown
+/// below it by the display and play commands.
+pub fn print_info(position: &dyn DynPosition) {
+    println!("FEN: {}", position.fen());
+    println!("Hash: {:016x}", u64::from(position.hash()));
+
+    if position.is_game_over() {
+        match position.winner() {
+            Some(winner) => println!("Result: {} wins", winner),
+            None => println!("Result: draw"),
+        }
+    } else {
+        println!("Legal moves: {}", position.legal_moves().len());
+    }
+}
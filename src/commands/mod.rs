@@ -0,0 +1,106 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, Subcommand};
+
+use crate::game::game_parser;
+
+mod bench;
+mod display;
+mod perft;
+mod play;
+mod validate;
+
+/// Result is the result type returned by the commands.
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// Command is one of the subcommands of the tetka binary.
+#[derive(Subcommand)]
+pub enum Command {
+    /// Count the leaf nodes of the move generation tree of a position.
+    Perft(PerftArgs),
+    /// Count the leaf nodes of the move generation tree for each move.
+    Divide(PerftArgs),
+    /// Display a position along with some information about it.
+    Display(PositionArgs),
+    /// Play moves interactively, checking them against the rules.
+    Play(PositionArgs),
+    /// Validate files containing one FEN on every line.
+    Validate(ValidateArgs),
+    /// Benchmark the speed of the move generators.
+    Bench(BenchArgs),
+}
+
+impl Command {
+    /// run runs the Command and returns the exit code of the binary.
+    pub fn run(self) -> Result<ExitCode> {
+        match self {
+            Command::Perft(args) => perft::perft(&args, false),
+            Command::Divide(args) => perft::perft(&args, true),
+            Command::Display(args) => display::display(&args),
+            Command::Play(args) => play::play(&args),
+            Command::Validate(args) => validate::validate(&args),
+            Command::Bench(args) => bench::bench(&args),
+        }
+    }
+}
+
+/// PositionArgs are the arguments which select a position of a game.
+#[derive(Args)]
+pub struct PositionArgs {
+    /// The game the position belongs to.
+    #[arg(value_parser = game_parser())]
+    pub game: String,
+    /// The FEN of the position, the starting position if not provided.
+    #[arg(long)]
+    pub fen: Option<String>,
+}
+
+#[derive(Args)]
+pub struct PerftArgs {
+    #[command(flatten)]
+    pub position: PositionArgs,
+    /// The depth of the move generation tree to walk.
+    pub depth: u8,
+    /// Disable bulk-counting of the leaf nodes.
+    #[arg(long)]
+    pub no_bulk: bool,
+}
+
+#[derive(Args)]
+pub struct ValidateArgs {
+    /// The game the FENs belong to.
+    #[arg(value_parser = game_parser())]
+    pub game: String,
+    /// The files to validate. Empty lines and lines starting with a '#' are
+    /// ignored.
+    #[arg(required = true)]
+    pub files: Vec<PathBuf>,
+    /// Only report the number of valid and invalid FENs of every file.
+    #[arg(long, short)]
+    pub quiet: bool,
+}
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// The games to benchmark, all of them if not provided.
+    #[arg(value_parser = game_parser())]
+    pub games: Vec<String>,
+    /// The perft depth to benchmark with, instead of the game's default.
+    #[arg(long)]
+    pub depth: Option<u8>,
+}
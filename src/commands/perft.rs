@@ -0,0 +1,82 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::process::ExitCode;
+use std::time::Instant;
+
+use tetka::games::dynamic::DynPosition;
+
+use super::{PerftArgs, Result};
+use crate::game::parse_position;
+
+/// perft runs perft on the position selected by the given arguments. If
+/// `divide` is set, the number of leaf nodes after every move is printed.
+pub fn perft(args: &PerftArgs, divide: bool) -> Result<ExitCode> {
+    let position = parse_position(&args.position.game, args.position.fen.as_deref())?;
+
+    let start = Instant::now();
+    let nodes = if divide && args.depth > 0 {
+        let mut nodes = 0;
+        for mov in position.legal_moves() {
+            let mut child = position.clone();
+            child.play(&mov)?;
+
+            let child_nodes = count(&*child, args.depth - 1, !args.no_bulk);
+            println!("{}: {}", mov, child_nodes);
+            nodes += child_nodes;
+        }
+
+        println!();
+        nodes
+    } else {
+        count(&*position, args.depth, !args.no_bulk)
+    };
+
+    let elapsed = start.elapsed();
+    println!("nodes {}", nodes);
+    println!("time  {} ms", elapsed.as_millis());
+    println!("nps   {}", nps(nodes, elapsed.as_secs_f64()));
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// count runs perft on the given position, with or without bulk-counting.
+/// Without bulk-counting, every move is played through the DynPosition, so
+/// it is significantly slower and mostly useful to debug move generation.
+pub fn count(position: &dyn DynPosition, depth: u8, bulk: bool) -> u64 {
+    if bulk {
+        return position.perft(depth);
+    }
+
+    if depth == 0 {
+        return 1;
+    }
+
+    let mut nodes = 0;
+    for mov in position.legal_moves() {
+        let mut child = position.box_clone();
+        child.play(&mov).expect("generated move should be legal");
+        nodes += count(&*child, depth - 1, false);
+    }
+
+    nodes
+}
+
+/// nps returns the number of nodes searched per second.
+pub fn nps(nodes: u64, seconds: f64) -> u64 {
+    if seconds > 0.0 {
+        (nodes as f64 / seconds) as u64
+    } else {
+        0
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use tetka::games::dynamic::DynPosition;
+
+use super::display::print_info;
+use super::{PositionArgs, Result};
+use crate::game::parse_position;
+
+const HELP: &str = "\
+Enter one or more moves separated by spaces to play them, or a command:
+  undo     take back the last move
+  moves    list the legal moves
+  display  display the current position
+  help     show this message
+  quit     exit the program";
+
+/// play plays moves read from stdin on the position selected by the given
+/// arguments, rejecting any moves which are illegal.
+pub fn play(args: &PositionArgs) -> Result<ExitCode> {
+    let mut history = vec![parse_position(&args.game, args.fen.as_deref())?];
+
+    println!("{}", HELP);
+    println!();
+    show(&**history.last().unwrap());
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        io::stdout().flush()?;
+
+        let Some(line) = lines.next() else {
+            println!();
+            break;
+        };
+
+        let line = line?;
+        let position = history.last().unwrap();
+        match line.trim() {
+            "" => continue,
+            "quit" | "exit" => break,
+            "help" => println!("{}", HELP),
+            "display" => show(&**position),
+            "moves" => println!("{}", position.legal_moves().join(" ")),
+            "undo" => {
+                if history.len() > 1 {
+                    history.pop();
+                    show(&**history.last().unwrap());
+                } else {
+                    println!("error: no moves to undo");
+                }
+            }
+            moves => {
+                // Moves are only committed if all of them are legal.
+                let mut position = position.clone();
+                let mut played = vec![];
+                for mov in moves.split_whitespace() {
+                    match position.play(mov) {
+                        Ok(()) => played.push(position.clone()),
+                        Err(err) => {
+                            println!("error: move {}: {}", mov, err);
+                            played.clear();
+                            break;
+                        }
+                    }
+                }
+
+                if !played.is_empty() {
+                    history.append(&mut played);
+                    show(&**history.last().unwrap());
+                }
+            }
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn show(position: &dyn DynPosition) {
+    println!("{}", position);
+    print_info(position);
+}
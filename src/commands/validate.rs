@@ -0,0 +1,60 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::process::ExitCode;
+
+use tetka::games::dynamic::Registry;
+
+use super::{Result, ValidateArgs};
+
+/// validate checks that every line of the given files is a valid FEN, and
+/// reports the invalid ones. It fails if any of the FENs are invalid.
+pub fn validate(args: &ValidateArgs) -> Result<ExitCode> {
+    let registry = Registry::default();
+    let game = registry.get(&args.game).unwrap();
+
+    let mut all_valid = true;
+    for path in &args.files {
+        let file = File::open(path).map_err(|err| format!("{}: {}", path.display(), err))?;
+
+        let (mut valid, mut invalid) = (0, 0);
+        for (number, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            let fen = line.trim();
+            if fen.is_empty() || fen.starts_with('#') {
+                continue;
+            }
+
+            match game.parse(fen) {
+                Ok(_) => valid += 1,
+                Err(err) => {
+                    invalid += 1;
+                    if !args.quiet {
+                        println!("{}:{}: {}: {}", path.display(), number + 1, fen, err);
+                    }
+                }
+            }
+        }
+
+        println!("{}: {} valid, {} invalid", path.display(), valid, invalid);
+        all_valid &= invalid == 0;
+    }
+
+    Ok(if all_valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,239 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Duration;
+
+use crate::engine::{allocate_time, Engine, Search};
+use crate::games::interface::PositionType;
+use crate::games::perft as perft_nodes;
+use crate::uxi::{error, Bundle, Command, Flag, RunError};
+
+/// position resolves into the position Command, which sets the current
+/// position of the Engine.
+pub fn position<P, S>() -> Command<Engine<P, S>>
+where
+    P: PositionType + Clone + Send + 'static,
+    <P as FromStr>::Err: Display,
+    S: Search<P>,
+{
+    Command::new(|bundle: Bundle<Engine<P, S>>| {
+        // The fen flag also collects the moves flag, since it is variadic.
+        let (fen, moves) = match bundle.get_array_flag("fen") {
+            Some(args) => {
+                let split = args
+                    .iter()
+                    .position(|arg| arg == "moves")
+                    .unwrap_or(args.len());
+                let fen = args[..split].join(" ");
+                let moves = args.get(split + 1..).unwrap_or_default().to_vec();
+                (Some(fen), moves)
+            }
+            None if bundle.is_flag_set("startpos") => {
+                (None, bundle.get_array_flag("moves").unwrap_or_default())
+            }
+            None => return error!("expected \"startpos\" or \"fen\" flags"),
+        };
+
+        let mut ctx = bundle.lock();
+        ctx.set_position(fen.as_deref(), &moves)
+            .map_err(RunError::Error)
+    })
+    .flag("startpos", Flag::Boolean)
+    .flag("fen", Flag::Variadic)
+    .flag("moves", Flag::Variadic)
+}
+
+/// go resolves into the go Command, which searches the current position in the
+/// background and reports the best move found.
+pub fn go<P, S>() -> Command<Engine<P, S>>
+where
+    P: PositionType + Clone + Send + 'static,
+    <P as FromStr>::Err: Display,
+    S: Search<P>,
+{
+    Command::new(|bundle: Bundle<Engine<P, S>>| {
+        let (position, searcher, limits) = {
+            let mut ctx = bundle.lock();
+
+            let mut limits = ctx.limits();
+            limits.depth = number(&bundle, "depth")?;
+            limits.nodes = number(&bundle, "nodes")?;
+            limits.infinite = bundle.is_flag_set("infinite");
+
+            // Time is allocated from the clock of the side to move, unless the
+            // time to search for is provided.
+            let prefix = if ctx.position().side_to_move().into() == 0 {
+                "p1"
+            } else {
+                "p2"
+            };
+            let millis =
+                |name: &str| Ok::<_, RunError>(number(&bundle, name)?.map(Duration::from_millis));
+            limits.time = match millis("movetime")? {
+                Some(time) => Some(time),
+                None => {
+                    let increment = millis(&format!("{}inc", prefix))?;
+                    millis(&format!("{}time", prefix))?
+                        .map(|time| allocate_time(time, increment.unwrap_or_default()))
+                }
+            };
+
+            let (position, searcher) = ctx.start_search().map_err(RunError::Error)?;
+            (position, searcher, limits)
+        };
+
+        // The search is run in a separate thread, without locking the context,
+        // so that other commands like stop can be run while it is running. The
+        // search is set up before the thread is spawned, so that a stop sent
+        // right after the go can't be missed.
+        thread::spawn(move || {
+            let mut searcher = Restore {
+                bundle,
+                searcher: Some(searcher),
+            };
+            let best_move = searcher.get().search(&position, &limits);
+            drop(searcher);
+
+            println!("bestmove {}", best_move);
+        });
+
+        Ok(())
+    })
+    .flag("p1time", Flag::Single)
+    .flag("p2time", Flag::Single)
+    .flag("p1inc", Flag::Single)
+    .flag("p2inc", Flag::Single)
+    .flag("movetime", Flag::Single)
+    .flag("depth", Flag::Single)
+    .flag("nodes", Flag::Single)
+    .flag("infinite", Flag::Boolean)
+}
+
+/// Restore holds the Search of an Engine while it is searching in the
+/// background, and puts it back into the Engine when dropped, so that the
+/// Engine can search again even if the search panics.
+struct Restore<P: PositionType + Send, S: Send> {
+    bundle: Bundle<Engine<P, S>>,
+    searcher: Option<S>,
+}
+
+impl<P: PositionType + Send, S: Send> Restore<P, S> {
+    /// get returns the Search held by the Restore.
+    fn get(&mut self) -> &mut S {
+        self.searcher.as_mut().unwrap()
+    }
+}
+
+impl<P: PositionType + Send, S: Send> Drop for Restore<P, S> {
+    fn drop(&mut self) {
+        self.bundle.lock().searcher = self.searcher.take();
+    }
+}
+
+/// stop resolves into the stop Command, which stops the current search.
+pub fn stop<P: PositionType + Send, S: Send>() -> Command<Engine<P, S>> {
+    Command::new(|bundle: Bundle<Engine<P, S>>| {
+        bundle.lock().stop.store(true, Ordering::Relaxed);
+        Ok(())
+    })
+}
+
+/// query resolves into the query Command, which answers queries about the
+/// current position.
+pub fn query<P, S>() -> Command<Engine<P, S>>
+where
+    P: PositionType + Clone + Send + 'static,
+    <P as FromStr>::Err: Display,
+    S: Search<P>,
+{
+    Command::new(|bundle: Bundle<Engine<P, S>>| {
+        let ctx = bundle.lock();
+        for name in ["gameover", "p1turn", "result"] {
+            if bundle.is_flag_set(name) {
+                println!("response {}", ctx.query(name).unwrap());
+                return Ok(());
+            }
+        }
+
+        error!("expected one of \"gameover\", \"p1turn\" or \"result\" flags")
+    })
+    .flag("gameover", Flag::Boolean)
+    .flag("p1turn", Flag::Boolean)
+    .flag("result", Flag::Boolean)
+}
+
+/// newgame resolves into the newgame Command, which resets the Engine for a
+/// new game.
+pub fn newgame<P, S>() -> Command<Engine<P, S>>
+where
+    P: PositionType + Clone + Send + 'static,
+    <P as FromStr>::Err: Display,
+    S: Search<P>,
+{
+    Command::new(|bundle: Bundle<Engine<P, S>>| bundle.lock().new_game().map_err(RunError::Error))
+}
+
+/// display resolves into the d Command, which displays the current position.
+pub fn display<P: PositionType + Send, S: Send>() -> Command<Engine<P, S>> {
+    Command::new(|bundle: Bundle<Engine<P, S>>| {
+        let ctx = bundle.lock();
+        println!("{}", ctx.position);
+        println!("Fen: {}", ctx.position.fen());
+        Ok(())
+    })
+}
+
+/// perft resolves into the perft Command, which runs a split perft on the
+/// current position.
+pub fn perft<P: PositionType + Clone + Send, S: Send>() -> Command<Engine<P, S>> {
+    Command::new(|bundle: Bundle<Engine<P, S>>| {
+        let depth = match number::<u8, _>(&bundle, "depth")? {
+            Some(depth) => depth,
+            None => return error!("expected \"depth\" flag"),
+        };
+
+        let position = bundle.lock().position.clone();
+        if depth == 0 {
+            println!("nodes 1");
+            return Ok(());
+        }
+
+        let mut nodes = 0;
+        for &mov in position.generate_moves::<false, true, true>().iter() {
+            let child = position.after_move::<false>(mov);
+            let child_nodes = perft_nodes::<false, true, P>(child, depth - 1);
+            println!("{}: {}", mov, child_nodes);
+            nodes += child_nodes;
+        }
+
+        println!();
+        println!("nodes {}", nodes);
+        Ok(())
+    })
+    .flag("depth", Flag::Single)
+}
+
+/// number parses the value of the given single flag, if it was provided.
+fn number<N: FromStr, T: Send>(bundle: &Bundle<T>, name: &str) -> Result<Option<N>, RunError> {
+    match bundle.get_single_flag(name) {
+        Some(value) => match value.parse() {
+            Ok(number) => Ok(Some(number)),
+            Err(_) => error!("bad {} value {}", name, value),
+        },
+        None => Ok(None),
+    }
+}
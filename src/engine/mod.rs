@@ -0,0 +1,207 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A generic UGI engine shell.
+//!
+//! [`Engine`] implements the game-agnostic parts of a UGI engine on top of a
+//! [`Client`], for any position type of tetka-games. Building an engine for a
+//! game only needs a [`Search`] to pick the moves:
+//! ```no_run
+//! use tetka::engine::{Engine, Search, SearchLimits};
+//! use tetka::games::ataxx::Position;
+//! use tetka::games::interface::{Move, PositionType};
+//! use std::str::FromStr;
+//!
+//! struct FirstMove;
+//!
+//! impl Search<Position> for FirstMove {
+//!     fn search(&mut self, position: &Position, _: &SearchLimits) -> Move<Position> {
+//!         position.generate_moves::<false, true, true>()[0]
+//!     }
+//! }
+//!
+//! let startpos = Position::from_str("x5o/7/7/7/7/7/o5x x 0 1").unwrap();
+//! Engine::<Position, FirstMove>::client("uai")
+//!     .engine("FirstMove v0.1.0")
+//!     .author("Anonymous")
+//!     .start(Engine::new(startpos, FirstMove));
+//! ```
+//!
+//! Along with the commands built into every [`Client`], the engine supports:
+//! - `position startpos [moves <move>...]` and
+//!   `position fen <fen> [moves <move>...]` to set the current position.
+//! - `go [p1time <ms>] [p2time <ms>] [p1inc <ms>] [p2inc <ms>] [movetime <ms>]
+//!   [depth <n>] [nodes <n>] [infinite]` to search the current position, and
+//!   report the best move as `bestmove <move>`. The search runs in the
+//!   background.
+//! - `stop` to stop the current search.
+//! - `query gameover`, `query p1turn` and `query result` to get information
+//!   about the current position, reported as `response <value>`.
+//! - `uginewgame`, or the newgame command of the engine's protocol.
+//! - `d` to display the current position, and `perft depth <n>` to run a
+//!   split perft on it.
+//!
+//! The first player (`p1`) is the side with the first Color of the game.
+
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use crate::games::interface::{Move, PositionType};
+use crate::uxi::Client;
+
+// Make the contents of the non-namespaced
+// modules public, so they can be accessed
+// without their parent namespace.
+pub use self::search::*;
+
+// Non-namespaced modules.
+mod commands;
+mod search;
+
+#[cfg(test)]
+mod tests;
+
+/// Engine is the context of a generic UGI engine [`Client`], which plays the
+/// game of the position type `P` using the [`Search`] `S`.
+pub struct Engine<P: PositionType, S> {
+    startpos: P,
+    position: P,
+    searcher: Option<S>,
+    stop: Arc<AtomicBool>,
+}
+
+impl<P, S> Engine<P, S>
+where
+    P: PositionType + Clone + Send + 'static,
+    <P as FromStr>::Err: Display,
+    S: Search<P>,
+{
+    /// new creates a new Engine which starts from the given starting position
+    /// and searches with the given Search.
+    pub fn new(startpos: P, searcher: S) -> Self {
+        Engine {
+            position: startpos.clone(),
+            startpos,
+            searcher: Some(searcher),
+            stop: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// client creates a new Client which supports the given protocol along
+    /// with UGI, and has all the commands of the Engine registered. Its name
+    /// and author can be set with the builder methods of Client.
+    pub fn client(protocol: &str) -> Client<Self> {
+        let mut client = Client::new()
+            .command("position", commands::position())
+            .command("go", commands::go())
+            .command("stop", commands::stop())
+            .command("query", commands::query())
+            .command("uginewgame", commands::newgame())
+            .command("d", commands::display())
+            .command("perft", commands::perft());
+
+        if protocol != "ugi" {
+            client = client
+                .protocol(protocol)
+                .command(&format!("{}newgame", protocol), commands::newgame());
+        }
+
+        client
+    }
+
+    /// position returns the current position of the Engine.
+    pub fn position(&self) -> &P {
+        &self.position
+    }
+
+    /// set_position sets the current position to the given FEN, or to the
+    /// starting position if no FEN is provided, and then plays the given moves.
+    /// The current position is left unchanged if any of them are invalid.
+    pub fn set_position(&mut self, fen: Option<&str>, moves: &[String]) -> Result<(), String> {
+        let mut position = match fen {
+            Some(fen) => P::from_str(fen).map_err(|err| format!("bad fen: {}", err))?,
+            None => self.startpos.clone(),
+        };
+
+        for mov in moves {
+            let parsed = position
+                .parse_move(mov)
+                .map_err(|err| format!("bad move {}: {}", mov, err))?;
+            position = position.after_move::<true>(parsed);
+        }
+
+        self.position = position;
+        Ok(())
+    }
+
+    /// new_game resets the Engine to the starting position and notifies its
+    /// Search of the new game. It fails if a search is running.
+    pub fn new_game(&mut self) -> Result<(), String> {
+        let searcher = self
+            .searcher
+            .as_mut()
+            .ok_or("a search is already running")?;
+        searcher.new_game();
+        self.position = self.startpos.clone();
+        Ok(())
+    }
+
+    /// query answers the given UGI query about the current position, or
+    /// returns `None` if the query is unknown.
+    pub fn query(&self, query: &str) -> Option<String> {
+        let is_p1 = |color: crate::games::interface::Color<P>| color.into() == 0;
+        let response = match query {
+            "gameover" => self.position.is_game_over().to_string(),
+            "p1turn" => is_p1(self.position.side_to_move()).to_string(),
+            "result" if !self.position.is_game_over() => "none".to_string(),
+            "result" => match self.position.winner() {
+                Some(winner) if is_p1(winner) => "p1win".to_string(),
+                Some(_) => "p2win".to_string(),
+                None => "draw".to_string(),
+            },
+            _ => return None,
+        };
+
+        Some(response)
+    }
+
+    /// search searches the current position with the Engine's Search and
+    /// returns the best move. It fails if the game is over or if another
+    /// search is already running.
+    pub fn search(&mut self, limits: &SearchLimits) -> Result<Move<P>, String> {
+        let (position, mut searcher) = self.start_search()?;
+        let best_move = searcher.search(&position, limits);
+        self.searcher = Some(searcher);
+        Ok(best_move)
+    }
+
+    /// start_search takes the Search out of the Engine for the duration of
+    /// a search, so that the Engine can be used while the search is running.
+    fn start_search(&mut self) -> Result<(P, S), String> {
+        if self.position.is_game_over() {
+            return Err("the game is already over".to_string());
+        }
+
+        let searcher = self.searcher.take().ok_or("a search is already running")?;
+        self.stop.store(false, std::sync::atomic::Ordering::Relaxed);
+        Ok((self.position.clone(), searcher))
+    }
+
+    /// limits returns new SearchLimits without any limits, which are stopped
+    /// by the Engine's stop command.
+    fn limits(&self) -> SearchLimits {
+        SearchLimits::new(Arc::clone(&self.stop))
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::games::interface::{Move, PositionType};
+
+/// Search is the interface of the searches used by an [`Engine`](super::Engine)
+/// to pick its moves. A Search can report information about its progress by
+/// printing `info` lines to stdout while it is running.
+pub trait Search<P: PositionType>: Send + 'static {
+    /// search searches the given position within the given limits and returns
+    /// the best move found. It should return as soon as possible once the
+    /// limits are [stopped](SearchLimits::is_stopped). It is only called on
+    /// positions where the game isn't over.
+    fn search(&mut self, position: &P, limits: &SearchLimits) -> Move<P>;
+
+    /// new_game is called when the GUI starts a new game, so that the Search
+    /// can clear any state which shouldn't carry over to it.
+    fn new_game(&mut self) {}
+}
+
+/// SearchLimits are the limits of a search, as provided by the `go` command.
+#[derive(Clone, Debug)]
+pub struct SearchLimits {
+    /// depth is the maximum depth to search to.
+    pub depth: Option<u8>,
+    /// nodes is the maximum number of nodes to search.
+    pub nodes: Option<u64>,
+    /// time is the maximum time to search for, either as provided by the GUI
+    /// or allocated from the clock of the side to move.
+    pub time: Option<Duration>,
+    /// infinite is set if the search should continue until it is stopped.
+    pub infinite: bool,
+
+    start: Instant,
+    stop: Arc<AtomicBool>,
+}
+
+impl SearchLimits {
+    /// new creates new SearchLimits without any limits, which are stopped once
+    /// the given flag is set.
+    pub fn new(stop: Arc<AtomicBool>) -> Self {
+        SearchLimits {
+            depth: None,
+            nodes: None,
+            time: None,
+            infinite: false,
+            start: Instant::now(),
+            stop,
+        }
+    }
+
+    /// is_stopped checks if the search has been stopped by the GUI, or if it
+    /// has run out of the time allocated to it.
+    pub fn is_stopped(&self) -> bool {
+        self.stop.load(Ordering::Relaxed) || self.time.is_some_and(|time| self.elapsed() >= time)
+    }
+
+    /// elapsed returns the time elapsed since the start of the search.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+/// allocate_time returns the time to spend on a move, given the remaining time
+/// and increment of the side to move. A small overhead is always left on the
+/// clock to account for the latency of the communication with the GUI.
+pub fn allocate_time(time: Duration, increment: Duration) -> Duration {
+    const OVERHEAD: Duration = Duration::from_millis(50);
+
+    let available = time.saturating_sub(OVERHEAD);
+    (time / 20 + increment / 2).min(available)
+}
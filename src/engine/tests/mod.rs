@@ -0,0 +1,158 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::engine::*;
+use crate::games::interface::{Move, PositionType};
+use crate::games::mnk::TicTacToe;
+
+/// FirstMove is a Search which plays the first legal move, and only returns
+/// from infinite searches once it is stopped.
+#[derive(Default)]
+struct FirstMove {
+    new_games: Arc<AtomicUsize>,
+}
+
+impl Search<TicTacToe> for FirstMove {
+    fn search(&mut self, position: &TicTacToe, limits: &SearchLimits) -> Move<TicTacToe> {
+        while limits.infinite && !limits.is_stopped() {
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        position.generate_moves::<false, true, true>()[0]
+    }
+
+    fn new_game(&mut self) {
+        self.new_games.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+fn engine() -> Engine<TicTacToe, FirstMove> {
+    Engine::new(
+        TicTacToe::from_str("3/3/3 x").unwrap(),
+        FirstMove::default(),
+    )
+}
+
+fn moves(moves: &[&str]) -> Vec<String> {
+    moves.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn set_position() {
+    let mut engine = engine();
+
+    engine.set_position(None, &moves(&["b2", "a1"])).unwrap();
+    assert_eq!(engine.position().fen(), "3/1x1/o2 x");
+
+    engine
+        .set_position(Some("x2/1o1/3 x"), &moves(&["c3"]))
+        .unwrap();
+    assert_eq!(engine.position().fen(), "x1x/1o1/3 o");
+
+    // Invalid positions and moves leave the current position unchanged.
+    assert!(engine.set_position(Some("x2/1o1 x"), &[]).is_err());
+    assert!(engine.set_position(None, &moves(&["b2", "b2"])).is_err());
+    assert_eq!(engine.position().fen(), "x1x/1o1/3 o");
+}
+
+#[test]
+fn query() {
+    let mut engine = engine();
+    assert_eq!(engine.query("gameover").unwrap(), "false");
+    assert_eq!(engine.query("p1turn").unwrap(), "true");
+    assert_eq!(engine.query("result").unwrap(), "none");
+    assert_eq!(engine.query("unknown"), None);
+
+    engine.set_position(Some("3/ooo/xx1 x"), &[]).unwrap();
+    assert_eq!(engine.query("gameover").unwrap(), "true");
+    assert_eq!(engine.query("result").unwrap(), "p2win");
+
+    engine.set_position(Some("xox/xoo/oxx x"), &[]).unwrap();
+    assert_eq!(engine.query("result").unwrap(), "draw");
+
+    engine.set_position(Some("3/1x1/3 o"), &[]).unwrap();
+    assert_eq!(engine.query("p1turn").unwrap(), "false");
+}
+
+#[test]
+fn search() {
+    let mut engine = engine();
+    let new_games = Arc::clone(&engine.searcher.as_ref().unwrap().new_games);
+
+    let limits = engine.limits();
+    let best_move = engine.search(&limits).unwrap();
+    assert!(engine.position().is_legal(best_move));
+
+    // A game which is over can't be searched.
+    engine.set_position(Some("3/ooo/xx1 x"), &[]).unwrap();
+    assert!(engine.search(&limits).is_err());
+
+    engine.new_game().unwrap();
+    assert_eq!(engine.position().fen(), "3/3/3 x");
+    assert_eq!(new_games.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn stop_search() {
+    let mut engine = engine();
+    let stop = Arc::clone(&engine.stop);
+
+    let mut limits = engine.limits();
+    limits.infinite = true;
+
+    let search = thread::spawn(move || engine.search(&limits).unwrap());
+    thread::sleep(Duration::from_millis(10));
+    assert!(!search.is_finished());
+
+    stop.store(true, Ordering::Relaxed);
+    search.join().unwrap();
+}
+
+#[test]
+fn search_time() {
+    let engine = engine();
+
+    let mut limits = engine.limits();
+    limits.time = Some(Duration::from_millis(5));
+    assert!(!limits.is_stopped());
+    thread::sleep(Duration::from_millis(10));
+    assert!(limits.is_stopped());
+
+    let ms = Duration::from_millis;
+    assert_eq!(allocate_time(ms(10000), ms(0)), ms(500));
+    assert_eq!(allocate_time(ms(10000), ms(100)), ms(550));
+    assert_eq!(allocate_time(ms(100), ms(1000)), ms(50));
+    assert_eq!(allocate_time(ms(10), ms(0)), ms(0));
+}
+
+#[test]
+fn commands() {
+    let client = Engine::<TicTacToe, FirstMove>::client("ugi");
+
+    let run = |command: &str| client.run_cmd_string(command.to_string(), engine());
+    assert!(run("position startpos moves b2 a1").is_ok());
+    assert!(run("position fen x2/1o1/3 x moves c3").is_ok());
+    assert!(run("position fen x2/1o1 x").is_err());
+    assert!(run("position moves b2").is_err());
+    assert!(run("query p1turn").is_ok());
+    assert!(run("query").is_err());
+    assert!(run("go depth 2").is_ok());
+    assert!(run("go depth two").is_err());
+    assert!(run("perft depth 2").is_ok());
+    assert!(run("stop").is_ok());
+    assert!(run("uginewgame").is_ok());
+    assert!(run("d").is_ok());
+}
+
+#[test]
+fn go_flags() {
+    let client = Engine::<TicTacToe, FirstMove>::client("ugi");
+
+    let run = |command: &str| client.run_cmd_string(command.to_string(), engine());
+    assert!(run("go p1time 1000 p1inc 10").is_ok());
+    assert!(run("go p1time 1000 p1inc ten").is_err());
+    assert!(run("go p1inc ten").is_err());
+}
@@ -0,0 +1,46 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use clap::builder::PossibleValuesParser;
+use tetka::games::dynamic::{DynPosition, Registry};
+
+use crate::commands::Result;
+
+/// game_parser returns a parser for the names of the games in the default
+/// Registry, so that they are listed in the help of the commands.
+pub fn game_parser() -> PossibleValuesParser {
+    PossibleValuesParser::new(Registry::default().names())
+}
+
+/// parse_position parses the given FEN into a position of the named game,
+/// defaulting to the game's starting position if no FEN is provided.
+pub fn parse_position(game: &str, fen: Option<&str>) -> Result<Box<dyn DynPosition>> {
+    let registry = Registry::default();
+    Ok(match fen {
+        Some(fen) => registry.parse(game, fen)?,
+        None => registry.start(game)?,
+    })
+}
+
+/// bench_depth returns the perft depth used to benchmark the move generation
+/// of the named game, which takes well under a second to walk in release
+/// builds.
+pub fn bench_depth(game: &str) -> u8 {
+    match game {
+        "amazons" => 2,
+        "hex11x11" | "gomoku" => 4,
+        "hex7x7" => 5,
+        "tictactoe" | "connect-four" | "draughts" => 9,
+        _ => 6,
+    }
+}
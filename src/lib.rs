@@ -1,3 +1,5 @@
+pub mod engine;
+
 pub mod games {
     pub use tetka_games::*;
 }
@@ -0,0 +1,101 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn tetka(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tetka"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn perft() {
+    let output = tetka(&["perft", "ataxx", "4"], "");
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("nodes 155888\n"));
+
+    let fen = ["--fen", "7/7/3o3/3x3/2ox3/1xoxo2 x"];
+    let output = tetka(&["perft", "connect-four", "5", fen[0], fen[1]], "");
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("nodes 16164\n"));
+
+    let output = tetka(&["perft", "tictactoe", "5", "--no-bulk"], "");
+    assert!(stdout(&output).contains("nodes 15120\n"));
+}
+
+#[test]
+fn divide() {
+    let output = tetka(&["divide", "tictactoe", "2"], "");
+    let stdout = stdout(&output);
+    assert!(output.status.success());
+    assert_eq!(
+        stdout.lines().filter(|line| line.ends_with(": 8")).count(),
+        9
+    );
+    assert!(stdout.contains("nodes 72\n"));
+}
+
+#[test]
+fn display() {
+    let output = tetka(&["display", "tictactoe", "--fen", "3/ooo/xx1 x"], "");
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("Result: o wins"));
+
+    let output = tetka(&["display", "ataxx", "--fen", "x5o/7 x"], "");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid ataxx"));
+}
+
+#[test]
+fn play() {
+    let output = tetka(&["play", "tictactoe"], "a1 b1\nd4\nb2 a2\nc3\nundo\nquit\n");
+    let stdout = stdout(&output);
+    assert!(output.status.success());
+    assert!(stdout.contains("error: move d4"));
+    assert!(stdout.contains("Result: x wins"));
+}
+
+#[test]
+fn validate() {
+    let dir = std::env::temp_dir().join(format!("tetka-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let valid = dir.join("valid.epd");
+    std::fs::write(&valid, "# comment\n3/3/3 x\n\nx2/1o1/3 x\n").unwrap();
+    let output = tetka(&["validate", "tictactoe", valid.to_str().unwrap()], "");
+    assert!(output.status.success());
+    assert!(stdout(&output).ends_with("2 valid, 0 invalid\n"));
+
+    let invalid = dir.join("invalid.epd");
+    std::fs::write(&invalid, "3/3/3 x\n3/3 x\n").unwrap();
+    let output = tetka(&["validate", "tictactoe", invalid.to_str().unwrap()], "");
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("invalid.epd:2: 3/3 x: "));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn bench() {
+    let output = tetka(&["bench", "tictactoe", "hexapawn", "--depth", "3"], "");
+    let stdout = stdout(&output);
+    assert!(output.status.success());
+    assert!(stdout.contains("tictactoe"));
+    assert!(stdout.contains("hexapawn"));
+    assert!(stdout.contains("\n532 nodes"));
+}
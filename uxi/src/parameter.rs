@@ -6,7 +6,13 @@ use std::fmt;
 /// used to configure various internal parameters of the game engine, like the
 /// number of threads it uses or its play style. An option's value can be
 /// changed using `setoption` Command.
+///
+/// With the `serde` feature, Parameters are deserialized from the same format
+/// they are serialized in, and deserializing one fails if it doesn't uphold
+/// the invariants of its variant.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawParameter"))]
 pub enum Parameter {
     /// Check represents a checkbox parameter which can be true or false.
     ///
@@ -34,7 +40,49 @@ pub enum Parameter {
     Combo(String, Vec<String>),
 }
 
+/// RawParameter is a deserialized Parameter which hasn't been checked for
+/// the invariants of its variant yet.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+enum RawParameter {
+    Check(bool),
+    String(String),
+    Spin(i64, i64, i64),
+    Combo(String, Vec<String>),
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawParameter> for Parameter {
+    type Error = String;
+
+    fn try_from(raw: RawParameter) -> Result<Self, Self::Error> {
+        match raw {
+            RawParameter::Check(default) => Ok(Parameter::Check(default)),
+            RawParameter::String(default) => Ok(Parameter::String(default)),
+            RawParameter::Spin(default, min, max) => {
+                if default < min || default > max {
+                    return Err(format!(
+                        "spin default {} is not between {} and {} (inclusive)",
+                        default, min, max
+                    ));
+                }
+                Ok(Parameter::Spin(default, min, max))
+            }
+            RawParameter::Combo(default, strings) => {
+                if !strings.contains(&default) {
+                    return Err(format!(
+                        "combo default {} is not one of the combo strings",
+                        default
+                    ));
+                }
+                Ok(Parameter::Combo(default, strings))
+            }
+        }
+    }
+}
+
 #[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Values {
     checks: HashMap<String, bool>,
     strings: HashMap<String, String>,
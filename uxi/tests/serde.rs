@@ -0,0 +1,35 @@
+#![cfg(feature = "serde")]
+
+use serde_json::{from_str, to_string};
+use uxi::Parameter;
+
+#[test]
+fn parameter_round_trip() {
+    let parameters = [
+        Parameter::Check(true),
+        Parameter::String("book.bin".to_string()),
+        Parameter::Spin(16, 1, 1024),
+        Parameter::Combo(
+            "fast".to_string(),
+            vec!["fast".to_string(), "slow".to_string()],
+        ),
+    ];
+
+    for parameter in parameters {
+        let json = to_string(&parameter).unwrap();
+        let parsed = from_str::<Parameter>(&json).unwrap();
+        assert_eq!(parsed.to_string(), parameter.to_string());
+        assert_eq!(to_string(&parsed).unwrap(), json);
+    }
+}
+
+#[test]
+fn invalid_parameters() {
+    assert!(from_str::<Parameter>(r#"{"Spin":[1,1,1]}"#).is_ok());
+    assert!(from_str::<Parameter>(r#"{"Spin":[0,1,1024]}"#).is_err());
+    assert!(from_str::<Parameter>(r#"{"Spin":[2048,1,1024]}"#).is_err());
+    assert!(from_str::<Parameter>(r#"{"Spin":[5,10,1]}"#).is_err());
+
+    assert!(from_str::<Parameter>(r#"{"Combo":["fast",["slow"]]}"#).is_err());
+    assert!(from_str::<Parameter>(r#"{"Combo":["fast",[]]}"#).is_err());
+}
@@ -0,0 +1,182 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! WebAssembly bindings for the ataxx implementation of tetka-games.
+//!
+//! The bindings are built into a JavaScript module with wasm-bindgen:
+//! ```js
+//! import { Position } from "tetka";
+//!
+//! const position = new Position();
+//! for (const mov of position.legalMoves()) {
+//!     console.log(mov, position.afterMove(mov).perft(2));
+//! }
+//! ```
+//! Moves are passed to and returned from every method as move strings, and
+//! invalid FENs and moves throw an `Error`.
+
+use std::str::FromStr;
+
+use wasm_bindgen::prelude::*;
+
+use tetka_games::ataxx;
+use tetka_games::interface::PositionType;
+use tetka_games::render::Diagram;
+
+/// STARTPOS is the FEN of the standard ataxx starting position.
+const STARTPOS: &str = "x5o/7/7/7/7/7/o5x x 0 1";
+
+/// Returns the FEN of the standard ataxx starting position.
+#[wasm_bindgen(js_name = startpos)]
+pub fn startpos() -> String {
+    STARTPOS.to_string()
+}
+
+/// Outcome represents the outcome of an ataxx position.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// Ongoing is a position where the game isn't over.
+    Ongoing = "ongoing",
+    /// BlackWins is a position where the game is over and black has won.
+    BlackWins = "black-wins",
+    /// WhiteWins is a position where the game is over and white has won.
+    WhiteWins = "white-wins",
+    /// Draw is a position where the game is over and drawn.
+    Draw = "draw",
+}
+
+/// Position is an ataxx position, which is created from its FEN, or from the
+/// starting position if no FEN is given.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Position(ataxx::Position);
+
+impl Position {
+    /// parse_move parses the given move string and checks that it is legal.
+    fn parse_move(&self, mov: &str) -> Result<ataxx::Move, JsError> {
+        self.0
+            .parse_move(mov)
+            .map_err(|err| JsError::new(&err.to_string()))
+    }
+}
+
+#[wasm_bindgen]
+impl Position {
+    /// Creates a new position from the given FEN, or the starting position if
+    /// no FEN is given.
+    #[wasm_bindgen(constructor)]
+    pub fn new(fen: Option<String>) -> Result<Position, JsError> {
+        ataxx::Position::from_str(fen.as_deref().unwrap_or(STARTPOS))
+            .map(Position)
+            .map_err(|err| JsError::new(&err.to_string()))
+    }
+
+    /// Returns the FEN of the position.
+    pub fn fen(&self) -> String {
+        self.0.fen()
+    }
+
+    /// The side to move, "x" for black or "o" for white.
+    #[wasm_bindgen(getter, js_name = sideToMove)]
+    pub fn side_to_move(&self) -> String {
+        self.0.side_to_move().to_string()
+    }
+
+    /// Returns the legal moves in the position as move strings.
+    #[wasm_bindgen(js_name = legalMoves)]
+    pub fn legal_moves(&self) -> Vec<String> {
+        let moves = self.0.generate_moves::<false, true, true>();
+        moves.iter().map(|mov| mov.to_string()).collect()
+    }
+
+    /// Returns true if the given move is legal in the position.
+    #[wasm_bindgen(js_name = isLegal)]
+    pub fn is_legal(&self, mov: &str) -> bool {
+        self.0.parse_move(mov).is_ok()
+    }
+
+    /// Plays the given move on the position, throwing an error without
+    /// changing the position if the move is illegal.
+    pub fn play(&mut self, mov: &str) -> Result<(), JsError> {
+        let mov = self.parse_move(mov)?;
+        self.0 = self.0.after_move::<true>(mov);
+        Ok(())
+    }
+
+    /// Returns the position reached after playing the given move, throwing an
+    /// error if the move is illegal.
+    #[wasm_bindgen(js_name = afterMove)]
+    pub fn after_move(&self, mov: &str) -> Result<Position, JsError> {
+        let mov = self.parse_move(mov)?;
+        Ok(Position(self.0.after_move::<true>(mov)))
+    }
+
+    /// Returns the number of leaf nodes in the move generation tree of the
+    /// given depth from the position.
+    pub fn perft(&self, depth: u8) -> u64 {
+        tetka_games::perft::<false, true, _>(self.0, depth)
+    }
+
+    /// Returns true if the game is over in the position.
+    #[wasm_bindgen(js_name = isGameOver)]
+    pub fn is_game_over(&self) -> bool {
+        self.0.is_game_over()
+    }
+
+    /// Returns the outcome of the game in the position.
+    pub fn outcome(&self) -> Outcome {
+        // The winner of an ataxx position is only defined once it is over.
+        if !self.0.is_game_over() {
+            return Outcome::Ongoing;
+        }
+
+        match self.0.winner() {
+            Some(ataxx::Color::Black) => Outcome::BlackWins,
+            Some(ataxx::Color::White) => Outcome::WhiteWins,
+            None => Outcome::Draw,
+        }
+    }
+
+    /// Returns an SVG diagram of the position.
+    pub fn svg(&self) -> String {
+        Diagram::new(&self.0).svg()
+    }
+
+    /// Returns an SVG diagram of the position, highlighting the squares of
+    /// the given last move, which throws an error if it is invalid.
+    #[wasm_bindgen(js_name = svgWithLastMove)]
+    pub fn svg_with_last_move(&self, last_move: &str) -> Result<String, JsError> {
+        let mov = ataxx::Move::from_str(last_move).map_err(|err| JsError::new(&err.to_string()))?;
+
+        let mut diagram = Diagram::new(&self.0);
+        if mov != ataxx::Move::PASS {
+            let source = (!mov.is_single()).then(|| mov.source());
+            diagram = diagram.last_move(source, mov.target());
+        }
+
+        Ok(diagram.svg())
+    }
+
+    /// Returns a copy of the position.
+    #[wasm_bindgen(js_name = clone)]
+    pub fn copy(&self) -> Position {
+        self.clone()
+    }
+
+    /// Returns the ASCII diagram of the position.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
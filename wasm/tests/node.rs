@@ -0,0 +1,111 @@
+// Copyright © 2024 Rak Laptudirm <rak@laptudirm.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests of the WebAssembly bindings, which are run headlessly on node with
+//! `cargo test -p tetka-wasm --target wasm32-unknown-unknown`.
+
+#![cfg(target_arch = "wasm32")]
+
+use tetka_wasm::{startpos, Outcome, Position};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn fen() {
+    let position = Position::new(None).unwrap();
+    assert_eq!(position.fen(), startpos());
+    assert_eq!(position.side_to_move(), "x");
+
+    let fen = "x5o/7/2-1-2/7/2-1-2/7/o5x o 0 1";
+    assert_eq!(Position::new(Some(fen.to_string())).unwrap().fen(), fen);
+
+    assert!(Position::new(Some("x5o/7/7/7/7/7 x 0 1".to_string())).is_err());
+    assert!(Position::new(Some("x5o/7/7/7/7/7/o5x y 0 1".to_string())).is_err());
+}
+
+#[wasm_bindgen_test]
+fn legal_moves() {
+    let position = Position::new(None).unwrap();
+    let moves = position.legal_moves();
+    assert_eq!(moves.len(), 16);
+    assert!(moves.iter().all(|mov| position.is_legal(mov)));
+    assert!(moves.contains(&"b6".to_string()));
+    assert!(moves.contains(&"a7c5".to_string()));
+
+    assert!(!position.is_legal("a1"));
+    assert!(!position.is_legal("0000"));
+    assert!(!position.is_legal("z9"));
+}
+
+#[wasm_bindgen_test]
+fn play() {
+    let mut position = Position::new(None).unwrap();
+    let after = position.after_move("g2").unwrap();
+
+    position.play("g2").unwrap();
+    assert_eq!(position.fen(), after.fen());
+    assert_eq!(position.fen(), "x5o/7/7/7/7/6x/o5x o 0 1");
+    assert_eq!(position.side_to_move(), "o");
+
+    assert!(position.play("g2").is_err());
+    assert!(position.after_move("a7c5").is_err());
+    assert_eq!(position.fen(), "x5o/7/7/7/7/6x/o5x o 0 1");
+}
+
+#[wasm_bindgen_test]
+fn perft() {
+    let position = Position::new(None).unwrap();
+    assert_eq!(position.perft(1), 16);
+    assert_eq!(position.perft(2), 256);
+    assert_eq!(position.perft(3), 6460);
+}
+
+#[wasm_bindgen_test]
+fn outcome() {
+    let position = Position::new(None).unwrap();
+    assert!(!position.is_game_over());
+    assert_eq!(position.outcome(), Outcome::Ongoing);
+
+    let position = Position::new(Some("7/7/7/7/7/7/x6 o 0 1".to_string())).unwrap();
+    assert!(position.is_game_over());
+    assert_eq!(position.outcome(), Outcome::BlackWins);
+
+    let position = Position::new(Some("o6/7/7/7/7/7/7 x 0 1".to_string())).unwrap();
+    assert_eq!(position.outcome(), Outcome::WhiteWins);
+
+    let position = Position::new(Some("x5o/7/7/7/7/7/o5x x 100 1".to_string())).unwrap();
+    assert_eq!(position.outcome(), Outcome::Draw);
+}
+
+#[wasm_bindgen_test]
+fn svg() {
+    let fen = "x5o/7/3-3/7/7/7/o5x x 0 1";
+    let position = Position::new(Some(fen.to_string())).unwrap();
+
+    let svg = position.svg();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.ends_with("</svg>"));
+    assert_eq!(svg.matches("<circle").count(), 4);
+    assert_eq!(svg.matches("<text").count(), 14);
+
+    // The squares of the last move are highlighted.
+    let highlight = r##"fill="#cdd26a""##;
+    assert_eq!(svg.matches(highlight).count(), 0);
+    let svg = position.svg_with_last_move("a7c5").unwrap();
+    assert_eq!(svg.matches(highlight).count(), 2);
+    let svg = position.svg_with_last_move("b6").unwrap();
+    assert_eq!(svg.matches(highlight).count(), 1);
+    let svg = position.svg_with_last_move("0000").unwrap();
+    assert_eq!(svg.matches(highlight).count(), 0);
+
+    assert!(position.svg_with_last_move("z9").is_err());
+}